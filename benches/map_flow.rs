@@ -0,0 +1,66 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use node_flow::{
+    context::{Fork, Join},
+    flows::MapFlow,
+    node::{Node, NodeOutput},
+};
+
+const ITEMS: usize = 10_000;
+const MAX_CONCURRENCY: usize = 64;
+const CONTEXT_SIZE: usize = 64 * 1024;
+
+/// Context that is expensive to fork.
+struct HeavyCtx(Vec<u8>);
+
+impl Fork for HeavyCtx {
+    fn fork(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl Join for HeavyCtx {
+    fn join(&mut self, _others: Box<[Self]>) {}
+}
+
+#[derive(Clone)]
+struct Double;
+
+impl Node<u32, NodeOutput<u64>, (), HeavyCtx> for Double {
+    async fn run(&mut self, input: u32, _: &mut HeavyCtx) -> Result<NodeOutput<u64>, ()> {
+        tokio::task::yield_now().await;
+        Ok(NodeOutput::Ok(u64::from(input) * 2))
+    }
+}
+
+fn fork_pool(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let input = (0..u32::try_from(ITEMS).unwrap()).collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("map_flow_fork_pool");
+    // without a pool all `ITEMS` forked contexts are alive until the end
+    for fork_pool in [None, Some(MAX_CONCURRENCY), Some(4 * MAX_CONCURRENCY)] {
+        let id = fork_pool.map_or_else(|| "unbounded".to_owned(), |size| size.to_string());
+        group.bench_with_input(
+            BenchmarkId::from_parameter(id),
+            &fork_pool,
+            |b, fork_pool| {
+                b.to_async(&runtime).iter(|| async {
+                    let flow =
+                        MapFlow::<u32, u64, (), HeavyCtx>::new_concurrent(Double, MAX_CONCURRENCY);
+                    let mut flow = match fork_pool {
+                        Some(size) => flow.with_fork_pool(*size),
+                        None => flow,
+                    };
+                    let mut ctx = HeavyCtx(vec![0; CONTEXT_SIZE]);
+                    flow.run(input.clone(), &mut ctx).await
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, fork_pool);
+criterion_main!(benches);
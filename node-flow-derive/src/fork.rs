@@ -0,0 +1,48 @@
+//! Expansion of the `Fork` derive macro.
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{DeriveInput, parse_quote, spanned::Spanned};
+
+use crate::parse::{has_fork_clone, struct_fields};
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let fields = struct_fields(input, "Fork")?;
+    let clone_all = has_fork_clone(&input.attrs)?;
+
+    let fork_trait = quote!(::node_flow::context::Fork);
+    let clone_trait = quote!(::core::clone::Clone);
+
+    let mut generics = input.generics.clone();
+    let has_generics = !generics.params.is_empty();
+    let mut inits = Vec::with_capacity(fields.len());
+    for (field, member) in fields.iter().zip(fields.members()) {
+        // trait and its method used for forking the field
+        let (trait_path, method) = if clone_all || has_fork_clone(&field.attrs)? {
+            (&clone_trait, quote!(clone))
+        } else {
+            (&fork_trait, quote!(fork))
+        };
+        // concrete field types are checked when calling `fork` or `clone`
+        if has_generics {
+            let ty = &field.ty;
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#ty: #trait_path));
+        }
+        // errors about missing implementations point at the field
+        inits.push(quote_spanned!(field.ty.span()=> #member: #trait_path::#method(&self.#member)));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #fork_trait for #name #type_generics #where_clause {
+            fn fork(&self) -> Self {
+                Self { #(#inits,)* }
+            }
+        }
+    })
+}
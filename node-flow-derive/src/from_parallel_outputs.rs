@@ -0,0 +1,44 @@
+//! Expansion of the `FromParallelOutputs` derive macro.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+use crate::parse::struct_fields;
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let fields = struct_fields(input, "FromParallelOutputs")?;
+    let Some(first) = fields.iter().next() else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`FromParallelOutputs` requires at least one field",
+        ));
+    };
+
+    let bindings = (0..fields.len())
+        .map(|idx| format_ident!("field{idx}"))
+        .collect::<Vec<_>>();
+
+    // outputs are nested the same way as in `ParallelFlow`: (((A,), B), C)
+    let first_ty = &first.ty;
+    let first_binding = &bindings[0];
+    let mut outputs_type = quote!((#first_ty,));
+    let mut outputs_pattern = quote!((#first_binding,));
+    for (field, binding) in fields.iter().zip(&bindings).skip(1) {
+        let ty = &field.ty;
+        outputs_type = quote!((#outputs_type, #ty));
+        outputs_pattern = quote!((#outputs_pattern, #binding));
+    }
+
+    let members = fields.members();
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#outputs_type> for #name #type_generics #where_clause {
+            fn from(#outputs_pattern: #outputs_type) -> Self {
+                Self { #(#members: #bindings,)* }
+            }
+        }
+    })
+}
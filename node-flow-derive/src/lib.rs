@@ -0,0 +1,94 @@
+#![warn(missing_docs, clippy::pedantic, clippy::all, clippy::nursery)]
+#![forbid(rustdoc::broken_intra_doc_links, missing_debug_implementations)]
+
+//! # Node Flow Derive
+//!
+//! Derive macros for the [`node-flow`](https://docs.rs/node-flow) crate.
+//!
+//! This crate should not be used directly,
+//! enable the `derive` feature of `node-flow` instead.
+
+use proc_macro::TokenStream;
+use syn::{DeriveInput, parse_macro_input};
+
+mod fork;
+mod from_parallel_outputs;
+mod parse;
+
+/// Derives the `Fork` trait for a struct.
+///
+/// By default, every field is forked using `Fork::fork`.
+/// - Fields marked with `#[fork(clone)]` are cloned using `Clone::clone` instead.
+/// - When the struct itself is marked with `#[fork(clone)]`, all fields are cloned.
+///
+/// When the struct has generic parameters, the implementation requires
+/// the type of each field to implement `Fork` or `Clone` respectively.
+///
+/// # Examples
+/// ```
+/// use node_flow::context::Fork;
+///
+/// #[derive(Fork)]
+/// struct Context {
+///     branch: Branch,
+///     #[fork(clone)]
+///     name: String,
+/// }
+///
+/// struct Branch(u8);
+/// impl Fork for Branch {
+///     fn fork(&self) -> Self {
+///         Self(self.0 + 1)
+///     }
+/// }
+///
+/// #[derive(Fork)]
+/// #[fork(clone)]
+/// struct Config(String, u32);
+///
+/// let ctx = Context { branch: Branch(0), name: "main".to_owned() };
+/// let forked = ctx.fork();
+/// assert_eq!(forked.branch.0, 1);
+/// assert_eq!(forked.name, "main");
+///
+/// let config = Config("cfg".to_owned(), 5).fork();
+/// assert_eq!((config.0.as_str(), config.1), ("cfg", 5));
+/// ```
+#[proc_macro_derive(Fork, attributes(fork))]
+pub fn derive_fork(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    fork::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `From` for converting outputs of a `ParallelFlow` into a struct.
+///
+/// `ParallelFlow` passes outputs of its nodes into the joiner as nested tuples
+/// (`(((NodeOutput<A>,), NodeOutput<B>), NodeOutput<C>)`).
+/// This derive generates a `From` implementation which converts such tuple into the struct,
+/// the fields are assigned in the order in which they are declared.
+/// The tuple is made of the types of the fields, so fields usually have the type `NodeOutput<T>`.
+///
+/// # Examples
+/// ```
+/// use node_flow::flows::parallel_flow::FromParallelOutputs;
+/// use node_flow::node::NodeOutput;
+///
+/// #[derive(FromParallelOutputs)]
+/// struct Outputs {
+///     user: NodeOutput<String>,
+///     orders: NodeOutput<u32>,
+/// }
+///
+/// let outputs = Outputs::from(((NodeOutput::Ok("user".to_owned()),), NodeOutput::SoftFail));
+/// assert_eq!(outputs.user, NodeOutput::Ok("user".to_owned()));
+/// assert_eq!(outputs.orders, NodeOutput::SoftFail);
+/// ```
+#[proc_macro_derive(FromParallelOutputs)]
+pub fn derive_from_parallel_outputs(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_parallel_outputs::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
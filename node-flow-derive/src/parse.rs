@@ -0,0 +1,32 @@
+//! Helpers for parsing the input of derive macros.
+
+use syn::{Attribute, Data, DeriveInput, Fields};
+
+/// Returns fields of the struct or an error if the input isn't a struct.
+pub fn struct_fields<'a>(input: &'a DeriveInput, derive: &str) -> syn::Result<&'a Fields> {
+    let span = match &input.data {
+        Data::Struct(data) => return Ok(&data.fields),
+        Data::Enum(data) => data.enum_token.span,
+        Data::Union(data) => data.union_token.span,
+    };
+    Err(syn::Error::new(
+        span,
+        format!("`{derive}` can only be derived for structs"),
+    ))
+}
+
+/// Returns whether the attributes contain `#[fork(clone)]`.
+pub fn has_fork_clone(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut clone = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("fork")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("clone") {
+                clone = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown fork attribute, expected `clone`"))
+            }
+        })?;
+    }
+    Ok(clone)
+}
@@ -0,0 +1,283 @@
+//! This module contains newtype helpers implementing [`Fork`], [`Update`] and [`Join`]
+//! for common aggregations.
+//!
+//! They can be embedded in a context, so the merging logic of simple values
+//! (like counters or collections) doesn't have to be written by hand.
+//!
+//! # Examples
+//! ```
+//! use node_flow::context::{Fork, Join, join::{Concat, Sum}};
+//!
+//! let mut ctx = (Sum(5u64), Concat(vec!["start"]));
+//! let mut branch1 = ctx.fork();
+//! branch1.0.0 += 2;
+//! branch1.1.0.push("branch1");
+//! let mut branch2 = ctx.fork();
+//! branch2.0.0 += 3;
+//! branch2.1.0.push("branch2");
+//!
+//! ctx.join(Box::new([branch1, branch2]));
+//! assert_eq!(ctx, (Sum(10), Concat(vec!["start", "branch1", "branch2"])));
+//! ```
+
+use std::{collections::HashMap, hash::Hash, ops::AddAssign};
+
+use super::{Fork, Join, Update};
+
+/// `Sum` adds up values of all branches.
+///
+/// Forking creates a zero (default) value, so each branch only holds its own contribution,
+/// which is added to this value on update or join.
+///
+/// # Examples
+/// ```
+/// use node_flow::context::{Join, join::Sum};
+///
+/// let mut total = Sum(5);
+/// total.join(Box::new([Sum(2), Sum(3)]));
+/// assert_eq!(total, Sum(10));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Sum<T>(pub T);
+
+impl<T: Default> Fork for Sum<T> {
+    fn fork(&self) -> Self {
+        Self(T::default())
+    }
+}
+
+impl<T: AddAssign> Update for Sum<T> {
+    fn update_from(&mut self, other: Self) {
+        self.0 += other.0;
+    }
+}
+
+impl<T: AddAssign> Join for Sum<T> {
+    fn join(&mut self, others: Box<[Self]>) {
+        for other in others {
+            self.0 += other.0;
+        }
+    }
+}
+
+/// `Max` keeps the greatest value of all branches.
+///
+/// Forking clones the current value.
+///
+/// # Examples
+/// ```
+/// use node_flow::context::{Join, join::Max};
+///
+/// let mut max = Max(5);
+/// max.join(Box::new([Max(2), Max(8)]));
+/// assert_eq!(max, Max(8));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Max<T>(pub T);
+
+impl<T: Clone> Fork for Max<T> {
+    fn fork(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<T: Ord> Update for Max<T> {
+    fn update_from(&mut self, other: Self) {
+        if other.0 > self.0 {
+            self.0 = other.0;
+        }
+    }
+}
+
+impl<T: Ord> Join for Max<T> {
+    fn join(&mut self, others: Box<[Self]>) {
+        for other in others {
+            self.update_from(other);
+        }
+    }
+}
+
+/// `Min` keeps the smallest value of all branches.
+///
+/// Forking clones the current value.
+///
+/// # Examples
+/// ```
+/// use node_flow::context::{Join, join::Min};
+///
+/// let mut min = Min(5);
+/// min.join(Box::new([Min(2), Min(8)]));
+/// assert_eq!(min, Min(2));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Min<T>(pub T);
+
+impl<T: Clone> Fork for Min<T> {
+    fn fork(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<T: Ord> Update for Min<T> {
+    fn update_from(&mut self, other: Self) {
+        if other.0 < self.0 {
+            self.0 = other.0;
+        }
+    }
+}
+
+impl<T: Ord> Join for Min<T> {
+    fn join(&mut self, others: Box<[Self]>) {
+        for other in others {
+            self.update_from(other);
+        }
+    }
+}
+
+/// `Concat` concatenates items collected by all branches.
+///
+/// Forking creates an empty [`Vec`], so each branch only holds its own items,
+/// which are appended to this value on update or join in the order of the branches.
+///
+/// # Examples
+/// ```
+/// use node_flow::context::{Join, join::Concat};
+///
+/// let mut items = Concat(vec![1]);
+/// items.join(Box::new([Concat(vec![2, 3]), Concat(vec![4])]));
+/// assert_eq!(items, Concat(vec![1, 2, 3, 4]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Concat<T>(pub Vec<T>);
+
+impl<T> Fork for Concat<T> {
+    fn fork(&self) -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T> Update for Concat<T> {
+    fn update_from(&mut self, other: Self) {
+        self.0.extend(other.0);
+    }
+}
+
+impl<T> Join for Concat<T> {
+    fn join(&mut self, others: Box<[Self]>) {
+        for other in others {
+            self.0.extend(other.0);
+        }
+    }
+}
+
+/// `Merge` merges entries of [`HashMap`]s of all branches.
+///
+/// Forking clones the current map.
+/// On update or join entries of the others are inserted into this map,
+/// so values of later branches overwrite values of earlier ones with the same key.
+///
+/// # Examples
+/// ```
+/// use node_flow::context::{Join, join::Merge};
+/// use std::collections::HashMap;
+///
+/// let mut map = Merge(HashMap::from([("a", 1)]));
+/// map.join(Box::new([
+///     Merge(HashMap::from([("a", 1), ("b", 2)])),
+///     Merge(HashMap::from([("b", 3)])),
+/// ]));
+/// assert_eq!(map.0, HashMap::from([("a", 1), ("b", 3)]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Merge<K, V>(pub HashMap<K, V>)
+where
+    K: Eq + Hash;
+
+impl<K, V> Fork for Merge<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    fn fork(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<K: Eq + Hash, V> Update for Merge<K, V> {
+    fn update_from(&mut self, other: Self) {
+        self.0.extend(other.0);
+    }
+}
+
+impl<K: Eq + Hash, V> Join for Merge<K, V> {
+    fn join(&mut self, others: Box<[Self]>) {
+        for other in others {
+            self.0.extend(other.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::{Concat, Max, Merge, Min, Sum};
+    use crate::context::{Fork, Join, Update};
+
+    #[test]
+    fn test_sum() {
+        let mut sum = Sum(5u64);
+        let mut forked = sum.fork();
+        assert_eq!(forked, Sum(0));
+        forked.0 += 4;
+        sum.update_from(forked);
+        assert_eq!(sum, Sum(9));
+        sum.join(Box::new([Sum(1), Sum(2)]));
+        assert_eq!(sum, Sum(12));
+    }
+
+    #[test]
+    fn test_max_min() {
+        let mut max = Max(5);
+        assert_eq!(max.fork(), Max(5));
+        max.update_from(Max(3));
+        assert_eq!(max, Max(5));
+        max.join(Box::new([Max(7), Max(6)]));
+        assert_eq!(max, Max(7));
+
+        let mut min = Min(5);
+        assert_eq!(min.fork(), Min(5));
+        min.update_from(Min(3));
+        assert_eq!(min, Min(3));
+        min.join(Box::new([Min(7), Min(1)]));
+        assert_eq!(min, Min(1));
+    }
+
+    #[test]
+    fn test_concat() {
+        let mut items = Concat(vec![1]);
+        assert_eq!(items.fork(), Concat(vec![]));
+        items.update_from(Concat(vec![2]));
+        items.join(Box::new([
+            Concat(vec![3, 4]),
+            Concat(vec![]),
+            Concat(vec![5]),
+        ]));
+        assert_eq!(items, Concat(vec![1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut map = Merge(HashMap::from([("a", 1)]));
+        let mut forked = map.fork();
+        assert_eq!(forked, map);
+        forked.0.insert("b", 2);
+        map.update_from(forked);
+        assert_eq!(map.0, HashMap::from([("a", 1), ("b", 2)]));
+        map.join(Box::new([
+            Merge(HashMap::from([("a", 3)])),
+            Merge(HashMap::from([("a", 4), ("c", 5)])),
+        ]));
+        assert_eq!(map.0, HashMap::from([("a", 4), ("b", 2), ("c", 5)]));
+    }
+}
@@ -0,0 +1,194 @@
+//! This module contains traits and types used for contextual logging.
+//!
+//! For details, see the documentation of [`LoggerProvider`] and [`Logger`].
+
+use std::fmt::Display;
+
+/// Severity of a log message.
+///
+/// See also [`Logger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    /// Very detailed information useful only when tracing execution.
+    Trace,
+    /// Information useful for debugging.
+    Debug,
+    /// General information about the execution.
+    Info,
+    /// Something unexpected happened, but the execution can continue.
+    Warn,
+    /// Something failed.
+    Error,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        })
+    }
+}
+
+/// The `Logger` trait represents a handle used for emitting log messages.
+///
+/// Loggers can create child loggers scoped to some part of the flow (for example a node),
+/// so that messages emitted from different nodes of the same flow (or request) can be correlated.
+///
+/// # Examples
+/// ```
+/// use node_flow::context::logger::{Level, Logger};
+///
+/// #[derive(Clone)]
+/// struct PrefixLogger(String);
+///
+/// impl Logger for PrefixLogger {
+///     fn child(&self, scope: &str) -> Self {
+///         Self(format!("{}/{scope}", self.0))
+///     }
+///
+///     fn log(&self, level: Level, message: &str) {
+///         eprintln!("{level} {}: {message}", self.0);
+///     }
+/// }
+///
+/// let logger = PrefixLogger("request-1".to_owned());
+/// logger.child("MyNode").log(Level::Info, "started");
+/// ```
+pub trait Logger {
+    /// Creates a child logger scoped to `scope`.
+    ///
+    /// Messages emitted through the child logger should carry the scope of this logger and the new `scope`.
+    #[must_use]
+    fn child(&self, scope: &str) -> Self
+    where
+        Self: Sized;
+
+    /// Emits a log message with the given [`Level`].
+    fn log(&self, level: Level, message: &str);
+}
+
+/// The `LoggerProvider` trait is used for accessing a [`Logger`] stored in a context.
+///
+/// This allows nodes to emit correlated log messages without taking a logger as a parameter.
+/// Implementors should make sure that the logger is preserved across [`Fork`](crate::context::Fork),
+/// so that forked contexts (children) inherit the logger of their parent.
+///
+/// # Examples
+/// ```
+/// use node_flow::context::{Fork, logger::{Level, Logger, LoggerProvider, StdoutLogger}};
+/// use node_flow::node::{Node, NodeOutput};
+///
+/// struct ExampleCtx {
+///     logger: StdoutLogger,
+/// }
+///
+/// impl Fork for ExampleCtx {
+///     fn fork(&self) -> Self {
+///         Self { logger: self.logger.clone() }
+///     }
+/// }
+///
+/// impl LoggerProvider for ExampleCtx {
+///     type Logger = StdoutLogger;
+///
+///     fn logger(&self) -> &Self::Logger {
+///         &self.logger
+///     }
+/// }
+///
+/// struct ExampleNode;
+///
+/// impl<Ctx: LoggerProvider + Send> Node<u8, NodeOutput<u8>, (), Ctx> for ExampleNode {
+///     async fn run(&mut self, input: u8, ctx: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         ctx.logger().child("ExampleNode").log(Level::Info, "running");
+///         Ok(NodeOutput::Ok(input))
+///     }
+/// }
+/// ```
+pub trait LoggerProvider {
+    /// The type of logger provided by this context.
+    type Logger: Logger;
+
+    /// Returns a reference to the logger.
+    fn logger(&self) -> &Self::Logger;
+}
+
+/// A trivial [`Logger`] that writes messages to the standard output.
+///
+/// Each message is prefixed with its level and scope (for example `[INFO flow/node] message`).
+///
+/// See also [`Logger`], [`LoggerProvider`].
+#[derive(Debug, Clone, Default)]
+pub struct StdoutLogger {
+    scope: String,
+}
+
+impl StdoutLogger {
+    /// Creates a new [`StdoutLogger`] with the given root scope.
+    #[must_use]
+    pub fn new(scope: impl Into<String>) -> Self {
+        Self {
+            scope: scope.into(),
+        }
+    }
+
+    /// Returns the scope of this logger.
+    #[must_use]
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+}
+
+impl Logger for StdoutLogger {
+    fn child(&self, scope: &str) -> Self {
+        if self.scope.is_empty() {
+            return Self::new(scope);
+        }
+        Self::new(format!("{}/{scope}", self.scope))
+    }
+
+    fn log(&self, level: Level, message: &str) {
+        println!("[{level} {}] {message}", self.scope);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Logger, LoggerProvider, StdoutLogger};
+    use crate::context::Fork;
+
+    struct Ctx(StdoutLogger);
+
+    impl Fork for Ctx {
+        fn fork(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+
+    impl LoggerProvider for Ctx {
+        type Logger = StdoutLogger;
+
+        fn logger(&self) -> &Self::Logger {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn test_child_scope() {
+        let logger = StdoutLogger::default();
+        let child = logger.child("flow").child("node");
+        assert_eq!(child.scope(), "flow/node");
+    }
+
+    #[test]
+    fn test_fork_inherits() {
+        let ctx = Ctx(StdoutLogger::new("request-1"));
+        let forked = ctx.fork();
+        assert_eq!(forked.logger().scope(), "request-1");
+        assert_eq!(forked.logger().child("node").scope(), "request-1/node");
+    }
+}
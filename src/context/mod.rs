@@ -1,10 +1,27 @@
 //! This module contains basic traits that should be implemented for node context.
 //!
 //! Traits in this module can be used by nodes to restrict the context type and ensure that it can perform certain functions.
-//! It also contains definitions and implementations of different types of storages.
+//! It also contains definitions and implementations of different types of storages and loggers.
 //!
 //! For details, see the documentation of each trait.
 
 mod traits;
+/// Derive macro generating an implementation of the [`Fork`] trait.
+///
+/// Every field is forked using [`Fork::fork`],
+/// fields marked with `#[fork(clone)]` are cloned instead.
+/// When the struct itself is marked with `#[fork(clone)]`, all fields are cloned.
+#[cfg(feature = "derive")]
+pub use node_flow_derive::Fork;
 pub use traits::*;
+#[cfg(feature = "tokio")]
+mod tokio_spawner;
+#[cfg(feature = "tokio")]
+pub use tokio_spawner::*;
+#[cfg(feature = "smol")]
+mod smol_spawner;
+#[cfg(feature = "smol")]
+pub use smol_spawner::*;
+pub mod join;
+pub mod logger;
 pub mod storage;
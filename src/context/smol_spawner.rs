@@ -0,0 +1,171 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use super::{Clock, Fork, Join, SpawnAsync, SpawnSync, Task};
+
+/// `SmolSpawner` is a context that spawns tasks using the [smol](https://docs.rs/smol) runtime.
+///
+/// It implements [`SpawnAsync`], [`SpawnSync`], [`Clock`], [`Fork`] and [`Join`],
+/// so it can be used directly as a context or as a building block of a custom context.
+///
+/// Asynchronous tasks are spawned onto the global executor using [`smol::spawn`]
+/// and blocking functions are run on a thread pool using [`smol::unblock`].
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::context::SmolSpawner;
+/// use node_flow::flows::Detached;
+///
+/// #[derive(Clone)]
+/// struct PrintNode;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<()>, (), Ctx> for PrintNode {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<()>, ()> {
+///         println!("Running detached task with input: {input}");
+///         Ok(NodeOutput::Ok(()))
+///     }
+/// }
+///
+/// smol::block_on(async {
+///     let mut detached = Detached::<u8, (), _>::new(PrintNode);
+///     let result = detached.run(7, &mut SmolSpawner).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(7)));
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SmolSpawner;
+
+/// `SmolTask` is a [`Task`] spawned by [`SmolSpawner`].
+///
+/// It wraps [`smol::Task`], [`Task::cancel`] drops the task, which cancels it,
+/// and [`Task::detach`] lets it run in the background.
+#[derive(Debug)]
+pub struct SmolTask<T>(smol::Task<T>);
+
+impl<T> Future for SmolTask<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.get_mut().0).poll(cx)
+    }
+}
+
+impl<T> Task<T> for SmolTask<T> {
+    fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+
+    fn cancel(self) {
+        drop(self.0);
+    }
+
+    fn detach(self) {
+        self.0.detach();
+    }
+}
+
+impl SpawnAsync for SmolSpawner {
+    fn spawn<F>(fut: F) -> impl Task<F::Output> + Send
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        SmolTask(smol::spawn(fut))
+    }
+}
+
+impl SpawnSync for SmolSpawner {
+    fn spawn_blocking<F, O>(func: F) -> impl Task<O> + Send
+    where
+        F: Fn() -> O + Send + 'static,
+        O: Send + 'static,
+    {
+        SmolTask(smol::unblock(func))
+    }
+}
+
+impl Clock for SmolSpawner {
+    async fn sleep(duration: Duration) {
+        smol::Timer::after(duration).await;
+    }
+}
+
+impl Fork for SmolSpawner {
+    fn fork(&self) -> Self {
+        Self
+    }
+}
+
+impl Join for SmolSpawner {
+    fn join(&mut self, _others: Box<[Self]>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::SmolSpawner;
+    use crate::context::{SpawnAsync, SpawnSync, Task};
+
+    async fn test<T>(spawn_fn: impl Fn(u64) -> T) -> (u64, u64)
+    where
+        T: Task<()>,
+    {
+        let mut acc = Vec::new();
+        let mut time_sum = 0;
+
+        let start = Instant::now();
+
+        for i in 0..15 {
+            let delay = i % 5 + 3;
+            time_sum += delay;
+            acc.push(spawn_fn(delay));
+        }
+        for f in acc {
+            f.await;
+        }
+
+        let took = u64::try_from(start.elapsed().as_millis()).unwrap();
+        (time_sum, took)
+    }
+
+    #[test]
+    fn test_async_smol() {
+        let (time_sum, took) = smol::block_on(test(|delay| {
+            SmolSpawner::spawn(async move {
+                smol::Timer::after(Duration::from_millis(delay)).await;
+            })
+        }));
+        println!("time_sum: {time_sum}, took: {took}");
+        assert!(time_sum > took);
+    }
+
+    #[test]
+    fn test_sync_smol() {
+        let (time_sum, took) = smol::block_on(test(|delay| {
+            SmolSpawner::spawn_blocking(move || {
+                std::thread::sleep(Duration::from_millis(delay));
+            })
+        }));
+        println!("time_sum: {time_sum}, took: {took}");
+        assert!(time_sum > took);
+    }
+
+    #[test]
+    fn test_cancel() {
+        smol::block_on(async {
+            let (sender, receiver) = std::sync::mpsc::channel::<()>();
+            let task = SmolSpawner::spawn(async move {
+                smol::Timer::after(Duration::from_millis(50)).await;
+                sender.send(()).unwrap();
+            });
+            assert!(!task.is_finished());
+            task.cancel();
+            assert!(receiver.recv().is_err());
+        });
+    }
+}
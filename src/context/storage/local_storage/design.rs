@@ -1,3 +1,5 @@
+use std::ops::DerefMut;
+
 /// Provides type-based local storage for arbitrary values.
 ///
 /// `LocalStorage` is a type-based per branch local storage,
@@ -14,6 +16,7 @@
 /// use node_flow::context::storage::LocalStorage;
 /// use std::collections::HashMap;
 /// use std::any::{TypeId, Any};
+/// use std::ops::DerefMut;
 ///
 /// struct ExampleStorage(HashMap<TypeId, Box<dyn Any>>);
 ///
@@ -26,7 +29,7 @@
 ///             .downcast_ref::<T>()
 ///     }
 ///
-///     fn get_mut<T>(&mut self) -> Option<&mut T>
+///     fn get_mut<T>(&mut self) -> Option<impl DerefMut<Target = T>>
 ///     where
 ///         T: 'static,
 ///     {
@@ -51,6 +54,21 @@
 ///             .remove(&TypeId::of::<T>())
 ///             .and_then(|boxed| boxed.downcast::<T>().ok().map(|b| *b))
 ///     }
+///
+///     fn get_or_insert_with<T>(&mut self, f: impl FnOnce() -> T) -> impl DerefMut<Target = T>
+///     where
+///         T: Clone + Send + 'static,
+///     {
+///         self.0
+///             .entry(TypeId::of::<T>())
+///             .or_insert_with(|| Box::new(f()))
+///             .downcast_mut::<T>()
+///             .unwrap()
+///     }
+///
+///     fn len(&self) -> usize {
+///         self.0.len()
+///     }
 /// }
 /// ```
 pub trait LocalStorage {
@@ -80,6 +98,9 @@ pub trait LocalStorage {
 
     /// Gets mutable reference of a value with type `T` from storage if it is present.
     ///
+    /// The value is returned behind a guard, so implementors can track whether it was actually mutated.
+    /// For example `LocalStorageImpl` marks the value as changed only when the guard is mutably dereferenced.
+    ///
     /// # Examples
     /// ```
     /// # use node_flow::context::storage::{LocalStorage, local_storage::{Merge, MergeResult, LocalStorageImpl}};
@@ -93,13 +114,13 @@ pub trait LocalStorage {
     /// let mut storage = ExampleStorage::new();
     ///
     /// storage.insert(ExampleValue(5u8));
-    /// if let Some(val) = storage.get_mut::<ExampleValue>() {
+    /// if let Some(mut val) = storage.get_mut::<ExampleValue>() {
     ///     val.0 = 15u8;
     /// }
     /// let result: Option<&ExampleValue> = storage.get();
     /// assert_eq!(result, Some(&ExampleValue(15u8)));
     /// ```
-    fn get_mut<T>(&mut self) -> Option<&mut T>
+    fn get_mut<T>(&mut self) -> Option<impl DerefMut<Target = T>>
     where
         T: 'static;
 
@@ -153,6 +174,96 @@ pub trait LocalStorage {
     fn remove<T>(&mut self) -> Option<T>
     where
         T: 'static;
+
+    /// Gets mutable reference of a value with type `T` from storage,
+    /// inserting the value returned by `f` first if it is not present.
+    ///
+    /// # Examples
+    /// ```
+    /// # use node_flow::context::storage::{LocalStorage, local_storage::{Merge, MergeResult, LocalStorageImpl}};
+    /// # type ExampleStorage = LocalStorageImpl;
+    /// #[derive(Debug, PartialEq, Eq, Clone)]
+    /// struct ExampleValue(u8);
+    /// impl Merge for ExampleValue // ...
+    /// # {
+    /// #     fn merge(parent: Option<&Self>, others: Box<[Self]>) -> MergeResult<Self> { todo!() }
+    /// # }
+    /// let mut storage = ExampleStorage::new();
+    ///
+    /// storage.get_or_insert_with(|| ExampleValue(5u8)).0 += 1;
+    /// let val = storage.get_or_insert_with(|| ExampleValue(50u8));
+    /// assert_eq!(*val, ExampleValue(6u8));
+    /// ```
+    fn get_or_insert_with<T>(&mut self, f: impl FnOnce() -> T) -> impl DerefMut<Target = T>
+    where
+        T: Merge + Clone + Send + 'static;
+
+    /// Returns `true` if a value with type `T` is present in storage.
+    ///
+    /// # Examples
+    /// ```
+    /// # use node_flow::context::storage::{LocalStorage, local_storage::{Merge, MergeResult, LocalStorageImpl}};
+    /// # type ExampleStorage = LocalStorageImpl;
+    /// #[derive(Debug, PartialEq, Eq, Clone)]
+    /// struct ExampleValue(u8);
+    /// impl Merge for ExampleValue // ...
+    /// # {
+    /// #     fn merge(parent: Option<&Self>, others: Box<[Self]>) -> MergeResult<Self> { todo!() }
+    /// # }
+    /// let mut storage = ExampleStorage::new();
+    ///
+    /// assert!(!storage.contains::<ExampleValue>());
+    /// storage.insert(ExampleValue(5u8));
+    /// assert!(storage.contains::<ExampleValue>());
+    /// ```
+    fn contains<T>(&self) -> bool
+    where
+        T: 'static,
+    {
+        self.get::<T>().is_some()
+    }
+
+    /// Returns the number of values in storage.
+    ///
+    /// # Examples
+    /// ```
+    /// # use node_flow::context::storage::{LocalStorage, local_storage::{Merge, MergeResult, LocalStorageImpl}};
+    /// # type ExampleStorage = LocalStorageImpl;
+    /// #[derive(Debug, PartialEq, Eq, Clone)]
+    /// struct ExampleValue(u8);
+    /// impl Merge for ExampleValue // ...
+    /// # {
+    /// #     fn merge(parent: Option<&Self>, others: Box<[Self]>) -> MergeResult<Self> { todo!() }
+    /// # }
+    /// let mut storage = ExampleStorage::new();
+    ///
+    /// storage.insert(ExampleValue(5u8));
+    /// storage.insert(ExampleValue(15u8));
+    /// assert_eq!(storage.len(), 1);
+    /// ```
+    fn len(&self) -> usize;
+
+    /// Returns `true` if storage contains no values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use node_flow::context::storage::{LocalStorage, local_storage::{Merge, MergeResult, LocalStorageImpl}};
+    /// # type ExampleStorage = LocalStorageImpl;
+    /// #[derive(Debug, PartialEq, Eq, Clone)]
+    /// struct ExampleValue(u8);
+    /// impl Merge for ExampleValue // ...
+    /// # {
+    /// #     fn merge(parent: Option<&Self>, others: Box<[Self]>) -> MergeResult<Self> { todo!() }
+    /// # }
+    /// let mut storage = ExampleStorage::new();
+    ///
+    /// assert!(storage.is_empty());
+    /// storage.insert(ExampleValue(5u8));
+    /// assert!(!storage.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 /// Represents the result of merging multiple instances of a type during context merging.
@@ -2,6 +2,7 @@ use std::{
     any::{Any, TypeId},
     collections::{HashMap, HashSet},
     fmt::Debug,
+    ops::{Deref, DerefMut},
 };
 
 use crate::context::{
@@ -51,6 +52,29 @@ impl Clone for Box<dyn StorageItem> {
     }
 }
 
+/// Guard returned by [`LocalStorageImpl::get_mut`] and [`LocalStorageImpl::get_or_insert_with`].
+///
+/// The value is marked as changed only when it is mutably dereferenced.
+struct ChangeGuard<'a, T> {
+    val: &'a mut T,
+    changed: &'a mut HashSet<TypeId>,
+}
+
+impl<T> Deref for ChangeGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.val
+    }
+}
+
+impl<T: 'static> DerefMut for ChangeGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.changed.insert(TypeId::of::<T>());
+        self.val
+    }
+}
+
 /// An implementation of type-based local storage.
 ///
 /// See [`LocalStorage`] for more info.\
@@ -77,6 +101,170 @@ impl LocalStorageImpl {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Takes a snapshot of the current state of the storage.
+    ///
+    /// All stored items are cloned, so later changes to the storage don't affect the snapshot.
+    /// The snapshot can be restored using [`LocalStorageImpl::restore`].
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::context::storage::local_storage::{LocalStorage, LocalStorageImpl, Merge, MergeResult};
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// struct Counter(u32);
+    /// impl Merge for Counter // ...
+    /// # {
+    /// #     fn merge(_: Option<&Self>, others: Box<[Self]>) -> MergeResult<Self> {
+    /// #         MergeResult::ReplaceOrInsert(others.into_iter().last().unwrap())
+    /// #     }
+    /// # }
+    ///
+    /// let mut storage = LocalStorageImpl::new();
+    /// storage.insert(Counter(1));
+    ///
+    /// let snapshot = storage.snapshot();
+    /// storage.insert(Counter(2));
+    /// assert_eq!(storage.get::<Counter>(), Some(&Counter(2)));
+    ///
+    /// storage.restore(snapshot);
+    /// assert_eq!(storage.get::<Counter>(), Some(&Counter(1)));
+    /// ```
+    #[must_use]
+    pub fn snapshot(&self) -> LocalStorageSnapshot {
+        LocalStorageSnapshot {
+            inner: self.inner.clone(),
+            changed: self.changed.clone(),
+        }
+    }
+
+    /// Restores the storage to the state captured by [`LocalStorageImpl::snapshot`].
+    ///
+    /// Both the stored items and the tracking of changed items are replaced,
+    /// so changes made after the snapshot was taken are not merged when this storage is joined.
+    pub fn restore(&mut self, snapshot: LocalStorageSnapshot) {
+        self.inner = snapshot.inner;
+        self.changed = snapshot.changed;
+    }
+
+    /// Inserts `val` into the storage for the lifetime of the returned [`ScopeGuard`].
+    ///
+    /// When the guard is dropped, the value is removed and the value that was stored before
+    /// (if any) is inserted back.
+    /// The value is then marked as changed only if it was marked as changed before the scope,
+    /// so a scoped value is never merged into the parent on [`Join`].
+    /// The storage can still be accessed through the guard while it is alive.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::context::storage::local_storage::{LocalStorage, LocalStorageImpl, Merge, MergeResult};
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// struct Scratch(u32);
+    /// impl Merge for Scratch // ...
+    /// # {
+    /// #     fn merge(_: Option<&Self>, others: Box<[Self]>) -> MergeResult<Self> {
+    /// #         MergeResult::ReplaceOrInsert(others.into_iter().last().unwrap())
+    /// #     }
+    /// # }
+    ///
+    /// let mut storage = LocalStorageImpl::new();
+    /// {
+    ///     let scope = storage.scope(Scratch(1));
+    ///     assert_eq!(scope.get::<Scratch>(), Some(&Scratch(1)));
+    /// }
+    /// assert!(!storage.contains::<Scratch>());
+    /// ```
+    pub fn scope<T>(&mut self, val: T) -> ScopeGuard<'_, T>
+    where
+        T: Merge + Clone + Send + 'static,
+    {
+        let was_changed = self.changed.contains(&TypeId::of::<T>());
+        let previous = self.insert(val);
+        ScopeGuard {
+            storage: self,
+            previous,
+            was_changed,
+        }
+    }
+}
+
+/// Guard returned by [`LocalStorageImpl::scope`].
+///
+/// On drop it removes the scoped value and restores the previous one (if any).
+/// It dereferences to the underlying [`LocalStorageImpl`].
+pub struct ScopeGuard<'a, T>
+where
+    T: Merge + Clone + Send + 'static,
+{
+    storage: &'a mut LocalStorageImpl,
+    previous: Option<T>,
+    was_changed: bool,
+}
+
+impl<T> Debug for ScopeGuard<'_, T>
+where
+    T: Merge + Clone + Send + 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScopeGuard").finish_non_exhaustive()
+    }
+}
+
+impl<T> Deref for ScopeGuard<'_, T>
+where
+    T: Merge + Clone + Send + 'static,
+{
+    type Target = LocalStorageImpl;
+
+    fn deref(&self) -> &Self::Target {
+        self.storage
+    }
+}
+
+impl<T> DerefMut for ScopeGuard<'_, T>
+where
+    T: Merge + Clone + Send + 'static,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.storage
+    }
+}
+
+impl<T> Drop for ScopeGuard<'_, T>
+where
+    T: Merge + Clone + Send + 'static,
+{
+    fn drop(&mut self) {
+        let key = TypeId::of::<T>();
+        match self.previous.take() {
+            Some(previous) => {
+                self.storage.inner.insert(key, Box::new(previous));
+            }
+            None => {
+                self.storage.inner.remove(&key);
+            }
+        }
+        if !self.was_changed {
+            self.storage.changed.remove(&key);
+        }
+    }
+}
+
+/// A snapshot of [`LocalStorageImpl`] state.
+///
+/// Created by [`LocalStorageImpl::snapshot`] and restored by [`LocalStorageImpl::restore`].
+#[derive(Clone)]
+pub struct LocalStorageSnapshot {
+    inner: HashMap<TypeId, Box<dyn StorageItem>>,
+    changed: HashSet<TypeId>,
+}
+
+impl Debug for LocalStorageSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalStorageSnapshot")
+            .finish_non_exhaustive()
+    }
 }
 
 impl LocalStorage for LocalStorageImpl {
@@ -90,14 +278,16 @@ impl LocalStorage for LocalStorageImpl {
         })
     }
 
-    fn get_mut<T>(&mut self) -> Option<&mut T>
+    fn get_mut<T>(&mut self) -> Option<impl DerefMut<Target = T>>
     where
         T: 'static,
     {
         self.inner.get_mut(&TypeId::of::<T>()).map(|val| {
-            self.changed.insert(TypeId::of::<T>());
             let any_debug_ref: &mut dyn Any = &mut **val;
-            any_debug_ref.downcast_mut::<T>().unwrap()
+            ChangeGuard {
+                val: any_debug_ref.downcast_mut::<T>().unwrap(),
+                changed: &mut self.changed,
+            }
         })
     }
 
@@ -120,6 +310,32 @@ impl LocalStorage for LocalStorageImpl {
             *(val as Box<dyn Any>).downcast::<T>().unwrap()
         })
     }
+
+    fn get_or_insert_with<T>(&mut self, f: impl FnOnce() -> T) -> impl DerefMut<Target = T>
+    where
+        T: Merge + Clone + Send + 'static,
+    {
+        let val = self.inner.entry(TypeId::of::<T>()).or_insert_with(|| {
+            self.changed.insert(TypeId::of::<T>());
+            Box::new(f())
+        });
+        let any_ref: &mut dyn Any = &mut **val;
+        ChangeGuard {
+            val: any_ref.downcast_mut::<T>().unwrap(),
+            changed: &mut self.changed,
+        }
+    }
+
+    fn contains<T>(&self) -> bool
+    where
+        T: 'static,
+    {
+        self.inner.contains_key(&TypeId::of::<T>())
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
 }
 
 impl Fork for LocalStorageImpl {
@@ -300,4 +516,147 @@ pub mod tests {
         let res = parent.get::<MyVal>();
         assert_eq!(res.unwrap().0, "bbbcccdddaaa".to_owned());
     }
+
+    #[test]
+    fn test_get_or_insert_with() {
+        let mut parent = LocalStorageImpl::new();
+        let mut child = parent.fork();
+        assert_eq!(child.get_or_insert_with(|| MyVal("a".to_owned())).0, "a");
+        child
+            .get_or_insert_with(|| MyVal("c".to_owned()))
+            .0
+            .push('b');
+        assert_eq!(child.get::<MyVal>(), Some(&MyVal("ab".to_owned())));
+
+        // changed value is merged into the parent
+        parent.join(Box::new([child]));
+        assert_eq!(parent.get::<MyVal>(), Some(&MyVal("ab".to_owned())));
+    }
+
+    #[test]
+    fn test_introspection() {
+        let mut s = LocalStorageImpl::new();
+        assert!(s.is_empty());
+        assert_eq!(s.len(), 0);
+        assert!(!s.contains::<MyVal>());
+
+        s.insert(MyVal("a".to_owned()));
+        s.insert(MyVal("b".to_owned()));
+        assert!(!s.is_empty());
+        assert_eq!(s.len(), 1);
+        assert!(s.contains::<MyVal>());
+
+        s.remove::<MyVal>();
+        assert!(s.is_empty());
+        assert!(!s.contains::<MyVal>());
+
+        // introspection doesn't mark anything as changed
+        s.changed.clear();
+        s.insert(MyVal("c".to_owned()));
+        s.changed.clear();
+        let _ = (s.len(), s.is_empty(), s.contains::<MyVal>());
+        assert!(s.changed.is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        #[derive(Clone)]
+        struct Flag;
+
+        impl Merge for Flag {
+            fn merge(_parent: Option<&Self>, _others: Box<[Self]>) -> MergeResult<Self> {
+                MergeResult::ReplaceOrInsert(Self)
+            }
+        }
+
+        let mut parent = LocalStorageImpl::new();
+        parent.insert(MyVal("a".to_owned()));
+        let mut child = parent.fork();
+        let snapshot = child.snapshot();
+
+        child.get_mut::<MyVal>().unwrap().0.push('b');
+        child.insert(Flag);
+        assert_eq!(child.get::<MyVal>(), Some(&MyVal("ab".to_owned())));
+        assert_eq!(child.len(), 2);
+
+        child.restore(snapshot.clone());
+        assert_eq!(child.get::<MyVal>(), Some(&MyVal("a".to_owned())));
+        assert!(!child.contains::<Flag>());
+        assert!(child.changed.is_empty());
+
+        // snapshot can be restored repeatedly
+        child.remove::<MyVal>();
+        child.restore(snapshot);
+        assert_eq!(child.get::<MyVal>(), Some(&MyVal("a".to_owned())));
+
+        // rolled back changes are not merged
+        parent.join(Box::new([child]));
+        assert_eq!(parent.get::<MyVal>(), Some(&MyVal("a".to_owned())));
+        assert_eq!(parent.len(), 1);
+    }
+
+    #[test]
+    fn test_scope() {
+        let mut s = LocalStorageImpl::new();
+        {
+            let mut scope = s.scope(MyVal("a".to_owned()));
+            assert_eq!(scope.get::<MyVal>(), Some(&MyVal("a".to_owned())));
+            scope.get_mut::<MyVal>().unwrap().0.push('b');
+        }
+        assert!(!s.contains::<MyVal>());
+
+        s.insert(MyVal("prior".to_owned()));
+        {
+            let scope = s.scope(MyVal("tmp".to_owned()));
+            assert_eq!(scope.get::<MyVal>(), Some(&MyVal("tmp".to_owned())));
+        }
+        assert_eq!(s.get::<MyVal>(), Some(&MyVal("prior".to_owned())));
+    }
+
+    #[test]
+    fn test_scope_not_merged() {
+        let mut parent = LocalStorageImpl::new();
+        parent.insert(MyVal("a".to_owned()));
+
+        let mut child = parent.fork();
+        {
+            let mut scope = child.scope(MyVal("tmp".to_owned()));
+            scope.get_mut::<MyVal>().unwrap().0.push('b');
+        }
+        assert_eq!(child.get::<MyVal>(), Some(&MyVal("a".to_owned())));
+        parent.join(Box::new([child]));
+        assert_eq!(parent.get::<MyVal>(), Some(&MyVal("a".to_owned())));
+
+        // value not present before the scope
+        let mut empty_parent = LocalStorageImpl::new();
+        let mut child = empty_parent.fork();
+        drop(child.scope(MyVal("tmp".to_owned())));
+        empty_parent.join(Box::new([child]));
+        assert!(!empty_parent.contains::<MyVal>());
+
+        // value changed before the scope is still merged
+        let mut child = parent.fork();
+        child.get_mut::<MyVal>().unwrap().0.push('c');
+        drop(child.scope(MyVal("tmp".to_owned())));
+        parent.join(Box::new([child]));
+        assert_eq!(parent.get::<MyVal>(), Some(&MyVal("aac".to_owned())));
+    }
+
+    #[test]
+    fn test_get_mut_read_only() {
+        let mut parent = LocalStorageImpl::new();
+        parent.insert(MyVal("a".to_owned()));
+        let mut child = parent.fork();
+        assert_eq!(child.get_mut::<MyVal>().unwrap().0, "a");
+        assert_eq!(child.get_or_insert_with(MyVal::default).0, "a");
+
+        // value was only read, so it is not merged
+        parent.join(Box::new([child]));
+        assert_eq!(parent.get::<MyVal>(), Some(&MyVal("a".to_owned())));
+
+        let mut child = parent.fork();
+        child.get_mut::<MyVal>().unwrap().0.push('b');
+        parent.join(Box::new([child]));
+        assert_eq!(parent.get::<MyVal>(), Some(&MyVal("aab".to_owned())));
+    }
 }
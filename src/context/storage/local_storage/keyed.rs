@@ -0,0 +1,153 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use super::{Merge, MergeResult};
+
+/// Wrapper that stores a value of type `T` under a key type `K`.
+///
+/// [`LocalStorage`](super::LocalStorage) holds at most one value per type.
+/// Wrapping values in `Keyed` with different key types allows multiple values
+/// of the same type to coexist, since `Keyed<A, T>` and `Keyed<B, T>` are different types.
+///
+/// Merging is delegated to `T`, so keyed values behave in fork-join the same way as plain ones.
+///
+/// # Examples
+/// ```
+/// use node_flow::context::storage::{LocalStorage, local_storage::{Keyed, LocalStorageImpl, Merge, MergeResult}};
+///
+/// #[derive(Debug, PartialEq, Eq, Clone)]
+/// struct Counter(u32);
+/// impl Merge for Counter // ...
+/// # {
+/// #     fn merge(parent: Option<&Self>, others: Box<[Self]>) -> MergeResult<Self> { todo!() }
+/// # }
+///
+/// // Key types
+/// struct Requests;
+/// struct Errors;
+///
+/// let mut storage = LocalStorageImpl::new();
+/// storage.insert(Keyed::<Requests, _>::new(Counter(10)));
+/// storage.insert(Keyed::<Errors, _>::new(Counter(2)));
+///
+/// let requests = storage.get::<Keyed<Requests, Counter>>().unwrap();
+/// let errors = storage.get::<Keyed<Errors, Counter>>().unwrap();
+/// assert_eq!(**requests, Counter(10));
+/// assert_eq!(**errors, Counter(2));
+/// ```
+pub struct Keyed<K, T> {
+    _key: PhantomData<fn() -> K>,
+    value: T,
+}
+
+impl<K, T> Keyed<K, T> {
+    /// Wraps `value` under the key type `K`.
+    pub const fn new(value: T) -> Self {
+        Self {
+            _key: PhantomData,
+            value,
+        }
+    }
+
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<K, T> Deref for Keyed<K, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<K, T> DerefMut for Keyed<K, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<K, T> Debug for Keyed<K, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Keyed")
+            .field("key", &std::any::type_name::<K>())
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<K, T> Clone for Keyed<K, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<K, T> PartialEq for Keyed<K, T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<K, T> Eq for Keyed<K, T> where T: Eq {}
+
+impl<K, T> Merge for Keyed<K, T>
+where
+    T: Merge,
+{
+    fn merge(parent: Option<&Self>, others: Box<[Self]>) -> MergeResult<Self> {
+        let others = others.into_iter().map(Self::into_inner).collect();
+        match T::merge(parent.map(|parent| &parent.value), others) {
+            MergeResult::KeepParent => MergeResult::KeepParent,
+            MergeResult::ReplaceOrInsert(value) => MergeResult::ReplaceOrInsert(Self::new(value)),
+            MergeResult::Remove => MergeResult::Remove,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Keyed;
+    use crate::context::{
+        Fork, Join,
+        storage::local_storage::{LocalStorage, LocalStorageImpl, tests::MyVal},
+    };
+
+    struct A;
+    struct B;
+
+    #[test]
+    fn test_keyed() {
+        let mut parent = LocalStorageImpl::new();
+        parent.insert(Keyed::<A, _>::new(MyVal("a".to_owned())));
+        parent.insert(Keyed::<B, _>::new(MyVal("b".to_owned())));
+        assert_eq!(parent.len(), 2);
+
+        let mut child1 = parent.fork();
+        child1.get_mut::<Keyed<A, MyVal>>().unwrap().0.push('1');
+        let mut child2 = parent.fork();
+        child2.get_mut::<Keyed<A, MyVal>>().unwrap().0.push('2');
+        parent.join(Box::new([child1, child2]));
+
+        assert_eq!(parent.get::<Keyed<A, MyVal>>().unwrap().0, "aa1a2");
+        assert_eq!(parent.get::<Keyed<B, MyVal>>().unwrap().0, "b");
+        assert!(parent.get::<MyVal>().is_none());
+        assert_eq!(
+            parent.remove::<Keyed<B, MyVal>>().map(Keyed::into_inner),
+            Some(MyVal("b".to_owned()))
+        );
+    }
+}
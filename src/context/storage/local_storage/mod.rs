@@ -4,3 +4,5 @@ mod implementation;
 pub use implementation::*;
 mod design;
 pub use design::*;
+mod keyed;
+pub use keyed::*;
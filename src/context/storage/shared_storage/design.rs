@@ -58,6 +58,10 @@ use std::ops::{Deref, DerefMut};
 ///         async { None }
 ///     }
 ///
+///     fn insert_if_absent<T>(&mut self, _val: T) -> impl Future<Output = bool> + Send {
+///         async { true }
+///     }
+///
 ///     fn insert_with_if_absent<T, E>(
 ///         &self,
 ///         fut: impl Future<Output = Result<T, E>> + Send,
@@ -68,6 +72,17 @@ use std::ops::{Deref, DerefMut};
 ///         }
 ///     }
 ///
+///     fn get_or_insert_with<T>(
+///         &self,
+///         fut: impl Future<Output = T> + Send,
+///     ) -> impl Future<Output = impl Deref<Target = T>> + Send {
+///         async { Guard(fut.await) }
+///     }
+///
+///     fn update<T>(&self, _f: impl FnOnce(&mut T) + Send) -> impl Future<Output = bool> + Send {
+///         async { false }
+///     }
+///
 ///     fn remove<T>(&mut self) -> impl Future<Output = Option<T>> + Send {
 ///         async { None }
 ///     }
@@ -162,6 +177,34 @@ pub trait SharedStorage {
     where
         T: Send + Sync + 'static;
 
+    /// Inserts value with type `T` to storage if it doesn't contain it.
+    ///
+    /// Returns `true` if the value was inserted and `false` if the storage already contained a value with type `T`.
+    /// Unlike [`SharedStorage::insert_with_if_absent`] it takes the value directly.
+    ///
+    /// # Examples
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread()
+    /// #     .enable_all()
+    /// #     .build()
+    /// #     .unwrap()
+    /// #     .block_on(async {
+    /// # use node_flow::context::storage::{SharedStorage, shared_storage::SharedStorageImpl};
+    /// # type ExampleStorage = SharedStorageImpl;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct ExampleValue(u8);
+    /// let mut storage = ExampleStorage::new();
+    ///
+    /// assert!(storage.insert_if_absent(ExampleValue(5u8)).await);
+    /// assert!(!storage.insert_if_absent(ExampleValue(15u8)).await);
+    /// let result = storage.remove().await;
+    /// assert_eq!(result, Some(ExampleValue(5u8)));
+    /// # });
+    /// ```
+    fn insert_if_absent<T>(&mut self, val: T) -> impl Future<Output = bool> + Send
+    where
+        T: Send + Sync + 'static;
+
     /// Inserts value with type `T` to storage if it doesn't contain it.
     ///
     /// # Examples
@@ -195,6 +238,68 @@ pub trait SharedStorage {
         T: Send + Sync + 'static,
         E: Send;
 
+    /// Gets reference of a value with type `T` from storage, inserting the output of `fut` if it is absent.
+    ///
+    /// Only one caller computes the value.
+    /// Other callers wait until the value is inserted and then read it.
+    ///
+    /// # Examples
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread()
+    /// #     .enable_all()
+    /// #     .build()
+    /// #     .unwrap()
+    /// #     .block_on(async {
+    /// # use node_flow::context::storage::{SharedStorage, shared_storage::SharedStorageImpl};
+    /// # type ExampleStorage = SharedStorageImpl;
+    /// use std::ops::Deref;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct ExampleValue(u8);
+    /// let storage = ExampleStorage::new();
+    ///
+    /// let result = storage.get_or_insert_with(async { ExampleValue(5u8) }).await;
+    /// assert_eq!(*result, ExampleValue(5u8));
+    /// drop(result);
+    /// let result = storage.get_or_insert_with(async { ExampleValue(15u8) }).await;
+    /// assert_eq!(*result, ExampleValue(5u8));
+    /// # });
+    /// ```
+    fn get_or_insert_with<T>(
+        &self,
+        fut: impl Future<Output = T> + Send,
+    ) -> impl Future<Output = impl Deref<Target = T>> + Send
+    where
+        T: Send + Sync + 'static;
+
+    /// Applies `f` to the value with type `T` if it is present in the storage.
+    ///
+    /// The whole read-modify-write is atomic with respect to other branches accessing the same value.
+    /// Unlike [`SharedStorage::get_mut`] it only needs a shared reference.
+    ///
+    /// Returns `true` if the value was present and `f` was applied.
+    ///
+    /// # Examples
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread()
+    /// #     .enable_all()
+    /// #     .build()
+    /// #     .unwrap()
+    /// #     .block_on(async {
+    /// # use node_flow::context::storage::{SharedStorage, shared_storage::SharedStorageImpl};
+    /// # type ExampleStorage = SharedStorageImpl;
+    /// let mut storage = ExampleStorage::new();
+    ///
+    /// assert!(!storage.update(|val: &mut u32| *val += 1).await);
+    /// let _ = storage.insert(5u32).await;
+    /// assert!(storage.update(|val: &mut u32| *val += 1).await);
+    /// let guard = storage.get::<u32>().await;
+    /// assert_eq!(guard.as_deref(), Some(&6));
+    /// # });
+    /// ```
+    fn update<T>(&self, f: impl FnOnce(&mut T) + Send) -> impl Future<Output = bool> + Send
+    where
+        T: 'static;
+
     /// Removes and returns value with type `T` from storage if it is present.
     ///
     /// # Examples
@@ -6,7 +6,7 @@ use std::{
     sync::{Arc, Mutex},
 };
 
-use async_lock::RwLock;
+use async_lock::{RwLock, RwLockUpgradableReadGuardArc, RwLockWriteGuardArc};
 use futures_util::FutureExt;
 
 use crate::context::{Fork, Join, Update, storage::shared_storage::SharedStorage};
@@ -38,6 +38,40 @@ impl SharedStorageImpl {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Returns a snapshot of [`TypeId`]s of all values present in the storage.
+    ///
+    /// Entries which are currently locked for writing (being inserted or modified) are skipped
+    /// instead of being awaited, so the snapshot may miss them.
+    /// Entries which don't contain a value are skipped as well.
+    ///
+    /// # Panics
+    /// Panics if the inner mutex is poisoned.
+    #[must_use]
+    pub fn type_ids(&self) -> Vec<TypeId> {
+        let guard = self.inner.lock().unwrap();
+        guard
+            .iter()
+            .filter(|(_, rw_lock)| rw_lock.try_read().is_some_and(|val| val.is_some()))
+            .map(|(type_id, _)| *type_id)
+            .collect()
+    }
+
+    /// Returns the number of values present in the storage.
+    ///
+    /// See also [`SharedStorageImpl::type_ids`].
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.type_ids().len()
+    }
+
+    /// Returns `true` if the storage contains no values.
+    ///
+    /// See also [`SharedStorageImpl::type_ids`].
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 impl SharedStorage for SharedStorageImpl {
@@ -112,6 +146,33 @@ impl SharedStorage for SharedStorageImpl {
         .right_future()
     }
 
+    fn insert_if_absent<T>(&mut self, val: T) -> impl Future<Output = bool> + Send
+    where
+        T: Send + Sync + 'static,
+    {
+        let rw_lock = {
+            let mut guard = self.inner.lock().unwrap();
+            match guard.entry(TypeId::of::<T>()) {
+                Entry::Occupied(occupied_entry) => occupied_entry.get().clone(),
+                Entry::Vacant(vacant_entry) => {
+                    vacant_entry.insert(Arc::new(RwLock::new(Some(Box::new(val)))));
+                    return futures_util::future::ready(true).left_future();
+                }
+            }
+        };
+
+        async move {
+            let mut rw_lock_guard = rw_lock.write().await;
+            // value is missing when computing it failed or it was removed
+            if rw_lock_guard.is_some() {
+                return false;
+            }
+            *rw_lock_guard = Some(Box::new(val));
+            true
+        }
+        .right_future()
+    }
+
     fn insert_with_if_absent<T, E>(
         &self,
         fut: impl Future<Output = Result<T, E>> + Send,
@@ -125,7 +186,8 @@ impl SharedStorage for SharedStorageImpl {
             Entry::Occupied(_) => futures_util::future::ready(Ok(())).left_future(),
             Entry::Vacant(vacant_entry) => {
                 let rw_lock = Arc::new(RwLock::new(None));
-                let mut rw_lock_guard = rw_lock.write_arc_blocking();
+                // the lock was just created, so it can be acquired without waiting
+                let mut rw_lock_guard = rw_lock.try_write_arc().unwrap();
                 vacant_entry.insert(rw_lock);
                 async move {
                     let val = fut.await?;
@@ -137,6 +199,78 @@ impl SharedStorage for SharedStorageImpl {
         }
     }
 
+    fn get_or_insert_with<T>(
+        &self,
+        fut: impl Future<Output = T> + Send,
+    ) -> impl Future<Output = impl Deref<Target = T>> + Send
+    where
+        T: Send + Sync + 'static,
+    {
+        let rw_lock_guard = {
+            let mut guard = self.inner.lock().unwrap();
+            match guard.entry(TypeId::of::<T>()) {
+                Entry::Occupied(occupied_entry) => {
+                    let rw_lock = occupied_entry.get().clone();
+                    async move {
+                        let rw_lock_guard = rw_lock.upgradable_read_arc().await;
+                        if rw_lock_guard.is_some() {
+                            return RwLockUpgradableReadGuardArc::downgrade(rw_lock_guard);
+                        }
+                        // value is missing when computing it failed or it was removed
+                        let mut rw_lock_guard =
+                            RwLockUpgradableReadGuardArc::upgrade(rw_lock_guard).await;
+                        *rw_lock_guard = Some(Box::new(fut.await));
+                        RwLockWriteGuardArc::downgrade(rw_lock_guard)
+                    }
+                    .left_future()
+                }
+                Entry::Vacant(vacant_entry) => {
+                    let rw_lock = Arc::new(RwLock::new(None));
+                    // the lock was just created, so it can be acquired without waiting
+                    let mut rw_lock_guard = rw_lock.try_write_arc().unwrap();
+                    vacant_entry.insert(rw_lock);
+                    async move {
+                        *rw_lock_guard = Some(Box::new(fut.await));
+                        RwLockWriteGuardArc::downgrade(rw_lock_guard)
+                    }
+                    .right_future()
+                }
+            }
+        };
+
+        async move {
+            guards::ReadGuard {
+                guard: rw_lock_guard.await,
+                _item_type: std::marker::PhantomData,
+            }
+        }
+    }
+
+    fn update<T>(&self, f: impl FnOnce(&mut T) + Send) -> impl Future<Output = bool> + Send
+    where
+        T: 'static,
+    {
+        let rw_lock = {
+            let guard = self.inner.lock().unwrap();
+            guard.get(&TypeId::of::<T>()).cloned()
+        };
+
+        async move {
+            let Some(rw_lock) = rw_lock else {
+                return false;
+            };
+            rw_lock
+                .write()
+                .await
+                .as_mut()
+                .map(|val| {
+                    let any_ref: &mut dyn Any = &mut **val;
+                    f(any_ref.downcast_mut::<T>().unwrap());
+                })
+                .is_some()
+        }
+    }
+
     fn remove<T>(&mut self) -> impl Future<Output = Option<T>> + Send
     where
         T: 'static,
@@ -249,6 +383,133 @@ pub mod tests {
         assert_eq!(v.unwrap().0, "jop".to_string());
     }
 
+    #[tokio::test]
+    async fn test_type_ids() {
+        let mut storage = SharedStorageImpl::new();
+        assert!(storage.is_empty());
+        let _ = storage.insert(MyVal("test".to_owned())).await;
+        let _ = storage.insert(5u8).await;
+        let _ = storage.insert(true).await;
+        let _ = storage.remove::<bool>().await;
+
+        let type_ids = storage.type_ids();
+        assert_eq!(type_ids.len(), 2);
+        assert!(type_ids.contains(&TypeId::of::<MyVal>()));
+        assert!(type_ids.contains(&TypeId::of::<u8>()));
+        assert_eq!(storage.len(), 2);
+        assert!(!storage.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_type_ids_while_inserting() {
+        let mut storage = SharedStorageImpl::new();
+        let _ = storage.insert(5u8).await;
+        let insert = storage.insert_with_if_absent(async { Ok::<_, ()>(MyVal("init".to_owned())) });
+
+        assert_eq!(storage.type_ids(), vec![TypeId::of::<u8>()]);
+        assert_eq!(insert.await, Ok(()));
+        assert_eq!(storage.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_read_while_inserting() {
+        let storage = SharedStorageImpl::new();
+        let insert = storage.insert_with_if_absent(async {
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            Ok::<_, ()>(MyVal("init".to_owned()))
+        });
+        let read = async { storage.get::<MyVal>().await.map(|val| val.0.clone()) };
+
+        let (inserted, read) = tokio::time::timeout(
+            std::time::Duration::from_millis(500),
+            futures_util::future::join(insert, read),
+        )
+        .await
+        .expect("reading while inserting shouldn't deadlock");
+        assert_eq!(inserted, Ok(()));
+        assert_eq!(read.as_deref(), Some("init"));
+    }
+
+    #[tokio::test]
+    async fn test_insert_if_absent() {
+        let mut storage = SharedStorageImpl::new();
+        assert!(storage.insert_if_absent(MyVal("first".to_owned())).await);
+        assert!(!storage.insert_if_absent(MyVal("second".to_owned())).await);
+        assert_eq!(storage.get::<MyVal>().await.unwrap().0, "first");
+
+        // failed computation leaves an empty entry behind
+        let res = storage
+            .insert_with_if_absent(async { Err::<u8, _>("failed") })
+            .await;
+        assert_eq!(res, Err("failed"));
+        assert!(storage.insert_if_absent(5u8).await);
+        assert_eq!(storage.get::<u8>().await.as_deref(), Some(&5));
+    }
+
+    #[tokio::test]
+    async fn test_get_or_insert_with() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let storage = SharedStorageImpl::new();
+        let runs = Arc::new(AtomicUsize::new(0));
+        let tasks = (0..8)
+            .map(|i| {
+                let storage = storage.fork();
+                let runs = runs.clone();
+                tokio::spawn(async move {
+                    let val = storage
+                        .get_or_insert_with(async {
+                            runs.fetch_add(1, Ordering::SeqCst);
+                            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                            MyVal(format!("task{i}"))
+                        })
+                        .await;
+                    val.0.clone()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::new();
+        for task in tasks {
+            results.push(task.await.unwrap());
+        }
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert!(results.iter().all(|res| *res == results[0]));
+        assert_eq!(storage.get::<MyVal>().await.unwrap().0, results[0]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_update() {
+        let mut storage = SharedStorageImpl::new();
+        assert!(!storage.update(|val: &mut u64| *val += 1).await);
+        let _ = storage.insert(0u64).await;
+
+        let tasks = (0..8)
+            .map(|_| {
+                let storage = storage.fork();
+                tokio::spawn(async move {
+                    for _ in 0..100 {
+                        let updated = storage
+                            .update(|val: &mut u64| {
+                                let old = *val;
+                                std::thread::yield_now();
+                                *val = old + 1;
+                            })
+                            .await;
+                        assert!(updated);
+                        tokio::task::yield_now().await;
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(*storage.get::<u64>().await.unwrap(), 800);
+    }
+
     #[tokio::test]
     async fn test_merge() {
         let mut parent = SharedStorageImpl::new();
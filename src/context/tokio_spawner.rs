@@ -0,0 +1,162 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use super::{Clock, Fork, Join, SpawnAsync, SpawnLocal, SpawnSync, Task};
+
+/// `TokioSpawner` is a context that spawns tasks using the [Tokio](https://tokio.rs) runtime.
+///
+/// It implements [`SpawnAsync`], [`SpawnSync`], [`SpawnLocal`], [`Clock`], [`Fork`] and [`Join`],
+/// so it can be used directly as a context or as a building block of a custom context.
+///
+/// Tasks can only be spawned from within a Tokio runtime
+/// and local tasks only from within a [`LocalSet`](tokio::task::LocalSet).
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::context::TokioSpawner;
+/// use node_flow::flows::Detached;
+///
+/// #[derive(Clone)]
+/// struct PrintNode;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<()>, (), Ctx> for PrintNode {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<()>, ()> {
+///         println!("Running detached task with input: {input}");
+///         Ok(NodeOutput::Ok(()))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut detached = Detached::<u8, (), _>::new(PrintNode);
+///     let result = detached.run(7, &mut TokioSpawner).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(7)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioSpawner;
+
+/// `TokioTask` is a [`Task`] spawned by [`TokioSpawner`].
+///
+/// It wraps [`tokio::task::JoinHandle`] and [`Task::cancel`] aborts the task.
+///
+/// # Panics
+/// Awaiting the task panics if the task panicked or was aborted.
+#[derive(Debug)]
+pub struct TokioTask<T>(tokio::task::JoinHandle<T>);
+
+impl<T> Future for TokioTask<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.get_mut().0)
+            .poll(cx)
+            .map(|res| res.expect("tokio task failed"))
+    }
+}
+
+impl<T> Task<T> for TokioTask<T> {
+    fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+
+    fn cancel(self) {
+        self.0.abort();
+    }
+}
+
+impl SpawnAsync for TokioSpawner {
+    fn spawn<F>(fut: F) -> impl Task<F::Output> + Send
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        TokioTask(tokio::spawn(fut))
+    }
+}
+
+impl SpawnLocal for TokioSpawner {
+    fn spawn_local<F>(fut: F) -> impl Task<F::Output>
+    where
+        F: Future + 'static,
+        F::Output: 'static,
+    {
+        TokioTask(tokio::task::spawn_local(fut))
+    }
+}
+
+impl SpawnSync for TokioSpawner {
+    fn spawn_blocking<F, O>(func: F) -> impl Task<O> + Send
+    where
+        F: Fn() -> O + Send + 'static,
+        O: Send + 'static,
+    {
+        TokioTask(tokio::task::spawn_blocking(func))
+    }
+}
+
+impl Clock for TokioSpawner {
+    fn sleep(duration: Duration) -> impl Future<Output = ()> + Send {
+        tokio::time::sleep(duration)
+    }
+}
+
+impl Fork for TokioSpawner {
+    fn fork(&self) -> Self {
+        Self
+    }
+}
+
+impl Join for TokioSpawner {
+    fn join(&mut self, _others: Box<[Self]>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::TokioSpawner;
+    use crate::context::{SpawnAsync, SpawnLocal, SpawnSync, Task};
+
+    #[tokio::test]
+    async fn test_spawn() {
+        let task = TokioSpawner::spawn(async { 5 });
+        assert_eq!(task.await, 5);
+        let task = TokioSpawner::spawn_blocking(|| 6);
+        assert_eq!(task.await, 6);
+    }
+
+    #[tokio::test]
+    async fn test_cancel() {
+        let (sender, mut receiver) = tokio::sync::mpsc::channel::<()>(1);
+        let task = TokioSpawner::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            sender.send(()).await.unwrap();
+        });
+        assert!(!task.is_finished());
+        task.cancel();
+        assert!(receiver.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_spawn_local() {
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let value = std::rc::Rc::new(5);
+                let task = TokioSpawner::spawn_local(async move { *value });
+                assert_eq!(task.await, 5);
+            })
+            .await;
+    }
+}
@@ -22,6 +22,8 @@
 /// let forked = ctx.fork();
 /// assert_eq!(forked.id, 1);
 /// ```
+///
+/// `Fork` is implemented for tuples of up to 8 elements, where each element is forked.
 pub trait Fork {
     /// Creates a forked instance of the implementor.
     #[must_use]
@@ -51,6 +53,8 @@ pub trait Fork {
 /// a.update_from(b);
 /// assert_eq!(a.count, 8);
 /// ```
+///
+/// `Update` is implemented for tuples of up to 8 elements, where each element is updated from the corresponding element.
 pub trait Update {
     /// Merges or synchronizes the state of `other` into `self`.
     ///
@@ -85,6 +89,8 @@ pub trait Update {
 /// total.join(Box::new([Sum(2), Sum(3)]));
 /// assert_eq!(total.0, 10);
 /// ```
+///
+/// `Join` is implemented for tuples of up to 8 elements, where each element is joined with the corresponding elements of the others.
 pub trait Join: Sized {
     /// Joins the state of multiple `others` into this instance.
     ///
@@ -93,6 +99,43 @@ pub trait Join: Sized {
     fn join(&mut self, others: Box<[Self]>);
 }
 
+/// Implements [`Fork`], [`Update`] and [`Join`] for a tuple, delegating to its elements.
+macro_rules! impl_context_traits_for_tuple {
+    ($($name:ident $idx:tt $var:ident),+) => {
+        impl<$($name: Fork),+> Fork for ($($name,)+) {
+            fn fork(&self) -> Self {
+                ($(self.$idx.fork(),)+)
+            }
+        }
+
+        impl<$($name: Update),+> Update for ($($name,)+) {
+            fn update_from(&mut self, other: Self) {
+                $(self.$idx.update_from(other.$idx);)+
+            }
+        }
+
+        impl<$($name: Join),+> Join for ($($name,)+) {
+            fn join(&mut self, others: Box<[Self]>) {
+                // split others into per element slices
+                $(let mut $var = Vec::with_capacity(others.len());)+
+                for other in others {
+                    $($var.push(other.$idx);)+
+                }
+                $(self.$idx.join($var.into_boxed_slice());)+
+            }
+        }
+    };
+}
+
+impl_context_traits_for_tuple!(A 0 a);
+impl_context_traits_for_tuple!(A 0 a, B 1 b);
+impl_context_traits_for_tuple!(A 0 a, B 1 b, C 2 c);
+impl_context_traits_for_tuple!(A 0 a, B 1 b, C 2 c, D 3 d);
+impl_context_traits_for_tuple!(A 0 a, B 1 b, C 2 c, D 3 d, E 4 e);
+impl_context_traits_for_tuple!(A 0 a, B 1 b, C 2 c, D 3 d, E 4 e, F 5 f);
+impl_context_traits_for_tuple!(A 0 a, B 1 b, C 2 c, D 3 d, E 4 e, F 5 f, G 6 g);
+impl_context_traits_for_tuple!(A 0 a, B 1 b, C 2 c, D 3 d, E 4 e, F 5 f, G 6 g, H 7 h);
+
 /// The `Task` trait represents an asynchronous task.
 ///
 /// `Task` is an abstraction over a specific task in some async runtime like
@@ -129,6 +172,18 @@ pub trait Task<T>: Future<Output = T> {
     /// Be aware that tasks spawned using [`SpawnSync::spawn_blocking`] may or may not be canceled,
     /// because they are not async (it all depends on the implementor).
     fn cancel(self);
+    /// Detaches the task, letting it run to completion in the background.
+    ///
+    /// The handle is released and the output of the task is discarded.
+    /// The default implementation drops the handle, which is enough for runtimes where
+    /// dropping the handle doesn't cancel the task (like Tokio).
+    /// Implementors whose tasks are canceled on drop should override this method.
+    fn detach(self)
+    where
+        Self: Sized,
+    {
+        drop(self);
+    }
 }
 
 /// The `SpawnAsync` trait provides an interface for spawning asynchronous tasks on a runtime or executor.
@@ -161,7 +216,7 @@ pub trait Task<T>: Future<Output = T> {
 /// # }
 ///
 /// impl SpawnAsync for MyRuntime {
-///     fn spawn<F>(fut: F) -> impl Task<F::Output>
+///     fn spawn<F>(fut: F) -> impl Task<F::Output> + Send
 ///     where
 ///         F: Future + Send + 'static,
 ///         F::Output: Send + 'static,
@@ -178,7 +233,8 @@ pub trait SpawnAsync {
     ///
     /// # Returns
     /// A task handle implementing [`Task`] trait.
-    fn spawn<F>(fut: F) -> impl Task<F::Output>
+    /// The handle must be `Send`, so it can be awaited inside of other `Send` futures.
+    fn spawn<F>(fut: F) -> impl Task<F::Output> + Send
     where
         F: Future + Send + 'static,
         F::Output: Send + 'static;
@@ -213,7 +269,7 @@ pub trait SpawnAsync {
 /// # }
 ///
 /// impl SpawnSync for MyRuntime {
-///     fn spawn_blocking<F, O>(func: F) -> impl Task<O>
+///     fn spawn_blocking<F, O>(func: F) -> impl Task<O> + Send
 ///     where
 ///         F: Fn() -> O + Send + 'static,
 ///         O: Send + 'static,
@@ -235,20 +291,110 @@ pub trait SpawnSync {
     ///
     /// # Returns
     /// A task handle implementing [`Task<O>`] trait.
-    fn spawn_blocking<F, O>(func: F) -> impl Task<O>
+    /// The handle must be `Send`, so it can be awaited inside of other `Send` futures.
+    fn spawn_blocking<F, O>(func: F) -> impl Task<O> + Send
     where
         F: Fn() -> O + Send + 'static,
         O: Send + 'static;
 }
 
+/// The `SpawnLocal` trait provides an interface for spawning **thread-local** asynchronous tasks.
+///
+/// This trait is the single-threaded version of the [`SpawnAsync`] trait.
+/// The spawned future doesn't have to be `Send`, so it can hold `!Send` state,
+/// but it is executed on the current thread (for example on a Tokio `LocalSet`).
+/// It returns a handle implementing the [`Task`] trait.
+///
+/// # Examples
+/// ```
+/// use node_flow::context::{SpawnLocal, Task};
+/// use std::future::Future;
+///
+/// struct MyRuntime;
+/// struct DummyTask<T>(T);
+/// impl<T> Future for DummyTask<T> // ...
+/// # {
+/// #     type Output = T;
+/// #     fn poll(
+/// #         self: std::pin::Pin<&mut Self>,
+/// #         _: &mut std::task::Context<'_>
+/// #     ) -> std::task::Poll<Self::Output> {
+/// #         todo!()
+/// #     }
+/// # }
+/// impl<T> Task<T> for DummyTask<T> // ...
+/// # {
+/// #     fn is_finished(&self) -> bool { todo!() }
+/// #     fn cancel(self) {}
+/// # }
+///
+/// impl SpawnLocal for MyRuntime {
+///     fn spawn_local<F>(fut: F) -> impl Task<F::Output>
+///     where
+///         F: Future + 'static,
+///         F::Output: 'static,
+///     {
+///         // Example stub (replace with actual runtime call)
+///         DummyTask(todo!())
+///     }
+/// }
+/// ```
+pub trait SpawnLocal {
+    /// Spawns an asynchronous task on the current thread.
+    ///
+    /// The task must be `'static`, but unlike [`SpawnAsync::spawn`] it doesn't have to be `Send`.
+    ///
+    /// # Returns
+    /// A task handle implementing [`Task`] trait.
+    fn spawn_local<F>(fut: F) -> impl Task<F::Output>
+    where
+        F: Future + 'static,
+        F::Output: 'static;
+}
+
+/// The `Clock` trait provides an interface for reading the current time and sleeping.
+///
+/// This trait abstracts over timers of different async runtimes (such as Tokio or smol),
+/// so that flows which depend on time (timeouts, windows, ...) stay runtime-agnostic.
+/// It also allows replacing the clock in tests.
+///
+/// # Examples
+/// ```
+/// use node_flow::context::Clock;
+/// use std::{future::Future, time::Duration};
+///
+/// struct MyRuntime;
+///
+/// impl Clock for MyRuntime {
+///     fn sleep(duration: Duration) -> impl Future<Output = ()> + Send {
+///         // Example stub (replace with actual runtime call)
+///         async move { std::thread::sleep(duration) }
+///     }
+/// }
+///
+/// let now = MyRuntime::now();
+/// ```
+pub trait Clock {
+    /// Returns the current time.
+    ///
+    /// Defaults to [`Instant::now`](std::time::Instant::now).
+    #[must_use]
+    fn now() -> std::time::Instant {
+        std::time::Instant::now()
+    }
+
+    /// Returns a future that completes after `duration` has elapsed.
+    fn sleep(duration: std::time::Duration) -> impl Future<Output = ()> + Send;
+}
+
 #[cfg(test)]
 pub(crate) mod test {
     use std::time::{Duration, Instant};
 
-    use super::{SpawnAsync, SpawnSync, Task};
+    use super::{Clock, SpawnAsync, SpawnLocal, SpawnSync, Task};
 
     mod tokio_ {
-        use super::{SpawnAsync, SpawnSync, Task};
+        use super::{Clock, SpawnAsync, SpawnLocal, SpawnSync, Task};
         use std::pin::Pin;
 
         pub struct TokioSpawner;
@@ -277,7 +423,7 @@ pub(crate) mod test {
         }
 
         impl SpawnAsync for TokioSpawner {
-            fn spawn<F>(fut: F) -> impl super::Task<F::Output>
+            fn spawn<F>(fut: F) -> impl super::Task<F::Output> + Send
             where
                 F: Future + Send + 'static,
                 F::Output: Send + 'static,
@@ -286,8 +432,24 @@ pub(crate) mod test {
             }
         }
 
+        impl SpawnLocal for TokioSpawner {
+            fn spawn_local<F>(fut: F) -> impl super::Task<F::Output>
+            where
+                F: Future + 'static,
+                F::Output: 'static,
+            {
+                TokioTask(tokio::task::spawn_local(fut))
+            }
+        }
+
+        impl Clock for TokioSpawner {
+            fn sleep(duration: std::time::Duration) -> impl Future<Output = ()> + Send {
+                tokio::time::sleep(duration)
+            }
+        }
+
         impl SpawnSync for TokioSpawner {
-            fn spawn_blocking<F, O>(func: F) -> impl Task<O>
+            fn spawn_blocking<F, O>(func: F) -> impl Task<O> + Send
             where
                 F: Fn() -> O + Send + 'static,
                 O: Send + 'static,
@@ -335,7 +497,7 @@ pub(crate) mod test {
         }
 
         impl SpawnAsync for NoneSpawner {
-            fn spawn<F>(fut: F) -> impl super::Task<F::Output>
+            fn spawn<F>(fut: F) -> impl super::Task<F::Output> + Send
             where
                 F: Future + Send + 'static,
                 F::Output: Send + 'static,
@@ -345,7 +507,7 @@ pub(crate) mod test {
         }
 
         impl SpawnSync for NoneSpawner {
-            fn spawn_blocking<F, O>(func: F) -> impl Task<O>
+            fn spawn_blocking<F, O>(func: F) -> impl Task<O> + Send
             where
                 F: Fn() -> O + Send + 'static,
                 O: Send + 'static,
@@ -426,4 +588,82 @@ pub(crate) mod test {
         println!("time_sum: {time_sum}, took: {took}");
         assert!(time_sum <= took);
     }
+
+    mod tuple {
+        use crate::context::{Fork, Join, Update};
+
+        #[derive(Debug, PartialEq, Eq)]
+        struct Counter(u32);
+
+        impl Fork for Counter {
+            fn fork(&self) -> Self {
+                Self(0)
+            }
+        }
+
+        impl Update for Counter {
+            fn update_from(&mut self, other: Self) {
+                self.0 += other.0;
+            }
+        }
+
+        impl Join for Counter {
+            fn join(&mut self, others: Box<[Self]>) {
+                self.0 += others.iter().map(|other| other.0).sum::<u32>();
+            }
+        }
+
+        #[derive(Debug, PartialEq, Eq)]
+        struct Flags(Vec<&'static str>);
+
+        impl Fork for Flags {
+            fn fork(&self) -> Self {
+                Self(self.0.clone())
+            }
+        }
+
+        impl Update for Flags {
+            fn update_from(&mut self, other: Self) {
+                *self = other;
+            }
+        }
+
+        impl Join for Flags {
+            fn join(&mut self, others: Box<[Self]>) {
+                for other in others {
+                    for flag in other.0 {
+                        if !self.0.contains(&flag) {
+                            self.0.push(flag);
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_fork_update() {
+            let mut ctx = (Counter(5), Flags(vec!["a"]));
+            let mut forked = ctx.fork();
+            assert_eq!(forked, (Counter(0), Flags(vec!["a"])));
+
+            forked.0.0 += 2;
+            forked.1.0.push("b");
+            ctx.update_from(forked);
+            assert_eq!(ctx, (Counter(7), Flags(vec!["a", "b"])));
+        }
+
+        #[test]
+        fn test_join() {
+            let mut ctx = (Counter(5), Flags(vec!["a"]));
+            let mut child1 = ctx.fork();
+            child1.0.0 += 1;
+            child1.1.0.push("b");
+            let mut child2 = ctx.fork();
+            child2.0.0 += 3;
+            child2.1.0.push("c");
+
+            ctx.join(Box::new([child1, child2]));
+            assert_eq!(ctx, (Counter(9), Flags(vec!["a", "b", "c"])));
+        }
+    }
 }
@@ -1,5 +1,32 @@
-use super::design::{Description, Edge, EdgeEnding, ExternalResource, Type};
-use std::{borrow::Cow, fmt::Write};
+use super::design::{Description, Edge, EdgeEnding, ExternalResource, NodePath, Type};
+use std::{borrow::Cow, collections::HashMap, fmt::Write};
+
+/// The direction in which a [D2](https://d2lang.com/) diagram is laid out.
+///
+/// See also [`D2Describer::direction`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Flow goes from top to bottom.
+    #[default]
+    Down,
+    /// Flow goes from left to right.
+    Right,
+    /// Flow goes from right to left.
+    Left,
+    /// Flow goes from bottom to top.
+    Up,
+}
+
+impl Direction {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Down => "down",
+            Self::Right => "right",
+            Self::Left => "left",
+            Self::Up => "up",
+        }
+    }
+}
 
 /// A configurable formatter for converting [`Description`] structures into
 /// [D2](https://d2lang.com/) graph syntax.
@@ -42,6 +69,9 @@ pub struct D2Describer {
     ///
     /// When enabled, types like `my_crate::nodes::ExampleNode` become `ExampleNode`.
     /// This makes diagrams more readable, especially for complex flows.
+    ///
+    /// Simplified names are provided by [`Type::get_name_simple`],
+    /// whose `describe_get_name_simple` feature is always enabled together with this describer.
     pub simple_type_name: bool,
     /// Whether to display the node context type inside each node.
     ///
@@ -55,6 +85,14 @@ pub struct D2Describer {
     ///
     /// When enabled, external resources will be included in the node.
     pub show_externals: bool,
+    /// The direction in which the diagram is laid out.
+    ///
+    /// [`Direction::Right`] is useful for wide pipelines.
+    pub direction: Direction,
+    /// An optional [D2 theme](https://d2lang.com/tour/themes/) ID.
+    ///
+    /// When set, the theme is configured in the `vars.d2-config` header of the diagram.
+    pub theme_id: Option<u32>,
 }
 
 impl Default for D2Describer {
@@ -64,12 +102,17 @@ impl Default for D2Describer {
             show_context_in_node: false,
             show_description: false,
             show_externals: false,
+            direction: Direction::Down,
+            theme_id: None,
         }
     }
 }
 
 fn escape_str(val: &str) -> String {
-    val.replace('<', "\\<")
+    val.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace(['\r', '\n'], " ")
+        .replace('<', "\\<")
         .replace('>', "\\>")
         .replace('{', "\\{")
         .replace('}', "\\}")
@@ -83,6 +126,8 @@ impl D2Describer {
     /// - `show_context_in_node`: `false`
     /// - `show_description`: `false`
     /// - `show_externals`: `false`
+    /// - `direction`: [`Direction::Down`]
+    /// - `theme_id`: `None`
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -133,13 +178,73 @@ impl D2Describer {
     /// A string containing valid D2 source code representing the description graph.
     #[must_use]
     pub fn format(&self, desc: &Description) -> String {
-        let id = rand::random();
+        self.format_with_map(desc).0
+    }
+
+    /// Formats a [`Description`] into a D2 diagram text representation
+    /// and returns a mapping from D2 node IDs to the originating descriptions.
+    ///
+    /// This is useful for interactive diagrams, where D2 nodes need to be mapped back to
+    /// the nodes of the flow (for example to implement click-to-source).
+    ///
+    /// # Parameters
+    /// - `desc`: The [`Description`] to be rendered.
+    ///
+    /// # Returns
+    /// A string containing valid D2 source code (same as [`D2Describer::format`])
+    /// and a map from D2 node IDs to [`NodePath`]s pointing into `desc`.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::describe::D2Describer;
+    /// use node_flow::node::{Node, NodeOutput};
+    /// use node_flow::flows::SequentialFlow;
+    ///
+    /// # #[derive(Clone)]
+    /// # struct ExampleNode;
+    /// #
+    /// # impl Node<i32, NodeOutput<i32>, (), ()> for ExampleNode {
+    /// #     async fn run(&mut self, input: i32, _: &mut ()) -> Result<NodeOutput<i32>, ()> {
+    /// #         Ok(NodeOutput::Ok(input))
+    /// #     }
+    /// # }
+    /// let flow = SequentialFlow::<i32, i32, (), ()>::builder()
+    ///     .add_node(ExampleNode)
+    ///     .add_node(ExampleNode)
+    ///     .build();
+    /// let description = flow.describe();
+    ///
+    /// let (d2_code, map) = D2Describer::new().format_with_map(&description);
+    /// // root flow + 2 nodes
+    /// assert_eq!(map.len(), 3);
+    /// for (id, path) in &map {
+    ///     assert!(d2_code.contains(&id.to_string()));
+    ///     assert!(description.get_by_path(path).is_some());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn format_with_map(&self, desc: &Description) -> (String, HashMap<u64, NodePath>) {
+        let mut next_id = 0;
+        let id = next_id;
         let (input, output, context) = {
             let base = desc.get_base_ref();
             (&base.input, &base.output, &base.context)
         };
+        let vars = self
+            .theme_id
+            .map(|theme_id| {
+                format!(
+                    r"vars: {{
+    d2-config: {{
+        theme-id: {theme_id}
+    }}
+}}
+"
+                )
+            })
+            .unwrap_or_default();
         let mut res = format!(
-            r"direction: down
+            r"{vars}direction: {direction}
 classes: {{
     node: {{
         style.border-radius: 8
@@ -160,6 +265,9 @@ classes: {{
         shape: oval
         style.italic: true
     }}
+    detached: {{
+        style.stroke-dash: 5
+    }}
 }}
 Start: {{
     class: start_end
@@ -181,18 +289,29 @@ End: {{
     class: edge
 }}
 ",
+            direction = self.direction.as_str(),
             context = escape_str(&self.get_type_name(context)),
             input = escape_str(&self.get_type_name(input)),
             output = escape_str(&self.get_type_name(output)),
         );
 
-        self.process(desc, id, &mut res);
+        let mut map = HashMap::new();
+        self.process(desc, id, &mut next_id, &mut Vec::new(), &mut map, &mut res);
 
-        res
+        (res, map)
     }
 
-    fn process(&self, desc: &Description, id: u64, out: &mut String) {
-        self.start_define_base(desc, id, out);
+    fn process(
+        &self,
+        desc: &Description,
+        id: u64,
+        next_id: &mut u64,
+        path: &mut NodePath,
+        map: &mut HashMap<u64, NodePath>,
+        out: &mut String,
+    ) {
+        map.insert(id, path.clone());
+        self.start_define_base(desc, id, next_id, out);
 
         let Description::Flow { base, nodes, edges } = desc else {
             out.push_str("}\n");
@@ -201,9 +320,13 @@ End: {{
 
         let nodes_and_ids = nodes
             .iter()
-            .map(|node_desc| {
-                let id = rand::random();
-                self.process(node_desc, id, out);
+            .enumerate()
+            .map(|(idx, node_desc)| {
+                *next_id += 1;
+                let id = *next_id;
+                path.push(idx);
+                self.process(node_desc, id, next_id, path, map, out);
+                path.pop();
                 (id, node_desc.get_base_ref())
             })
             .collect::<Vec<_>>();
@@ -266,16 +389,21 @@ End: {{
         out.push_str("}\n");
     }
 
-    fn start_define_base(&self, desc: &Description, id: u64, out: &mut String) {
+    fn start_define_base(&self, desc: &Description, id: u64, next_id: &mut u64, out: &mut String) {
         let base = desc.get_base_ref();
         let is_node = matches!(desc, Description::Node { .. });
+        let class = if is_node { "node" } else { "flow" };
         writeln!(
             out,
             r"{}:{} {{
                 class: {}",
             id,
             escape_str(&self.get_type_name(&base.r#type)),
-            if is_node { "node" } else { "flow" }
+            if base.detached {
+                format!("[{class}; detached]")
+            } else {
+                class.to_owned()
+            }
         )
         .unwrap();
 
@@ -292,7 +420,14 @@ End: {{
                 .unwrap();
             }
             if has_description {
-                out.push_str(&escape_str(base.description.as_ref().unwrap()));
+                let description = base.description.as_ref().unwrap();
+                out.push_str(
+                    &description
+                        .lines()
+                        .map(escape_str)
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                );
             }
             writeln!(
                 out,
@@ -317,7 +452,8 @@ End: {{
             output,
         } in externals
         {
-            let ext_id: u64 = rand::random();
+            *next_id += 1;
+            let ext_id = *next_id;
             writeln!(
                 out,
                 r"{}:{} {{
@@ -336,3 +472,77 @@ End: {{
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{D2Describer, Direction, escape_str};
+    use crate::describe::{Description, DescriptionBase, Edge, ExternalResource};
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape_str("a\"b\\c\nd<e>"), "a\\\"b\\\\c d\\<e\\>");
+    }
+
+    #[test]
+    fn test_format_quoted_type_name() {
+        let mut base = DescriptionBase::from::<(), (), (), (), ()>();
+        base.r#type.name = "Node<\"quoted\"\nname>".to_owned();
+        let desc = Description::Node { base };
+
+        let mut describer = D2Describer::new();
+        describer.modify(|cfg| cfg.simple_type_name = false);
+        let res = describer.format(&desc);
+
+        let label_lines = res
+            .lines()
+            .filter(|line| line.contains("quoted"))
+            .collect::<Vec<_>>();
+        assert_eq!(label_lines.len(), 1);
+        assert!(label_lines[0].ends_with(r#":Node\<\"quoted\" name\> {"#));
+    }
+
+    #[test]
+    fn test_format_deterministic() {
+        let mut base = DescriptionBase::from::<(), (), (), (), ()>();
+        base.externals = Some(vec![ExternalResource::new::<(), ()>()]);
+        let desc = Description::Flow {
+            base: base.clone(),
+            nodes: vec![
+                Description::Node { base: base.clone() },
+                Description::Node { base },
+            ],
+            edges: vec![
+                Edge::flow_to_node(0),
+                Edge::node_to_node(0, 1),
+                Edge::node_to_flow(1),
+            ],
+        };
+
+        let mut describer = D2Describer::new();
+        describer.modify(|cfg| cfg.show_externals = true);
+        let (res, map) = describer.format_with_map(&desc);
+        assert_eq!(res, describer.format(&desc));
+        assert_eq!(map.len(), 3);
+        assert_eq!(map[&0], Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_format_direction_and_theme() {
+        let desc = Description::Node {
+            base: DescriptionBase::from::<(), (), (), (), ()>(),
+        };
+
+        let res = D2Describer::new().format(&desc);
+        assert!(res.starts_with("direction: down\n"));
+        assert!(!res.contains("theme-id"));
+
+        let mut describer = D2Describer::new();
+        describer.modify(|cfg| {
+            cfg.direction = Direction::Right;
+            cfg.theme_id = Some(200);
+        });
+        let res = describer.format(&desc);
+        assert!(res.starts_with("vars: {\n    d2-config: {\n        theme-id: 200\n"));
+        assert!(res.contains("\ndirection: right\n"));
+    }
+}
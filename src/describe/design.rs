@@ -5,7 +5,13 @@ use crate::node::{Node, NodeOutput};
 /// Represents a description of either a single [`Node`] or an entire flow of connected nodes.
 ///
 /// This enum is primarily used for introspection and visualization of a flow.
+///
+/// With the `serde` feature enabled, all description types implement `Serialize` and `Deserialize`.
+/// Enums are internally tagged with a `kind` field (for example `{"kind": "Node", "base": {...}}`),
+/// so the serialized form is self-describing.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind"))]
 pub enum Description {
     /// Single node description.
     Node {
@@ -94,6 +100,189 @@ impl Description {
         self
     }
 
+    /// Adds an external resource used by this node or flow.
+    ///
+    /// Unlike [`Description::with_externals`], this keeps already present external resources.
+    /// It is useful when external resources depend on the state of a node (for example its configuration).
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::describe::{Description, ExternalResource};
+    /// use node_flow::node::{Node, NodeOutput};
+    ///
+    /// struct Fetch {
+    ///     endpoint: String,
+    /// }
+    ///
+    /// impl<Ctx: Send> Node<(), NodeOutput<String>, (), Ctx> for Fetch {
+    ///     async fn run(&mut self, _: (), _: &mut Ctx) -> Result<NodeOutput<String>, ()> {
+    ///         todo!()
+    ///     }
+    ///
+    ///     fn describe(&self) -> Description {
+    ///         let mut description = Description::new_node::<_, (), String, (), Ctx>(self);
+    ///         description.add_external(
+    ///             ExternalResource::new::<String, String>()
+    ///                 .with_description(format!("GET {}", self.endpoint)),
+    ///         );
+    ///         description
+    ///     }
+    /// }
+    /// ```
+    pub fn add_external(&mut self, external: ExternalResource) {
+        self.get_base_mut()
+            .externals
+            .get_or_insert_with(Vec::new)
+            .push(external);
+    }
+
+    /// Collects all [`ExternalResource`]s used by this node or flow and all of its nested nodes.
+    ///
+    /// The description tree is walked depth-first,
+    /// so externals of a flow come before externals of its nodes.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::describe::{Description, DescriptionBase, ExternalResource};
+    ///
+    /// # let base = DescriptionBase::from::<(), (), (), (), ()>();
+    /// let node = Description::Node { base: base.clone() }
+    ///     .with_externals(vec![ExternalResource::new::<String, String>()]);
+    /// let flow = Description::Flow { base, nodes: vec![node], edges: vec![] };
+    ///
+    /// assert_eq!(flow.collect_externals().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn collect_externals(&self) -> Vec<&ExternalResource> {
+        let mut externals = Vec::new();
+        self.collect_externals_into(&mut externals);
+        externals
+    }
+
+    fn collect_externals_into<'a>(&'a self, externals: &mut Vec<&'a ExternalResource>) {
+        externals.extend(self.get_base_ref().externals.iter().flatten());
+        if let Self::Flow { nodes, .. } = self {
+            for node in nodes {
+                node.collect_externals_into(externals);
+            }
+        }
+    }
+
+    /// Marks this node or flow as detached.
+    ///
+    /// See [`DescriptionBase::detached`].
+    #[must_use]
+    pub const fn as_detached(mut self) -> Self {
+        self.get_base_mut().detached = true;
+        self
+    }
+
+    /// Returns the nested [`Description`] at the given [`NodePath`].
+    ///
+    /// An empty path returns this description.
+    /// Returns `None` if the path doesn't point to an existing node.
+    #[must_use]
+    pub fn get_by_path(&self, path: &[usize]) -> Option<&Self> {
+        let mut desc = self;
+        for &idx in path {
+            let Self::Flow { nodes, .. } = desc else {
+                return None;
+            };
+            desc = nodes.get(idx)?;
+        }
+        Some(desc)
+    }
+
+    /// Collapses flows nested `max_depth` or more levels deep into a [`Description::Node`].
+    ///
+    /// The root description is at depth `0`, so `prune_depth(0)` collapses the whole flow
+    /// and `prune_depth(1)` keeps only the nodes of the root flow.
+    /// A collapsed flow keeps its [`DescriptionBase`], but its nodes and edges are discarded.
+    ///
+    /// This is useful for rendering an overview of a large flow
+    /// and inspecting the sub-flows separately (see [`Description::get_by_path`]).
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::describe::Description;
+    /// # use node_flow::describe::DescriptionBase;
+    ///
+    /// # let base = DescriptionBase::from::<(), (), (), (), ()>();
+    /// let inner = Description::Flow { base: base.clone(), nodes: vec![], edges: vec![] };
+    /// let outer = Description::Flow { base, nodes: vec![inner], edges: vec![] };
+    ///
+    /// let pruned = outer.prune_depth(1);
+    /// assert!(matches!(pruned.get_by_path(&[0]), Some(Description::Node { .. })));
+    /// ```
+    #[must_use]
+    pub fn prune_depth(self, max_depth: usize) -> Self {
+        match self {
+            Self::Node { .. } => self,
+            Self::Flow { base, .. } if max_depth == 0 => Self::Node { base },
+            Self::Flow { base, nodes, edges } => Self::Flow {
+                base,
+                nodes: nodes
+                    .into_iter()
+                    .map(|node| node.prune_depth(max_depth - 1))
+                    .collect(),
+                edges,
+            },
+        }
+    }
+
+    /// Returns the number of [`Description::Node`]s in this description, including the nested ones.
+    ///
+    /// Flows themselves are not counted, a single node returns `1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::describe::Description;
+    /// # use node_flow::describe::DescriptionBase;
+    ///
+    /// # let base = DescriptionBase::from::<(), (), (), (), ()>();
+    /// let node = Description::Node { base: base.clone() };
+    /// let inner = Description::Flow { base: base.clone(), nodes: vec![node.clone()], edges: vec![] };
+    /// let outer = Description::Flow { base, nodes: vec![node, inner], edges: vec![] };
+    ///
+    /// assert_eq!(outer.node_count(), 2);
+    /// ```
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        match self {
+            Self::Node { .. } => 1,
+            Self::Flow { nodes, .. } => nodes.iter().map(Self::node_count).sum(),
+        }
+    }
+
+    /// Returns the depth of the most nested description.
+    ///
+    /// The root description is at depth `0` (same as in [`Description::prune_depth`]),
+    /// so a single node or an empty flow returns `0` and a flow of nodes returns `1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::describe::Description;
+    /// # use node_flow::describe::DescriptionBase;
+    ///
+    /// # let base = DescriptionBase::from::<(), (), (), (), ()>();
+    /// let node = Description::Node { base: base.clone() };
+    /// let inner = Description::Flow { base: base.clone(), nodes: vec![node.clone()], edges: vec![] };
+    /// let outer = Description::Flow { base, nodes: vec![node, inner], edges: vec![] };
+    ///
+    /// assert_eq!(outer.max_depth(), 2);
+    /// ```
+    #[must_use]
+    pub fn max_depth(&self) -> usize {
+        match self {
+            Self::Node { .. } => 0,
+            Self::Flow { nodes, .. } => nodes
+                .iter()
+                .map(|node| node.max_depth() + 1)
+                .max()
+                .unwrap_or(0),
+        }
+    }
+
     /// Modifies the name using a provided function.
     ///
     /// This is useful when you only want to modify the name.
@@ -103,6 +292,301 @@ impl Description {
         func(name);
         self
     }
+
+    /// Walks this description depth-first and calls the corresponding methods of the `visitor`.
+    ///
+    /// For a [`Description::Node`], [`DescriptionVisitor::visit_node`] is called.
+    /// For a [`Description::Flow`], [`DescriptionVisitor::enter_flow`] is called,
+    /// then all of its nodes are visited in order and finally [`DescriptionVisitor::exit_flow`] is called.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::describe::{Description, DescriptionBase, DescriptionVisitor};
+    ///
+    /// #[derive(Default)]
+    /// struct NodeCounter(usize);
+    ///
+    /// impl DescriptionVisitor for NodeCounter {
+    ///     fn visit_node(&mut self, _base: &DescriptionBase) {
+    ///         self.0 += 1;
+    ///     }
+    /// }
+    ///
+    /// fn count_nodes(description: &Description) -> usize {
+    ///     let mut counter = NodeCounter::default();
+    ///     description.visit(&mut counter);
+    ///     counter.0
+    /// }
+    /// ```
+    pub fn visit(&self, visitor: &mut impl DescriptionVisitor) {
+        match self {
+            Self::Node { base } => visitor.visit_node(base),
+            Self::Flow { base, nodes, edges } => {
+                visitor.enter_flow(base, edges);
+                for node in nodes {
+                    node.visit(visitor);
+                }
+                visitor.exit_flow(base, edges);
+            }
+        }
+    }
+
+    /// Returns `true` if both descriptions have the same structure.
+    ///
+    /// Descriptions are structurally equal when their nodes and flows are nested the same way,
+    /// have the same types (including input, output, error and context types) and are connected by the same edges.
+    /// Descriptions and external resources are ignored.
+    ///
+    /// This is useful for asserting that a refactor didn't change the structure of a flow.
+    /// To find out what differs, use [`Description::diff`].
+    #[must_use]
+    pub fn structural_eq(&self, other: &Self) -> bool {
+        self.diff(other).is_empty()
+    }
+
+    /// Returns a list of structural differences between `self` and `other`.
+    ///
+    /// `self` is treated as the old description and `other` as the new one.
+    /// Nodes are matched by their position in the flow, so nodes added or removed at the end
+    /// of a flow are reported as [`DiffEntry::Added`] or [`DiffEntry::Removed`].
+    /// Changed nodes are not compared any further.
+    ///
+    /// An empty list means that the descriptions are [structurally equal](Description::structural_eq).
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::describe::{Description, DescriptionBase, DiffEntry, Type};
+    ///
+    /// # let base = DescriptionBase::from::<(), (), (), (), ()>();
+    /// let node = Description::Node { base: base.clone() };
+    /// let old = Description::Flow { base: base.clone(), nodes: vec![node.clone()], edges: vec![] };
+    /// let new = Description::Flow { base, nodes: vec![node.clone(), node], edges: vec![] };
+    ///
+    /// assert!(!old.structural_eq(&new));
+    /// assert_eq!(
+    ///     old.diff(&new),
+    ///     vec![DiffEntry::Added { path: vec![1], r#type: Type::of::<()>() }]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<DiffEntry> {
+        let mut entries = Vec::new();
+        self.diff_into(other, &mut Vec::new(), &mut entries);
+        entries
+    }
+
+    fn diff_into(&self, other: &Self, path: &mut NodePath, entries: &mut Vec<DiffEntry>) {
+        let (old, new) = (self.get_base_ref(), other.get_base_ref());
+        if old.r#type != new.r#type {
+            entries.push(DiffEntry::TypeChanged {
+                path: path.clone(),
+                old: old.r#type.clone(),
+                new: new.r#type.clone(),
+            });
+            return;
+        }
+        if (&old.input, &old.output, &old.error, &old.context)
+            != (&new.input, &new.output, &new.error, &new.context)
+        {
+            entries.push(DiffEntry::SignatureChanged { path: path.clone() });
+        }
+
+        match (self, other) {
+            (Self::Node { .. }, Self::Node { .. }) => {}
+            (
+                Self::Flow {
+                    nodes: old_nodes,
+                    edges: old_edges,
+                    ..
+                },
+                Self::Flow {
+                    nodes: new_nodes,
+                    edges: new_edges,
+                    ..
+                },
+            ) => {
+                if old_edges != new_edges {
+                    entries.push(DiffEntry::EdgesChanged { path: path.clone() });
+                }
+                for (idx, (old, new)) in old_nodes.iter().zip(new_nodes).enumerate() {
+                    path.push(idx);
+                    old.diff_into(new, path, entries);
+                    path.pop();
+                }
+                for (idx, node) in old_nodes.iter().enumerate().skip(new_nodes.len()) {
+                    let mut path = path.clone();
+                    path.push(idx);
+                    entries.push(DiffEntry::Removed {
+                        path,
+                        r#type: node.get_base_ref().r#type.clone(),
+                    });
+                }
+                for (idx, node) in new_nodes.iter().enumerate().skip(old_nodes.len()) {
+                    let mut path = path.clone();
+                    path.push(idx);
+                    entries.push(DiffEntry::Added {
+                        path,
+                        r#type: node.get_base_ref().r#type.clone(),
+                    });
+                }
+            }
+            _ => entries.push(DiffEntry::KindChanged { path: path.clone() }),
+        }
+    }
+}
+
+/// Renders a compact indented tree of the description, one node or flow per line.
+///
+/// Each line contains the type name without module paths, followed by the input and output types
+/// (and the description of the node, if there is one).
+/// Nested nodes are indented by two spaces.
+///
+/// # Examples
+/// ```
+/// use node_flow::describe::{Description, DescriptionBase};
+///
+/// let base = DescriptionBase::from::<Vec<u8>, u8, String, (), ()>();
+/// let node = Description::Node { base: base.clone() };
+/// let flow = Description::Flow { base, nodes: vec![node], edges: vec![] };
+///
+/// assert_eq!(
+///     flow.to_string(),
+///     "Vec<u8> (u8 -> String)\n  Vec<u8> (u8 -> String)\n"
+/// );
+/// ```
+impl std::fmt::Display for Description {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_tree(f, 0)
+    }
+}
+
+impl Description {
+    fn fmt_tree(&self, f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {
+        let base = self.get_base_ref();
+        write!(
+            f,
+            "{:indent$}{} ({} -> {})",
+            "",
+            simplify_type_name(&base.r#type.name),
+            simplify_type_name(&base.input.name),
+            simplify_type_name(&base.output.name),
+            indent = depth * 2,
+        )?;
+        if base.detached {
+            f.write_str(" [detached]")?;
+        }
+        if let Some(description) = &base.description {
+            write!(f, ": {description}")?;
+        }
+        writeln!(f)?;
+
+        if let Self::Flow { nodes, .. } = self {
+            for node in nodes {
+                node.fmt_tree(f, depth + 1)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Removes module paths from a type name.
+///
+/// For example `alloc::vec::Vec<my::Type>` becomes `Vec<Type>`.
+fn simplify_type_name(name: &str) -> String {
+    let mut simplified = String::with_capacity(name.len());
+    // start of the current path segment in `simplified`
+    let mut segment_start = 0;
+    let mut chars = name.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            simplified.truncate(segment_start);
+            continue;
+        }
+        simplified.push(ch);
+        if !(ch.is_alphanumeric() || ch == '_') {
+            segment_start = simplified.len();
+        }
+    }
+    simplified
+}
+
+/// Visitor of a [`Description`] tree.
+///
+/// It is used for walking the description without matching on it by hand,
+/// for example when counting nodes or collecting [`ExternalResource`]s.
+///
+/// See also [`Description::visit`].
+pub trait DescriptionVisitor {
+    /// Called for every [`Description::Node`].
+    fn visit_node(&mut self, base: &DescriptionBase);
+
+    /// Called for every [`Description::Flow`] before its nodes are visited.
+    ///
+    /// The default implementation does nothing.
+    fn enter_flow(&mut self, base: &DescriptionBase, edges: &[Edge]) {
+        let _ = (base, edges);
+    }
+
+    /// Called for every [`Description::Flow`] after its nodes are visited.
+    ///
+    /// The default implementation does nothing.
+    fn exit_flow(&mut self, base: &DescriptionBase, edges: &[Edge]) {
+        let _ = (base, edges);
+    }
+}
+
+/// Path to a nested [`Description`].
+///
+/// Each item is an index into the `nodes` of [`Description::Flow`], starting at the root description.
+/// An empty path points to the root description itself.
+///
+/// See also [`Description::get_by_path`].
+pub type NodePath = Vec<usize>;
+
+/// A single structural difference between two [`Description`]s.
+///
+/// See [`Description::diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffEntry {
+    /// A node or flow is present only in the new description.
+    Added {
+        /// Path to the added node in the new description.
+        path: NodePath,
+        /// The type of the added node.
+        r#type: Type,
+    },
+    /// A node or flow is present only in the old description.
+    Removed {
+        /// Path to the removed node in the old description.
+        path: NodePath,
+        /// The type of the removed node.
+        r#type: Type,
+    },
+    /// The type of a node or flow has changed.
+    TypeChanged {
+        /// Path to the changed node.
+        path: NodePath,
+        /// The type in the old description.
+        old: Type,
+        /// The type in the new description.
+        new: Type,
+    },
+    /// The input, output, error or context type of a node or flow has changed.
+    SignatureChanged {
+        /// Path to the changed node.
+        path: NodePath,
+    },
+    /// A node was replaced with a flow or the other way around.
+    KindChanged {
+        /// Path to the changed node.
+        path: NodePath,
+    },
+    /// The edges of a flow have changed.
+    EdgesChanged {
+        /// Path to the changed flow.
+        path: NodePath,
+    },
 }
 
 /// The base structure describing a node's type signature and metadata.
@@ -110,6 +594,7 @@ impl Description {
 /// Contains information about the node's input, output, error, and context types,
 /// along with optional description and external resource metadata.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DescriptionBase {
     /// The type of the node or flow itself.
     pub r#type: Type,
@@ -125,6 +610,11 @@ pub struct DescriptionBase {
     pub description: Option<String>,
     /// Optional list of external resources the node uses.
     pub externals: Option<Vec<ExternalResource>>,
+    /// Whether the node or flow runs detached (in the background) from the flow that contains it.
+    ///
+    /// Detached nodes don't affect the output of the containing flow,
+    /// so renderers should display them differently than inline ones.
+    pub detached: bool,
 }
 
 impl DescriptionBase {
@@ -139,6 +629,7 @@ impl DescriptionBase {
             context: Type::of::<Context>(),
             description: None,
             externals: None,
+            detached: false,
         }
     }
 
@@ -171,7 +662,8 @@ impl DescriptionBase {
 }
 
 /// Represents a type.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Type {
     /// The name of a type.
     ///
@@ -194,6 +686,90 @@ impl Type {
         Self::of::<T>()
     }
 
+    /// Creates a [`Type`] with the given name.
+    ///
+    /// This is useful when the type is only known at runtime (for example from a configuration).
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::describe::{DescriptionBase, Type};
+    ///
+    /// let mut base = DescriptionBase::from::<(), (), (), (), ()>();
+    /// base.r#type = Type::from_name("ConfiguredNode");
+    /// base.input = Type::from_name(String::from("Request"));
+    /// assert_eq!(base.r#type.name, "ConfiguredNode");
+    /// assert_eq!(base.input.name, "Request");
+    /// ```
+    #[must_use]
+    pub fn from_name(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+
+    /// Creates a [`Type`] representing an unknown type.
+    ///
+    /// Its name is empty, describers render it as an empty label.
+    #[must_use]
+    pub const fn unknown() -> Self {
+        Self {
+            name: String::new(),
+        }
+    }
+
+    /// Splits the type name into the base name and the outermost generic arguments.
+    ///
+    /// Nested generic arguments are kept in the names of the returned types,
+    /// so they can be split again.
+    /// Types without generic arguments return an empty [`Vec`].
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::describe::Type;
+    ///
+    /// let ty = Type::from_name("Foo<Bar<Baz>, (u8, u16)>");
+    /// let (base, args) = ty.generic_args();
+    /// assert_eq!(base, "Foo");
+    /// assert_eq!(args, [Type::from_name("Bar<Baz>"), Type::from_name("(u8, u16)")]);
+    /// ```
+    #[must_use]
+    pub fn generic_args(&self) -> (&str, Vec<Self>) {
+        let Some(start) = self.name.find('<') else {
+            return (self.name.as_str(), Vec::new());
+        };
+        let base = &self.name[..start];
+
+        let mut args = Vec::new();
+        let mut depth = 0usize;
+        let mut arg_start = start + 1;
+        let mut prev = '<';
+        for (idx, ch) in self
+            .name
+            .char_indices()
+            .skip_while(|(idx, _)| *idx <= start)
+        {
+            let is_arrow = ch == '>' && prev == '-';
+            prev = ch;
+            match ch {
+                // `>` of `->` in function types doesn't close a bracket
+                '>' if is_arrow => {}
+                '<' | '(' | '[' => depth += 1,
+                ',' if depth == 0 => {
+                    args.push(Self::from_name(self.name[arg_start..idx].trim()));
+                    arg_start = idx + 1;
+                }
+                '>' if depth == 0 => {
+                    let arg = self.name[arg_start..idx].trim();
+                    if !arg.is_empty() {
+                        args.push(Self::from_name(arg));
+                    }
+                    break;
+                }
+                '>' | ')' | ']' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+        (base, args)
+    }
+
     /// Returns a simplified version of the type name.
     ///
     /// Instead of `std::option::Option<std::string::String>` it returns `Option<String>`.
@@ -207,7 +783,8 @@ impl Type {
 /// Represents a directional connection between nodes in a flow.
 ///
 /// Each edge connects two [`EdgeEnding`]s, which can be either a node or the flow itself.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Edge {
     /// The starting point of the edge.
     pub start: EdgeEnding,
@@ -218,7 +795,9 @@ pub struct Edge {
 /// Represents one end of an [`Edge`].
 ///
 /// An `EdgeEnding` can either connect to the flow or to a specific node.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind"))]
 pub enum EdgeEnding {
     /// The edge connects to the flow.
     ToFlow,
@@ -279,6 +858,7 @@ impl Edge {
 ///
 /// These resources may represent things like files, APIs, or external data sources.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExternalResource {
     /// The type of the external resource.
     pub r#type: Type,
@@ -299,6 +879,44 @@ impl ExternalResource {
         }
     }
 
+    /// Creates a new [`ExternalResource`] description from already known [`Type`]s.
+    ///
+    /// This is useful when the types are only known at runtime (for example from a configuration).
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::describe::{ExternalResource, Type};
+    ///
+    /// let resource = ExternalResource::from_types(
+    ///     Type { name: "Database".to_owned() },
+    ///     Type::of::<String>(),
+    /// );
+    /// assert_eq!(resource.r#type.name, "Database");
+    /// assert_eq!(resource.output.name, "alloc::string::String");
+    /// ```
+    #[must_use]
+    pub const fn from_types(resource: Type, output: Type) -> Self {
+        Self {
+            r#type: resource,
+            description: None,
+            output,
+        }
+    }
+
+    /// Sets a type of the external resource.
+    #[must_use]
+    pub fn with_type(mut self, r#type: Type) -> Self {
+        self.r#type = r#type;
+        self
+    }
+
+    /// Sets a type of data produced by the external resource.
+    #[must_use]
+    pub fn with_output(mut self, output: Type) -> Self {
+        self.output = output;
+        self
+    }
+
     /// Sets a description.
     ///
     /// This is primarily used to provide additional documentation or context.
@@ -308,3 +926,326 @@ impl ExternalResource {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{
+        Description, DescriptionBase, DescriptionVisitor, DiffEntry, Edge, ExternalResource, Type,
+    };
+    use crate::{
+        context::storage::local_storage::LocalStorageImpl,
+        flows::{ParallelFlow, SequentialFlow, tests::Passer},
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Default)]
+    struct Counter {
+        nodes: usize,
+        flows: usize,
+        depth: usize,
+        max_depth: usize,
+    }
+
+    impl DescriptionVisitor for Counter {
+        fn visit_node(&mut self, _base: &DescriptionBase) {
+            self.nodes += 1;
+        }
+
+        fn enter_flow(&mut self, _base: &DescriptionBase, _edges: &[Edge]) {
+            self.flows += 1;
+            self.depth += 1;
+            self.max_depth = self.max_depth.max(self.depth);
+        }
+
+        fn exit_flow(&mut self, _base: &DescriptionBase, _edges: &[Edge]) {
+            self.depth -= 1;
+        }
+    }
+
+    #[test]
+    fn test_visit() {
+        let parallel = ParallelFlow::<u8, u16, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(Passer::<u8, u8, ()>::new())
+            .build(async |_, _: &mut LocalStorageImpl| Ok(NodeOutput::Ok(0u16)));
+        let flow = SequentialFlow::<u8, u16, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(parallel)
+            .add_node(Passer::<u16, u16, ()>::new())
+            .build();
+
+        let mut counter = Counter::default();
+        flow.describe().visit(&mut counter);
+        // the joiner of ParallelFlow is described as a node too
+        assert_eq!(counter.nodes, 5);
+        assert_eq!(counter.flows, 2);
+        assert_eq!(counter.depth, 0);
+        assert_eq!(counter.max_depth, 2);
+    }
+
+    #[test]
+    fn test_prune_depth() {
+        let level4 = SequentialFlow::<u8, u8, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .build();
+        let level3 = SequentialFlow::<u8, u8, (), LocalStorageImpl>::builder()
+            .add_node(level4)
+            .build();
+        let level2 = SequentialFlow::<u8, u8, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(level3)
+            .build();
+        let flow = SequentialFlow::<u8, u8, (), LocalStorageImpl>::builder()
+            .add_node(level2)
+            .add_node(Passer::<u8, u8, ()>::new())
+            .build();
+
+        let desc = flow.describe();
+        let mut counter = Counter::default();
+        desc.visit(&mut counter);
+        assert_eq!(counter.max_depth, 4);
+        assert_eq!(counter.nodes, 3);
+
+        let pruned = desc.prune_depth(2);
+        let mut counter = Counter::default();
+        pruned.visit(&mut counter);
+        assert_eq!(counter.max_depth, 2);
+        // two passers and the collapsed flow
+        assert_eq!(counter.nodes, 3);
+        assert_eq!(counter.flows, 2);
+
+        let collapsed = pruned.get_by_path(&[0, 1]).unwrap();
+        assert!(matches!(collapsed, Description::Node { .. }));
+        assert!(
+            collapsed
+                .get_base_ref()
+                .r#type
+                .name
+                .contains("SequentialFlow")
+        );
+        assert!(pruned.get_by_path(&[0, 1, 0]).is_none());
+        assert!(matches!(
+            pruned.get_by_path(&[0, 0]),
+            Some(Description::Node { .. })
+        ));
+
+        assert!(matches!(pruned.prune_depth(0), Description::Node { .. }));
+    }
+
+    #[test]
+    fn test_node_count_and_max_depth() {
+        let sequential = SequentialFlow::<u8, u8, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(Passer::<u8, u8, ()>::new())
+            .build();
+        assert_eq!(sequential.describe().node_count(), 3);
+        assert_eq!(sequential.describe().max_depth(), 1);
+
+        let flow = ParallelFlow::<u8, u16, (), LocalStorageImpl>::builder()
+            .add_node(sequential)
+            .add_node(Passer::<u8, u8, ()>::new())
+            .build(async |_, _: &mut LocalStorageImpl| Ok(NodeOutput::Ok(0u16)));
+
+        let desc = flow.describe();
+        // the joiner of ParallelFlow is described as a node too
+        assert_eq!(desc.node_count(), 5);
+        assert_eq!(desc.max_depth(), 2);
+        assert_eq!(desc.clone().prune_depth(1).node_count(), 3);
+        assert_eq!(desc.prune_depth(1).max_depth(), 1);
+
+        let node = Node::<u8, _, _, LocalStorageImpl>::describe(&Passer::<u8, u8, ()>::new());
+        assert_eq!(node.node_count(), 1);
+        assert_eq!(node.max_depth(), 0);
+    }
+
+    #[test]
+    fn test_collect_externals() {
+        #[derive(Clone)]
+        struct Fetch(&'static str);
+
+        impl<C: Send> Node<u8, NodeOutput<u8>, (), C> for Fetch {
+            async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u8>, ()> {
+                Ok(NodeOutput::Ok(input))
+            }
+
+            fn describe(&self) -> Description {
+                Description::new_node::<_, u8, u8, (), C>(self).with_externals(vec![
+                    ExternalResource::new::<String, u8>().with_description(self.0.to_owned()),
+                ])
+            }
+        }
+
+        let inner = SequentialFlow::<u8, u8, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(Fetch("inner"))
+            .build();
+        let flow = SequentialFlow::<u8, u8, (), LocalStorageImpl>::builder()
+            .add_node(Fetch("outer"))
+            .add_node(inner)
+            .build();
+
+        let desc = flow.describe();
+        let externals = desc
+            .collect_externals()
+            .into_iter()
+            .map(|external| external.description.as_deref().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(externals, ["outer", "inner"]);
+
+        let desc = desc.with_externals(vec![
+            ExternalResource::new::<String, u8>().with_description("flow".to_owned()),
+        ]);
+        assert_eq!(desc.collect_externals().len(), 3);
+        assert_eq!(
+            desc.collect_externals()[0].description.as_deref(),
+            Some("flow")
+        );
+    }
+
+    #[test]
+    fn test_external_resource_from_types() {
+        let generic = ExternalResource::new::<String, u8>();
+        let from_types = ExternalResource::from_types(Type::of::<String>(), Type::of::<u8>());
+        assert_eq!(generic.r#type.name, from_types.r#type.name);
+        assert_eq!(generic.output.name, from_types.output.name);
+
+        let modified = generic
+            .with_type(Type {
+                name: "Database".to_owned(),
+            })
+            .with_output(Type::of::<u16>())
+            .with_description("users".to_owned());
+        assert_eq!(modified.r#type.name, "Database");
+        assert_eq!(modified.output.name, "u16");
+        assert_eq!(modified.description.as_deref(), Some("users"));
+    }
+
+    #[test]
+    fn test_structural_diff() {
+        let build = || {
+            SequentialFlow::<u8, u16, (), LocalStorageImpl>::builder()
+                .add_node(Passer::<u8, u8, ()>::new())
+                .add_node(Passer::<u8, u16, ()>::new())
+                .build()
+        };
+        let flow = build().describe();
+        let same = build()
+            .describe()
+            .with_description("descriptions are ignored");
+        assert!(flow.structural_eq(&same));
+        assert!(flow.diff(&same).is_empty());
+
+        let modified = SequentialFlow::<u8, u16, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(Passer::<u8, u16, ()>::new())
+            .build()
+            .describe();
+        assert!(!flow.structural_eq(&modified));
+        assert_eq!(
+            flow.diff(&modified),
+            vec![
+                DiffEntry::EdgesChanged { path: vec![] },
+                DiffEntry::TypeChanged {
+                    path: vec![1],
+                    old: Type::of::<Passer<u8, u16, ()>>(),
+                    new: Type::of::<Passer<u8, u8, ()>>(),
+                },
+                DiffEntry::Added {
+                    path: vec![2],
+                    r#type: Type::of::<Passer<u8, u16, ()>>(),
+                },
+            ]
+        );
+        assert_eq!(
+            modified.diff(&flow)[2],
+            DiffEntry::Removed {
+                path: vec![2],
+                r#type: Type::of::<Passer<u8, u16, ()>>(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_generic_args() {
+        let ty = Type::from_name("Foo<Bar<Baz>, Qux>");
+        let (base, args) = ty.generic_args();
+        assert_eq!(base, "Foo");
+        assert_eq!(args, [Type::from_name("Bar<Baz>"), Type::from_name("Qux")]);
+
+        let (base, args) = args[0].generic_args();
+        assert_eq!(base, "Bar");
+        assert_eq!(args, [Type::from_name("Baz")]);
+
+        let ty = Type::from_name("a::Map<(u8, Vec<u16>), [u32; 2]>");
+        let (base, args) = ty.generic_args();
+        assert_eq!(base, "a::Map");
+        assert_eq!(
+            args,
+            [
+                Type::from_name("(u8, Vec<u16>)"),
+                Type::from_name("[u32; 2]")
+            ]
+        );
+
+        let ty = Type::from_name("Foo<fn(u8) -> u8, Bar>");
+        let (base, args) = ty.generic_args();
+        assert_eq!(base, "Foo");
+        assert_eq!(
+            args,
+            [Type::from_name("fn(u8) -> u8"), Type::from_name("Bar")]
+        );
+
+        let ty = Type::from_name("Foo<Box<dyn Fn() -> Vec<u8>>>");
+        let (_, args) = ty.generic_args();
+        assert_eq!(args, [Type::from_name("Box<dyn Fn() -> Vec<u8>>")]);
+
+        let ty = Type::of::<u8>();
+        assert_eq!(ty.generic_args(), ("u8", Vec::new()));
+    }
+
+    #[test]
+    fn test_display() {
+        let parallel = ParallelFlow::<u8, u16, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .build(async |_, _: &mut LocalStorageImpl| Ok(NodeOutput::Ok(0u16)));
+        let flow = SequentialFlow::<u8, u16, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(parallel)
+            .build()
+            .with_description("example");
+
+        let display = flow.describe().to_string();
+        let lines = display.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            [
+                "SequentialFlow (u8 -> u16): example",
+                "  Passer<u8, u8, ()> (u8 -> u8)",
+                "  ParallelFlow (u8 -> u16)",
+                "    Passer<u8, u8, ()> (u8 -> u8)",
+                "    Joiner ((NodeOutput<u8>,) -> u16)",
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let flow = ParallelFlow::<u8, u16, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(Passer::<u8, u8, ()>::new())
+            .build(async |_, _: &mut LocalStorageImpl| Ok(NodeOutput::Ok(0u16)));
+        let json = serde_json::to_string(&flow.describe()).unwrap();
+        assert!(json.starts_with(r#"{"kind":"Flow""#));
+
+        let parsed = serde_json::from_str::<Description>(&json).unwrap();
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+        let Description::Flow { nodes, edges, .. } = parsed else {
+            panic!("expected flow description");
+        };
+        assert_eq!(nodes.len(), 3);
+        assert!(!edges.is_empty());
+    }
+}
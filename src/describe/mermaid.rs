@@ -0,0 +1,353 @@
+use super::design::{Description, Edge, EdgeEnding, ExternalResource, Type};
+use std::{borrow::Cow, fmt::Write};
+
+/// A configurable formatter for converting [`Description`] structures into
+/// [Mermaid](https://mermaid.js.org/) flowchart syntax.
+///
+/// Nodes are rendered as boxes and flows as subgraphs containing their nodes.
+///
+/// # Examples
+///
+/// ```
+/// use node_flow::describe::{Description, MermaidDescriber};
+/// use node_flow::node::{Node, NodeOutput};
+///
+/// # struct ExampleNode;
+/// #
+/// # impl Node<i32, NodeOutput<String>, (), ()> for ExampleNode {
+/// #     async fn run(
+/// #         &mut self,
+/// #         input: i32,
+/// #         _context: &mut (),
+/// #     ) -> Result<NodeOutput<String>, ()> {
+/// #         Ok(NodeOutput::Ok(format!("Processed: {}", input)))
+/// #     }
+/// # }
+/// let flow = ExampleNode;
+/// let some_description = flow.describe();
+///
+/// let mut describer = MermaidDescriber::new();
+/// describer.modify(|cfg| {
+///     cfg.show_description = true;
+///     cfg.show_externals = true;
+/// });
+///
+/// let mermaid_code = describer.format(&some_description);
+/// println!("{}", mermaid_code);
+/// // Output could be fed to a Mermaid renderer for visualization.
+/// ```
+#[expect(clippy::struct_excessive_bools)]
+#[derive(Debug)]
+pub struct MermaidDescriber {
+    /// Whether to display simplified type names instead of full paths.
+    ///
+    /// When enabled, types like `my_crate::nodes::ExampleNode` become `ExampleNode`.
+    /// This makes diagrams more readable, especially for complex flows.
+    ///
+    /// Simplified names are provided by [`Type::get_name_simple`],
+    /// whose `describe_get_name_simple` feature is always enabled together with this describer.
+    pub simple_type_name: bool,
+    /// Whether to display the node context type inside each node.
+    ///
+    /// When enabled, context will be added to node's label.
+    pub show_context_in_node: bool,
+    /// Whether to include the node's description.
+    ///
+    /// When enabled, description will be added to node's label.
+    pub show_description: bool,
+    /// Whether to include information about external resources.
+    ///
+    /// When enabled, external resources will be connected to the node.
+    pub show_externals: bool,
+}
+
+impl Default for MermaidDescriber {
+    fn default() -> Self {
+        Self {
+            simple_type_name: true,
+            show_context_in_node: false,
+            show_description: false,
+            show_externals: false,
+        }
+    }
+}
+
+fn escape_str(val: &str) -> String {
+    val.replace('#', "#35;")
+        .replace('"', "#quot;")
+        .replace(['\r', '\n'], " ")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+        .replace('|', "#124;")
+}
+
+impl MermaidDescriber {
+    /// Creates a new [`MermaidDescriber`] using default configuration.
+    ///
+    /// Default settings:
+    /// - `simple_type_name`: `true`
+    /// - `show_context_in_node`: `false`
+    /// - `show_description`: `false`
+    /// - `show_externals`: `false`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows modification of the configuration using a closure.
+    ///
+    /// # Examples
+    /// ```
+    /// # use node_flow::describe::MermaidDescriber;
+    /// let mut describer = MermaidDescriber::new();
+    /// describer.modify(|cfg| {
+    ///     cfg.show_description = true;
+    ///     cfg.show_externals = true;
+    /// });
+    /// ```
+    pub fn modify(&mut self, func: impl FnOnce(&mut Self)) -> &mut Self {
+        func(self);
+        self
+    }
+
+    fn get_type_name<'a>(&self, r#type: &'a Type) -> Cow<'a, str> {
+        if self.simple_type_name {
+            let res = r#type.get_name_simple();
+            // fallback
+            if res.is_empty() {
+                return Cow::Borrowed(&r#type.name);
+            }
+            Cow::Owned(res)
+        } else {
+            Cow::Borrowed(&r#type.name)
+        }
+    }
+
+    fn edge_label(&self, r#type: &Type) -> String {
+        if r#type.name.is_empty() {
+            String::new()
+        } else {
+            format!("|\"{}\"|", escape_str(&self.get_type_name(r#type)))
+        }
+    }
+
+    /// Formats a [`Description`] into a Mermaid flowchart text representation.
+    ///
+    /// The resulting string can be embedded in a `mermaid` code block or rendered using
+    /// the [Mermaid live editor](https://mermaid.live/).
+    ///
+    /// # Parameters
+    /// - `desc`: The [`Description`] to be rendered.
+    ///
+    /// # Returns
+    /// A string containing valid Mermaid source code representing the description graph.
+    #[must_use]
+    pub fn format(&self, desc: &Description) -> String {
+        let base = desc.get_base_ref();
+        let mut res = format!(
+            r#"flowchart TD
+    classDef detached stroke-dasharray: 5 5
+    flow_start(["<i>Start</i><br/><b>Context</b>: {context}<br/><b>Input</b>: {input}"])
+    flow_end(["<i>End</i><br/><b>Output</b>: {output}"])
+"#,
+            context = escape_str(&self.get_type_name(&base.context)),
+            input = escape_str(&self.get_type_name(&base.input)),
+            output = escape_str(&self.get_type_name(&base.output)),
+        );
+
+        let mut next_id = 0;
+        let id = self.process(desc, &mut next_id, 1, &mut res);
+        writeln!(
+            res,
+            "    flow_start -->{} {id}",
+            self.edge_label(&base.input)
+        )
+        .unwrap();
+        writeln!(
+            res,
+            "    {id} -->{} flow_end",
+            self.edge_label(&base.output)
+        )
+        .unwrap();
+
+        res
+    }
+
+    fn process(
+        &self,
+        desc: &Description,
+        next_id: &mut usize,
+        depth: usize,
+        out: &mut String,
+    ) -> String {
+        let id = format!("n{next_id}");
+        *next_id += 1;
+        let indent = "    ".repeat(depth);
+        let base = desc.get_base_ref();
+
+        let mut label = escape_str(&self.get_type_name(&base.r#type));
+        let is_node = matches!(desc, Description::Node { .. });
+        if is_node && self.show_context_in_node && !base.context.name.is_empty() {
+            write!(
+                label,
+                "<br/><b>Context</b>: {}",
+                escape_str(&self.get_type_name(&base.context))
+            )
+            .unwrap();
+        }
+        if self.show_description
+            && let Some(description) = &base.description
+        {
+            write!(label, "<br/>{}", escape_str(description)).unwrap();
+        }
+
+        match desc {
+            Description::Node { .. } => {
+                writeln!(out, "{indent}{id}[\"{label}\"]").unwrap();
+            }
+            Description::Flow { base, nodes, edges } => {
+                writeln!(
+                    out,
+                    r#"{indent}subgraph {id} ["{label}"]
+{indent}    direction TB
+{indent}    {id}_start(["<i>Start</i><br/><b>Context</b>: {context}<br/><b>Input</b>: {input}"])
+{indent}    {id}_end(["<i>End</i><br/><b>Output</b>: {output}"])"#,
+                    context = escape_str(&self.get_type_name(&base.context)),
+                    input = escape_str(&self.get_type_name(&base.input)),
+                    output = escape_str(&self.get_type_name(&base.output)),
+                )
+                .unwrap();
+
+                let nodes_and_ids = nodes
+                    .iter()
+                    .map(|node_desc| {
+                        let node_id = self.process(node_desc, next_id, depth + 1, out);
+                        (node_id, node_desc.get_base_ref())
+                    })
+                    .collect::<Vec<_>>();
+
+                for Edge { start, end } in edges {
+                    let (start_id, r#type) = match start {
+                        EdgeEnding::ToFlow => (format!("{id}_start"), &base.input),
+                        EdgeEnding::ToNode { node_index } => {
+                            let node = &nodes_and_ids[*node_index];
+                            (node.0.clone(), &node.1.output)
+                        }
+                    };
+                    let end_id = match end {
+                        EdgeEnding::ToFlow => format!("{id}_end"),
+                        EdgeEnding::ToNode { node_index } => nodes_and_ids[*node_index].0.clone(),
+                    };
+                    writeln!(
+                        out,
+                        "{indent}    {start_id} -->{} {end_id}",
+                        self.edge_label(r#type)
+                    )
+                    .unwrap();
+                }
+
+                writeln!(out, "{indent}end").unwrap();
+            }
+        }
+
+        if base.detached {
+            writeln!(out, "{indent}class {id} detached").unwrap();
+        }
+
+        if self.show_externals
+            && let Some(externals) = &base.externals
+        {
+            for (
+                idx,
+                ExternalResource {
+                    r#type,
+                    description,
+                    output,
+                },
+            ) in externals.iter().enumerate()
+            {
+                let mut label = format!(
+                    "{}<br/><b>output</b>: {}",
+                    escape_str(&self.get_type_name(r#type)),
+                    escape_str(&self.get_type_name(output)),
+                );
+                if let Some(description) = description {
+                    write!(label, "<br/>{}", escape_str(description)).unwrap();
+                }
+                writeln!(out, "{indent}{id}_ext{idx}[/\"{label}\"/]").unwrap();
+                writeln!(out, "{indent}{id} -.- {id}_ext{idx}").unwrap();
+            }
+        }
+
+        id
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MermaidDescriber, escape_str};
+    use crate::{
+        describe::{Description, DescriptionBase},
+        flows::SequentialFlow,
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct ExampleNode;
+
+    impl Node<i32, NodeOutput<i32>, (), ()> for ExampleNode {
+        async fn run(&mut self, input: i32, _context: &mut ()) -> Result<NodeOutput<i32>, ()> {
+            Ok(NodeOutput::Ok(input))
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        let flow = SequentialFlow::<i32, i32, (), ()>::builder()
+            .add_node(ExampleNode)
+            .add_node(ExampleNode)
+            .build();
+        let res = MermaidDescriber::new().format(&flow.describe());
+        let lines = res.lines().map(str::trim).collect::<Vec<_>>();
+
+        assert_eq!(lines[0], "flowchart TD");
+        for line in [
+            r#"subgraph n0 ["SequentialFlow"]"#,
+            r#"n1["ExampleNode"]"#,
+            r#"n2["ExampleNode"]"#,
+            r#"n0_start -->|"i32"| n1"#,
+            r#"n1 -->|"i32"| n2"#,
+            r#"n2 -->|"i32"| n0_end"#,
+            r#"flow_start -->|"i32"| n0"#,
+            r#"n0 -->|"i32"| flow_end"#,
+        ] {
+            assert!(lines.contains(&line), "missing line: {line}\n{res}");
+        }
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape_str("Vec<\"a|b#\">\nc"),
+            "Vec#lt;#quot;a#124;b#35;#quot;#gt; c"
+        );
+    }
+
+    #[test]
+    fn test_format_multi_line_description() {
+        let mut base = DescriptionBase::from::<(), (), (), (), ()>();
+        base.description = Some("first line\r\nsecond line".to_owned());
+        let desc = Description::Node { base };
+
+        let mut describer = MermaidDescriber::new();
+        describer.modify(|cfg| cfg.show_description = true);
+        let res = describer.format(&desc);
+
+        let label_lines = res
+            .lines()
+            .filter(|line| line.contains("line"))
+            .collect::<Vec<_>>();
+        assert_eq!(label_lines.len(), 1, "{res}");
+        assert!(label_lines[0].contains("first line  second line"), "{res}");
+    }
+}
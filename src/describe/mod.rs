@@ -1,6 +1,7 @@
 //! This module contains all the necessary components for describing the structure of a flow.
 //!
-//! It also contains a [`D2Describer`] for formatting [`Description`] into [D2](https://d2lang.com/) graph syntax.
+//! It also contains a [`D2Describer`] for formatting [`Description`] into [D2](https://d2lang.com/) graph syntax
+//! and a `MermaidDescriber` (behind the `mermaiddescriber` feature) for formatting it into [Mermaid](https://mermaid.js.org/) flowchart syntax.
 //!
 //! For details, see the documentation of [`Description`].
 
@@ -12,6 +13,11 @@ mod d2;
 #[cfg(feature = "d2describer")]
 pub use d2::*;
 
+#[cfg(feature = "mermaiddescriber")]
+mod mermaid;
+#[cfg(feature = "mermaiddescriber")]
+pub use mermaid::*;
+
 pub(crate) fn remove_generics_from_name(orig_name: &mut String) {
     let generic_start_idx = orig_name.find('<').unwrap_or(orig_name.len());
     orig_name.truncate(generic_start_idx);
@@ -0,0 +1,399 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    context::{Clock, Fork, SpawnAsync, Task},
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::{
+        NodeResult,
+        batch_result::{Batch, FinishOnDrop},
+    },
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// `BatchFlow` accumulates inputs of many runs and passes them to a node **in chunks**.
+///
+/// Every run adds its input to the current batch and waits until the batch is flushed.
+/// The batch is flushed when `max_batch` inputs accumulate or when `max_delay` elapses
+/// since the first input of the batch was added.
+/// The node receives all inputs of the batch as a [`Vec`] and its result is returned by all runs from the batch.
+///
+/// When the batch is full, the node is executed with the context of the run that filled it.
+/// When `max_delay` elapses, the node is executed in a task spawned using the [`SpawnAsync`] context trait
+/// with a forked context (the delay is measured using the [`Clock`] context trait).
+/// The forked context is not joined back, since the runs from the batch may have already returned,
+/// so changes made by the node to the context are discarded in that case.
+/// If the run executing the node is canceled before the node finishes, the other runs from the batch soft fail.
+///
+/// The batch state is shared between all clones of the flow,
+/// so concurrent runs of cloned flows are batched together.
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::context::{Clock, Fork, SpawnAsync, Task};
+/// use node_flow::flows::BatchFlow;
+/// use std::time::Duration;
+///
+/// #[derive(Clone)]
+/// struct InsertRows;
+///
+/// impl<Ctx: Send> Node<Vec<u8>, NodeOutput<usize>, (), Ctx> for InsertRows {
+///     async fn run(&mut self, rows: Vec<u8>, _: &mut Ctx) -> Result<NodeOutput<usize>, ()> {
+///         Ok(NodeOutput::Ok(rows.len()))
+///     }
+/// }
+///
+/// struct ExampleCtx;
+/// impl Clock for ExampleCtx // ...
+/// # {
+/// #     fn sleep(duration: Duration) -> impl Future<Output = ()> + Send {
+/// #         tokio::time::sleep(duration)
+/// #     }
+/// # }
+/// impl Fork for ExampleCtx // ...
+/// # { fn fork(&self) -> Self { Self } }
+/// impl SpawnAsync for ExampleCtx // ...
+/// # {
+/// #     fn spawn<F>(fut: F) -> impl Task<F::Output> + Send
+/// #     where
+/// #         F: Future + Send + 'static,
+/// #         F::Output: Send + 'static,
+/// #     {
+/// #         DummyTask(tokio::spawn(fut))
+/// #     }
+/// # }
+/// # struct DummyTask<T>(tokio::task::JoinHandle<T>);
+/// # impl<T> Future for DummyTask<T> {
+/// #     type Output = T;
+/// #     fn poll(
+/// #         self: std::pin::Pin<&mut Self>,
+/// #         cx: &mut std::task::Context<'_>,
+/// #     ) -> std::task::Poll<Self::Output> {
+/// #         std::pin::Pin::new(&mut self.get_mut().0).poll(cx).map(Result::unwrap)
+/// #     }
+/// # }
+/// # impl<T> Task<T> for DummyTask<T> {
+/// #     fn is_finished(&self) -> bool { self.0.is_finished() }
+/// #     fn cancel(self) { self.0.abort(); }
+/// # }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut first = BatchFlow::<u8, usize, (), ExampleCtx>::new(
+///         InsertRows,
+///         2,
+///         Duration::from_millis(10),
+///     );
+///     let mut second = first.clone();
+///
+///     let (mut ctx1, mut ctx2) = (ExampleCtx, ExampleCtx);
+///     let (a, b) = tokio::join!(first.run(1, &mut ctx1), second.run(2, &mut ctx2));
+///     assert_eq!(a, Ok(NodeOutput::Ok(2)));
+///     assert_eq!(b, Ok(NodeOutput::Ok(2)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct BatchFlow<Input, Output, Error, Context, NodeType = (), NodeOutput = (), NodeError = ()>
+{
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    _node_oe: PhantomData<fn() -> (NodeOutput, NodeError)>,
+    node: NodeType,
+    state: Arc<Mutex<BatchState<Input, Output, Error>>>,
+    max_batch: usize,
+    max_delay: Duration,
+}
+
+struct BatchState<Input, Output, Error> {
+    /// Inputs of the current batch.
+    inputs: Vec<Input>,
+    /// Result of the current batch.
+    batch: Arc<Batch<Output, Error>>,
+}
+
+impl<Input, Output, Error> Debug for BatchState<Input, Output, Error> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchState")
+            .field("pending", &self.inputs.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context> BatchFlow<Input, Output, Error, Context> {
+    /// Creates a new [`BatchFlow`] that runs `node` once `max_batch` inputs accumulate
+    /// or `max_delay` elapses since the first input of the batch.
+    ///
+    /// # Panics
+    /// Panics if `max_batch` is `0`.
+    pub fn new<NodeType, NodeOutput, NodeError>(
+        node: NodeType,
+        max_batch: usize,
+        max_delay: Duration,
+    ) -> BatchFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    where
+        NodeType: Node<Vec<Input>, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeOutput: Into<Output>,
+        NodeError: Into<Error>,
+    {
+        assert!(max_batch > 0, "max_batch must be greater than 0");
+        BatchFlow {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node,
+            state: Arc::new(Mutex::new(BatchState {
+                inputs: Vec::new(),
+                batch: Arc::new(Batch::new()),
+            })),
+            max_batch,
+            max_delay,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Debug
+    for BatchFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchFlow")
+            .field("node", &self.node)
+            .field("state", &self.state)
+            .field("max_batch", &self.max_batch)
+            .field("max_delay", &self.max_delay)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Clone
+    for BatchFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node: self.node.clone(),
+            state: self.state.clone(),
+            max_batch: self.max_batch,
+            max_delay: self.max_delay,
+        }
+    }
+}
+
+/// Runs the node with the inputs of the batch and shares its result with all runs from the batch.
+async fn flush<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>(
+    node: &mut NodeType,
+    inputs: Vec<Input>,
+    context: &mut Context,
+    batch: &Batch<Output, Error>,
+) -> NodeResult<Output, Error>
+where
+    NodeType: Node<Vec<Input>, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+    NodeOutput: Into<Output>,
+    NodeError: Into<Error>,
+    Output: Clone,
+    Error: Clone,
+{
+    let mut guard = FinishOnDrop {
+        batch,
+        finished: false,
+    };
+    let result = match node.run(inputs, context).await {
+        Ok(NodeOutputStruct::Ok(output)) => Ok(NodeOutputStruct::Ok(output.into())),
+        Ok(NodeOutputStruct::SoftFail) => Ok(NodeOutputStruct::SoftFail),
+        Err(err) => Err(err.into()),
+    };
+    batch.finish(result.clone());
+    guard.finished = true;
+    result
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for BatchFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType:
+        Node<Vec<Input>, NodeOutputStruct<NodeOutput>, NodeError, Context> + Clone + Send + 'static,
+    NodeOutput: Into<Output>,
+    NodeError: Into<Error>,
+    Input: Send + 'static,
+    Output: Clone + Send + 'static,
+    Error: Clone + Send + 'static,
+    Context: SpawnAsync + Fork + Clock + Send + 'static,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        let (inputs, batch, first) = {
+            let mut state = self.state.lock().unwrap();
+            state.inputs.push(input);
+            let batch = state.batch.clone();
+            if state.inputs.len() >= self.max_batch {
+                // batch is full, following runs start a new one
+                state.batch = Arc::new(Batch::new());
+                (Some(std::mem::take(&mut state.inputs)), batch, false)
+            } else {
+                (None, batch, state.inputs.len() == 1)
+            }
+        };
+
+        if let Some(inputs) = inputs {
+            return flush(&mut self.node, inputs, context, &batch).await;
+        }
+
+        if first {
+            Context::spawn({
+                let mut node = self.node.clone();
+                let mut context = context.fork();
+                let state = self.state.clone();
+                let batch = batch.clone();
+                let max_delay = self.max_delay;
+                async move {
+                    Context::sleep(max_delay).await;
+                    let inputs = {
+                        let mut state = state.lock().unwrap();
+                        if Arc::ptr_eq(&state.batch, &batch) {
+                            state.batch = Arc::new(Batch::new());
+                            Some(std::mem::take(&mut state.inputs))
+                        } else {
+                            // batch was already flushed because it was full
+                            None
+                        }
+                    };
+                    if let Some(inputs) = inputs {
+                        let _ = flush(&mut node, inputs, &mut context, &batch).await;
+                    }
+                }
+            })
+            .detach();
+        }
+
+        batch.wait().await
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_flow(
+            self,
+            vec![self.node.describe()],
+            vec![Edge::flow_to_node(0), Edge::node_to_flow(0)],
+        )
+        .modify_name(remove_generics_from_name)
+        .with_description(format!(
+            "Max batch: {}, max delay: {:?}",
+            self.max_batch, self.max_delay
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    };
+
+    use super::BatchFlow;
+    use crate::{
+        context::test::TokioSpawner,
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone, Default)]
+    struct RecordBatches(Arc<Mutex<Vec<Vec<u8>>>>);
+
+    impl<C: Send> Node<Vec<u8>, NodeOutput<u16>, (), C> for RecordBatches {
+        async fn run(&mut self, input: Vec<u8>, _context: &mut C) -> Result<NodeOutput<u16>, ()> {
+            let sum = input.iter().copied().map(u16::from).sum();
+            self.0.lock().unwrap().push(input);
+            Ok(NodeOutput::Ok(sum))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow() {
+        let node = RecordBatches::default();
+        let flow =
+            BatchFlow::<u8, u16, (), TokioSpawner>::new(node.clone(), 2, Duration::from_millis(20));
+        let mut flows = [
+            flow.clone(),
+            flow.clone(),
+            flow.clone(),
+            flow.clone(),
+            flow.clone(),
+        ];
+        let [f1, f2, f3, f4, f5] = &mut flows;
+        let mut ctxs = [
+            TokioSpawner,
+            TokioSpawner,
+            TokioSpawner,
+            TokioSpawner,
+            TokioSpawner,
+        ];
+        let [c1, c2, c3, c4, c5] = &mut ctxs;
+
+        let results = tokio::join!(
+            f1.run(1, c1),
+            f2.run(2, c2),
+            f3.run(3, c3),
+            f4.run(4, c4),
+            f5.run(5, c5),
+        );
+
+        assert_eq!(
+            results,
+            (
+                Ok(NodeOutput::Ok(3)),
+                Ok(NodeOutput::Ok(3)),
+                Ok(NodeOutput::Ok(7)),
+                Ok(NodeOutput::Ok(7)),
+                Ok(NodeOutput::Ok(5)),
+            )
+        );
+        assert_eq!(*node.0.lock().unwrap(), [vec![1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[tokio::test]
+    async fn test_flow_max_delay() {
+        let node = RecordBatches::default();
+        let mut flow = BatchFlow::<u8, u16, (), TokioSpawner>::new(
+            node.clone(),
+            10,
+            Duration::from_millis(30),
+        );
+
+        let start = Instant::now();
+        let res = flow.run(4, &mut TokioSpawner).await;
+        let took = start.elapsed();
+
+        assert_eq!(res, Ok(NodeOutput::Ok(4)));
+        assert_eq!(*node.0.lock().unwrap(), [vec![4]]);
+        assert!(took.as_millis() >= 30);
+    }
+
+    #[test]
+    #[should_panic(expected = "max_batch must be greater than 0")]
+    fn test_flow_zero_max_batch() {
+        let _ = BatchFlow::<u8, u16, (), TokioSpawner>::new(
+            RecordBatches::default(),
+            0,
+            Duration::from_millis(30),
+        );
+    }
+}
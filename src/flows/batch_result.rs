@@ -0,0 +1,65 @@
+use std::{
+    future::poll_fn,
+    sync::Mutex,
+    task::{Poll, Waker},
+};
+
+use crate::{flows::NodeResult, node::NodeOutput as NodeOutputStruct};
+
+/// Result of a single batch of runs shared by all of its runs.
+pub struct Batch<Output, Error>(Mutex<BatchState<Output, Error>>);
+
+struct BatchState<Output, Error> {
+    result: Option<NodeResult<Output, Error>>,
+    wakers: Vec<Waker>,
+}
+
+impl<Output, Error> Batch<Output, Error> {
+    pub const fn new() -> Self {
+        Self(Mutex::new(BatchState {
+            result: None,
+            wakers: Vec::new(),
+        }))
+    }
+
+    pub fn finish(&self, result: NodeResult<Output, Error>) {
+        let mut state = self.0.lock().unwrap();
+        state.result = Some(result);
+        for waker in state.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    pub async fn wait(&self) -> NodeResult<Output, Error>
+    where
+        Output: Clone,
+        Error: Clone,
+    {
+        poll_fn(|cx| {
+            let mut state = self.0.lock().unwrap();
+            if let Some(result) = &state.result {
+                Poll::Ready(result.clone())
+            } else {
+                if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+                    state.wakers.push(cx.waker().clone());
+                }
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
+
+/// Finishes the batch with [`NodeOutput::SoftFail`](NodeOutputStruct::SoftFail) when the run is canceled.
+pub struct FinishOnDrop<'a, Output, Error> {
+    pub batch: &'a Batch<Output, Error>,
+    pub finished: bool,
+}
+
+impl<Output, Error> Drop for FinishOnDrop<'_, Output, Error> {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.batch.finish(Ok(NodeOutputStruct::SoftFail));
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    context::SpawnSync,
+    describe::{Description, remove_generics_from_name},
+    flows::NodeResult,
+    node::{Node, NodeOutput},
+};
+
+/// `BlockingNode` turns a synchronous function into a node that runs on a blocking pool.
+///
+/// When run, the function is offloaded using [`SpawnSync::spawn_blocking`] of the `Context`
+/// and the resulting [`Task`](crate::context::Task) is awaited.
+/// This keeps CPU-bound or otherwise blocking work from stalling the async runtime.
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this node.
+/// - `Output`: The type of data produced by this node.
+/// - `Error`: The type of error emitted by this node.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::BlockingNode;
+/// # use node_flow::context::{SpawnSync, Task};
+/// # use std::{pin::Pin, task::Poll};
+/// #
+/// # struct ExampleCtx;
+/// # struct ExampleTask<T>(tokio::task::JoinHandle<T>);
+/// # impl<T> Future for ExampleTask<T> {
+/// #     type Output = T;
+/// #     fn poll(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<T> {
+/// #         Pin::new(&mut self.0).poll(cx).map(Result::unwrap)
+/// #     }
+/// # }
+/// # impl<T> Task<T> for ExampleTask<T> {
+/// #     fn is_finished(&self) -> bool { self.0.is_finished() }
+/// #     fn cancel(self) { self.0.abort(); }
+/// # }
+/// # impl SpawnSync for ExampleCtx {
+/// #     fn spawn_blocking<F, O>(func: F) -> impl Task<O> + Send
+/// #     where
+/// #         F: Fn() -> O + Send + 'static,
+/// #         O: Send + 'static,
+/// #     {
+/// #         ExampleTask(tokio::task::spawn_blocking(func))
+/// #     }
+/// # }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut node = BlockingNode::<u64, u64, (), ExampleCtx>::new(|input: u64| {
+///         // some expensive computation
+///         Ok(NodeOutput::Ok((0..=input).sum()))
+///     });
+///
+///     let result = node.run(100, &mut ExampleCtx).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(5050)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct BlockingNode<Input, Output, Error, Context, F = ()> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    func: Arc<F>,
+}
+
+impl<Input, Output, Error, Context> BlockingNode<Input, Output, Error, Context> {
+    /// Creates a new [`BlockingNode`] from a synchronous function.
+    pub fn new<F>(func: F) -> BlockingNode<Input, Output, Error, Context, F>
+    where
+        F: Fn(Input) -> NodeResult<Output, Error> + Send + Sync + 'static,
+    {
+        BlockingNode {
+            _ioec: PhantomData,
+            func: Arc::new(func),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, F> Debug for BlockingNode<Input, Output, Error, Context, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlockingNode").finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, F> Clone for BlockingNode<Input, Output, Error, Context, F> {
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            func: self.func.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, F> Node<Input, NodeOutput<Output>, Error, Context>
+    for BlockingNode<Input, Output, Error, Context, F>
+where
+    F: Fn(Input) -> NodeResult<Output, Error> + Send + Sync + 'static,
+    Input: Send + 'static,
+    Output: Send + 'static,
+    Error: Send + 'static,
+    Context: SpawnSync + Send,
+{
+    async fn run(&mut self, input: Input, _context: &mut Context) -> NodeResult<Output, Error> {
+        let func = self.func.clone();
+        // spawn_blocking accepts `Fn`, so the input has to be moved out on the first call
+        let input = Mutex::new(Some(input));
+        Context::spawn_blocking(move || {
+            let input = input
+                .lock()
+                .unwrap()
+                .take()
+                .expect("blocking function should be called only once");
+            func(input)
+        })
+        .await
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_node::<Self, Input, Output, Error, Context>(self)
+            .modify_name(remove_generics_from_name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::thread::{self, ThreadId};
+
+    use super::BlockingNode;
+    use crate::{
+        context::test::TokioSpawner,
+        node::{Node, NodeOutput},
+    };
+
+    #[tokio::test]
+    async fn test_runs_off_async_thread() {
+        let async_thread = thread::current().id();
+        let mut node = BlockingNode::<u8, (u8, ThreadId), (), TokioSpawner>::new(|input: u8| {
+            Ok(NodeOutput::Ok((input + 1, thread::current().id())))
+        });
+
+        let res = node.run(1, &mut TokioSpawner).await;
+        let Ok(NodeOutput::Ok((output, blocking_thread))) = res else {
+            panic!("unexpected result: {res:?}");
+        };
+        assert_eq!(output, 2);
+        assert_ne!(async_thread, blocking_thread);
+    }
+
+    #[tokio::test]
+    async fn test_propagates_result() {
+        let mut node = BlockingNode::<u8, u8, u8, TokioSpawner>::new(|input: u8| match input {
+            0 => Err(0),
+            1 => Ok(NodeOutput::SoftFail),
+            x => Ok(NodeOutput::Ok(x)),
+        });
+
+        assert_eq!(node.run(0, &mut TokioSpawner).await, Err(0));
+        assert_eq!(
+            node.run(1, &mut TokioSpawner).await,
+            Ok(NodeOutput::SoftFail)
+        );
+        assert_eq!(node.run(2, &mut TokioSpawner).await, Ok(NodeOutput::Ok(2)));
+    }
+}
@@ -0,0 +1,334 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use super::state::State;
+use crate::{
+    context::Clock,
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::NodeResult,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+type OpenError<Error> = Arc<dyn Fn() -> Error + Send + Sync>;
+
+/// `CircuitBreakerFlow` stops running a node after it fails repeatedly.
+///
+/// The flow works as a circuit breaker with three states:
+/// - **Closed**: The node is run. When it returns an **error** `failure_threshold` times in a row,
+///   the circuit opens.
+///   Any other result resets the count.
+/// - **Open**: The node is not run and the flow immediately returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail),
+///   or an error when [`CircuitBreakerFlow::with_open_error`] was used.
+///   After `cooldown` elapses, the circuit becomes half-open.
+/// - **Half-open**: One trial run of the node is allowed, other runs behave as if the circuit was open.
+///   If the trial succeeds, the circuit closes, otherwise it opens again.
+///
+/// The state is shared between all clones of the flow, so it is kept between runs
+/// even when the flow is cloned (for example by other flows).
+/// Time is measured using the [`Clock`] context trait.
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::context::Clock;
+/// use node_flow::flows::CircuitBreakerFlow;
+/// use std::time::Duration;
+///
+/// #[derive(Clone)]
+/// struct Unavailable;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, String, Ctx> for Unavailable {
+///     async fn run(&mut self, _: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, String> {
+///         Err("service unavailable".to_owned())
+///     }
+/// }
+///
+/// struct ExampleCtx;
+/// impl Clock for ExampleCtx // ...
+/// # {
+/// #     async fn sleep(duration: Duration) {}
+/// # }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = CircuitBreakerFlow::<u8, u8, String, ExampleCtx>::new(
+///         Unavailable,
+///         2,
+///         Duration::from_secs(30),
+///     );
+///
+///     let mut ctx = ExampleCtx;
+///     assert!(flow.run(5, &mut ctx).await.is_err());
+///     assert!(flow.run(5, &mut ctx).await.is_err());
+///     // the circuit is open, the node is not run
+///     assert_eq!(flow.run(5, &mut ctx).await, Ok(NodeOutput::SoftFail));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct CircuitBreakerFlow<
+    Input,
+    Output,
+    Error,
+    Context,
+    NodeType = (),
+    NodeOutput = (),
+    NodeError = (),
+> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    _node_oe: PhantomData<fn() -> (NodeOutput, NodeError)>,
+    node: NodeType,
+    state: Arc<Mutex<State>>,
+    failure_threshold: u32,
+    cooldown: Duration,
+    open_error: Option<OpenError<Error>>,
+}
+
+impl<Input, Output, Error, Context> CircuitBreakerFlow<Input, Output, Error, Context> {
+    /// Creates a new [`CircuitBreakerFlow`] wrapping `node`.
+    ///
+    /// By default, the flow returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail) when the circuit is open.
+    ///
+    /// # Parameters
+    /// - `node`: The node to protect.
+    /// - `failure_threshold`: The number of consecutive errors after which the circuit opens.
+    /// - `cooldown`: How long the circuit stays open before a trial run is allowed.
+    ///
+    /// # Panics
+    /// Panics if `failure_threshold` is `0`.
+    pub fn new<NodeType, NodeOutput, NodeError>(
+        node: NodeType,
+        failure_threshold: u32,
+        cooldown: Duration,
+    ) -> CircuitBreakerFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    where
+        NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeOutput: Into<Output>,
+        NodeError: Into<Error>,
+    {
+        assert!(
+            failure_threshold > 0,
+            "failure_threshold must be greater than 0"
+        );
+        CircuitBreakerFlow {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node,
+            state: Arc::new(Mutex::new(State::Closed { failures: 0 })),
+            failure_threshold,
+            cooldown,
+            open_error: None,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    CircuitBreakerFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+{
+    /// Makes the flow return an error created by `open_error` when the circuit is open
+    /// instead of [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail).
+    #[must_use]
+    pub fn with_open_error<F>(mut self, open_error: F) -> Self
+    where
+        F: Fn() -> Error + Send + Sync + 'static,
+    {
+        self.open_error = Some(Arc::new(open_error));
+        self
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Debug
+    for CircuitBreakerFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CircuitBreakerFlow")
+            .field("node", &self.node)
+            .field("state", &self.state)
+            .field("failure_threshold", &self.failure_threshold)
+            .field("cooldown", &self.cooldown)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Clone
+    for CircuitBreakerFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node: self.node.clone(),
+            state: self.state.clone(),
+            failure_threshold: self.failure_threshold,
+            cooldown: self.cooldown,
+            open_error: self.open_error.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for CircuitBreakerFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context> + Send,
+    NodeOutput: Into<Output>,
+    NodeError: Into<Error>,
+    Input: Send,
+    Context: Clock + Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        let acquired = self
+            .state
+            .lock()
+            .unwrap()
+            .try_acquire(Context::now(), self.cooldown);
+        if !acquired {
+            return self
+                .open_error
+                .as_ref()
+                .map_or(Ok(NodeOutputStruct::SoftFail), |open_error| {
+                    Err(open_error())
+                });
+        }
+
+        let res = self.node.run(input, context).await;
+        self.state.lock().unwrap().record(
+            res.is_ok(),
+            Context::now(),
+            self.failure_threshold,
+            self.cooldown,
+        );
+        match res {
+            Ok(NodeOutputStruct::Ok(output)) => Ok(NodeOutputStruct::Ok(output.into())),
+            Ok(NodeOutputStruct::SoftFail) => Ok(NodeOutputStruct::SoftFail),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_flow(
+            self,
+            vec![self.node.describe()],
+            vec![Edge::flow_to_node(0), Edge::node_to_flow(0)],
+        )
+        .modify_name(remove_generics_from_name)
+        .with_description(format!(
+            "Circuit breaker: opens after {} consecutive failures for {:?}",
+            self.failure_threshold, self.cooldown
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicBool, AtomicU32, Ordering},
+        },
+        time::Duration,
+    };
+
+    use super::CircuitBreakerFlow;
+    use crate::{
+        context::test::TokioSpawner,
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct Switch {
+        fail: Arc<AtomicBool>,
+        runs: Arc<AtomicU32>,
+    }
+
+    impl Switch {
+        fn new(fail: bool) -> Self {
+            Self {
+                fail: Arc::new(fail.into()),
+                runs: Arc::default(),
+            }
+        }
+    }
+
+    impl<C: Send> Node<u8, NodeOutput<u8>, u32, C> for Switch {
+        async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u8>, u32> {
+            let runs = self.runs.fetch_add(1, Ordering::SeqCst) + 1;
+            if self.fail.load(Ordering::SeqCst) {
+                Err(runs)
+            } else {
+                Ok(NodeOutput::Ok(input))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow() {
+        let node = Switch::new(true);
+        let mut flow =
+            CircuitBreakerFlow::<u8, u64, u32, _>::new(node.clone(), 2, Duration::from_millis(50));
+
+        assert_eq!(flow.run(5, &mut TokioSpawner).await, Err(1));
+        assert_eq!(flow.run(5, &mut TokioSpawner).await, Err(2));
+        // open
+        assert_eq!(
+            flow.run(5, &mut TokioSpawner).await,
+            Ok(NodeOutput::SoftFail)
+        );
+        assert_eq!(node.runs.load(Ordering::SeqCst), 2);
+
+        // half-open, trial fails
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert_eq!(flow.run(5, &mut TokioSpawner).await, Err(3));
+        assert_eq!(
+            flow.run(5, &mut TokioSpawner).await,
+            Ok(NodeOutput::SoftFail)
+        );
+
+        // half-open, trial succeeds
+        node.fail.store(false, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert_eq!(flow.run(5, &mut TokioSpawner).await, Ok(NodeOutput::Ok(5)));
+        assert_eq!(flow.run(5, &mut TokioSpawner).await, Ok(NodeOutput::Ok(5)));
+        assert_eq!(node.runs.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn test_flow_reset_and_shared_state() {
+        let node = Switch::new(true);
+        let mut flow =
+            CircuitBreakerFlow::<u8, u64, u32, _>::new(node.clone(), 2, Duration::from_mins(1))
+                .with_open_error(|| 0);
+
+        assert_eq!(flow.run(5, &mut TokioSpawner).await, Err(1));
+        node.fail.store(false, Ordering::SeqCst);
+        assert_eq!(flow.run(5, &mut TokioSpawner).await, Ok(NodeOutput::Ok(5)));
+        node.fail.store(true, Ordering::SeqCst);
+        // failure count was reset
+        assert_eq!(flow.run(5, &mut TokioSpawner).await, Err(3));
+
+        let mut cloned = flow.clone();
+        assert_eq!(cloned.run(5, &mut TokioSpawner).await, Err(4));
+        assert_eq!(flow.run(5, &mut TokioSpawner).await, Err(0));
+        assert_eq!(node.runs.load(Ordering::SeqCst), 4);
+    }
+}
@@ -0,0 +1,43 @@
+use std::time::{Duration, Instant};
+
+/// State of the circuit shared between all clones of a [`CircuitBreakerFlow`](super::CircuitBreakerFlow).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    /// The node is run, consecutive failures are counted.
+    Closed { failures: u32 },
+    /// The node is not run until the cooldown elapses.
+    Open { until: Instant },
+    /// One trial run is in progress.
+    ///
+    /// If the trial doesn't finish (its future is dropped) before `until`, another trial is allowed.
+    HalfOpen { until: Instant },
+}
+
+impl State {
+    /// Returns `true` if the node can be run.
+    pub fn try_acquire(&mut self, now: Instant, cooldown: Duration) -> bool {
+        match *self {
+            Self::Closed { .. } => true,
+            Self::Open { until } | Self::HalfOpen { until } if now >= until => {
+                *self = Self::HalfOpen {
+                    until: now + cooldown,
+                };
+                true
+            }
+            Self::Open { .. } | Self::HalfOpen { .. } => false,
+        }
+    }
+
+    /// Records the result of a node run.
+    pub fn record(&mut self, success: bool, now: Instant, threshold: u32, cooldown: Duration) {
+        *self = match *self {
+            _ if success => Self::Closed { failures: 0 },
+            Self::Closed { failures } if failures + 1 < threshold => Self::Closed {
+                failures: failures + 1,
+            },
+            _ => Self::Open {
+                until: now + cooldown,
+            },
+        };
+    }
+}
@@ -0,0 +1,297 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::NodeResult,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// `ConditionalFlow` runs **one of two nodes** depending on the input.
+///
+/// The predicate is evaluated with the input:
+/// - If it returns `true`, the "then" node is run.
+/// - If it returns `false`, the "else" node is run.
+///
+/// The result of the executed node is returned.
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::ConditionalFlow;
+///
+/// #[derive(Clone)]
+/// struct Double;
+/// #[derive(Clone)]
+/// struct Negate;
+///
+/// impl<Ctx: Send> Node<i32, NodeOutput<i32>, (), Ctx> for Double {
+///     async fn run(&mut self, input: i32, _: &mut Ctx) -> Result<NodeOutput<i32>, ()> {
+///         Ok(NodeOutput::Ok(input * 2))
+///     }
+/// }
+///
+/// impl<Ctx: Send> Node<i32, NodeOutput<i32>, (), Ctx> for Negate {
+///     async fn run(&mut self, input: i32, _: &mut Ctx) -> Result<NodeOutput<i32>, ()> {
+///         Ok(NodeOutput::Ok(-input))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = ConditionalFlow::<i32, i32, (), ()>::new(
+///         |input: &i32| *input >= 0,
+///         Double,
+///         Negate,
+///     );
+///
+///     assert_eq!(flow.run(5, &mut ()).await, Ok(NodeOutput::Ok(10)));
+///     assert_eq!(flow.run(-5, &mut ()).await, Ok(NodeOutput::Ok(5)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct ConditionalFlow<
+    Input,
+    Output,
+    Error,
+    Context,
+    Predicate = (),
+    Then = (),
+    Else = (),
+    ThenOutput = (),
+    ThenError = (),
+    ElseOutput = (),
+    ElseError = (),
+> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    #[expect(clippy::type_complexity)]
+    _branches_oe: PhantomData<fn() -> (ThenOutput, ThenError, ElseOutput, ElseError)>,
+    predicate: Predicate,
+    then: Then,
+    r#else: Else,
+}
+
+impl<Input, Output, Error, Context> ConditionalFlow<Input, Output, Error, Context> {
+    /// Creates a new [`ConditionalFlow`].
+    ///
+    /// # Parameters
+    /// - `predicate`: Selects the node to run, `true` selects `then` and `false` selects `r#else`.
+    /// - `then`: The node run when the predicate returns `true`.
+    /// - `r#else`: The node run when the predicate returns `false`.
+    pub fn new<Predicate, Then, Else, ThenOutput, ThenError, ElseOutput, ElseError>(
+        predicate: Predicate,
+        then: Then,
+        r#else: Else,
+    ) -> ConditionalFlow<
+        Input,
+        Output,
+        Error,
+        Context,
+        Predicate,
+        Then,
+        Else,
+        ThenOutput,
+        ThenError,
+        ElseOutput,
+        ElseError,
+    >
+    where
+        Predicate: Fn(&Input) -> bool,
+        Then: Node<Input, NodeOutputStruct<ThenOutput>, ThenError, Context>,
+        Else: Node<Input, NodeOutputStruct<ElseOutput>, ElseError, Context>,
+        // Trait bounds for better and nicer errors
+        ThenOutput: Into<Output>,
+        ThenError: Into<Error>,
+        ElseOutput: Into<Output>,
+        ElseError: Into<Error>,
+    {
+        ConditionalFlow {
+            _ioec: PhantomData,
+            _branches_oe: PhantomData,
+            predicate,
+            then,
+            r#else,
+        }
+    }
+}
+
+impl<
+    Input,
+    Output,
+    Error,
+    Context,
+    Predicate,
+    Then,
+    Else,
+    ThenOutput,
+    ThenError,
+    ElseOutput,
+    ElseError,
+> Debug
+    for ConditionalFlow<
+        Input,
+        Output,
+        Error,
+        Context,
+        Predicate,
+        Then,
+        Else,
+        ThenOutput,
+        ThenError,
+        ElseOutput,
+        ElseError,
+    >
+where
+    Then: Debug,
+    Else: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConditionalFlow")
+            .field("then", &self.then)
+            .field("else", &self.r#else)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<
+    Input,
+    Output,
+    Error,
+    Context,
+    Predicate,
+    Then,
+    Else,
+    ThenOutput,
+    ThenError,
+    ElseOutput,
+    ElseError,
+> Clone
+    for ConditionalFlow<
+        Input,
+        Output,
+        Error,
+        Context,
+        Predicate,
+        Then,
+        Else,
+        ThenOutput,
+        ThenError,
+        ElseOutput,
+        ElseError,
+    >
+where
+    Predicate: Clone,
+    Then: Clone,
+    Else: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _branches_oe: PhantomData,
+            predicate: self.predicate.clone(),
+            then: self.then.clone(),
+            r#else: self.r#else.clone(),
+        }
+    }
+}
+
+impl<
+    Input,
+    Output,
+    Error,
+    Context,
+    Predicate,
+    Then,
+    Else,
+    ThenOutput,
+    ThenError,
+    ElseOutput,
+    ElseError,
+> Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for ConditionalFlow<
+        Input,
+        Output,
+        Error,
+        Context,
+        Predicate,
+        Then,
+        Else,
+        ThenOutput,
+        ThenError,
+        ElseOutput,
+        ElseError,
+    >
+where
+    Predicate: Fn(&Input) -> bool + Send + Sync,
+    Then: Node<Input, NodeOutputStruct<ThenOutput>, ThenError, Context> + Clone + Send + Sync,
+    Else: Node<Input, NodeOutputStruct<ElseOutput>, ElseError, Context> + Clone + Send + Sync,
+    ThenOutput: Into<Output>,
+    ThenError: Into<Error>,
+    ElseOutput: Into<Output>,
+    ElseError: Into<Error>,
+    Input: Send,
+    Context: Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        if (self.predicate)(&input) {
+            let mut node = self.then.clone();
+            Ok(match node.run(input, context).await.map_err(Into::into)? {
+                NodeOutputStruct::Ok(output) => NodeOutputStruct::Ok(output.into()),
+                NodeOutputStruct::SoftFail => NodeOutputStruct::SoftFail,
+            })
+        } else {
+            let mut node = self.r#else.clone();
+            Ok(match node.run(input, context).await.map_err(Into::into)? {
+                NodeOutputStruct::Ok(output) => NodeOutputStruct::Ok(output.into()),
+                NodeOutputStruct::SoftFail => NodeOutputStruct::SoftFail,
+            })
+        }
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_flow(
+            self,
+            vec![self.then.describe(), self.r#else.describe()],
+            vec![
+                Edge::flow_to_node(0),
+                Edge::flow_to_node(1),
+                Edge::node_to_flow(0),
+                Edge::node_to_flow(1),
+            ],
+        )
+        .modify_name(remove_generics_from_name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConditionalFlow;
+    use crate::{
+        context::storage::local_storage::LocalStorageImpl,
+        flows::tests::{Passer, SoftFailNode},
+        node::{Node, NodeOutput},
+    };
+
+    #[tokio::test]
+    async fn test_flow() {
+        let mut st = LocalStorageImpl::new();
+        let mut flow = ConditionalFlow::<u8, u64, (), _>::new(
+            |input: &u8| *input > 5,
+            Passer::<u8, u32, ()>::new(),
+            SoftFailNode::<u8, u16, ()>::new(),
+        );
+        assert_eq!(flow.run(10, &mut st).await, Ok(NodeOutput::Ok(10)));
+        assert_eq!(flow.run(1, &mut st).await, Ok(NodeOutput::SoftFail));
+    }
+}
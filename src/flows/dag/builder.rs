@@ -0,0 +1,266 @@
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use super::{
+    DagError, DagFlow,
+    erased::{ErasedNode, Joined, Single, TypeInfo},
+    flow::Plan,
+};
+use crate::{
+    context::{Fork, Join},
+    describe::{Edge, EdgeEnding},
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// Builder for [`DagFlow`].
+///
+/// Nodes are added using [`Builder::add_node`] or [`Builder::add_join_node`],
+/// which return the index of the added node.
+/// These indices are then used for connecting nodes using [`Edge`]s added by [`Builder::add_edge`].
+///
+/// See also [`DagFlow`].
+pub struct Builder<Input, Output, Error, Context>
+where
+    // Trait bounds for better and nicer errors
+    Input: Send + Clone + 'static,
+    Output: Send + 'static,
+    Error: Send,
+    Context: Fork + Join + Send,
+{
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    nodes: Vec<Box<dyn ErasedNode<Error, Context>>>,
+    edges: Vec<Edge>,
+}
+
+impl<Input, Output, Error, Context> Debug for Builder<Input, Output, Error, Context>
+where
+    Input: Send + Clone + 'static,
+    Output: Send + 'static,
+    Error: Send,
+    Context: Fork + Join + Send,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DagFlowBuilder")
+            .field("node_count", &self.nodes.len())
+            .field("edges", &self.edges)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context> Default for Builder<Input, Output, Error, Context>
+where
+    // Trait bounds for better and nicer errors
+    Input: Send + Clone + 'static,
+    Output: Send + 'static,
+    Error: Send,
+    Context: Fork + Join + Send,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Input, Output, Error, Context> Builder<Input, Output, Error, Context>
+where
+    // Trait bounds for better and nicer errors
+    Input: Send + Clone + 'static,
+    Output: Send + 'static,
+    Error: Send,
+    Context: Fork + Join + Send,
+{
+    /// Creates a new empty builder for [`DagFlow`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            _ioec: PhantomData,
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Adds a node with exactly one incoming edge.
+    ///
+    /// The node receives the data passed along its incoming edge.
+    ///
+    /// # Returns
+    /// The index of the added node, which can be used in [`Edge`]s.
+    pub fn add_node<NodeType, NodeInput, NodeOutput, NodeError>(&mut self, node: NodeType) -> usize
+    where
+        NodeType: Node<NodeInput, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        NodeError: Into<Error> + 'static,
+        // Trait bounds for better and nicer errors
+        NodeType: Clone + Send + Sync + 'static,
+        NodeInput: Send + 'static,
+        NodeOutput: Clone + Send + 'static,
+    {
+        self.nodes.push(Box::new(Single {
+            node,
+            _ioe: PhantomData::<fn() -> (NodeInput, NodeOutput, NodeError)>,
+        }));
+        self.nodes.len() - 1
+    }
+
+    /// Adds a node with at least one incoming edge.
+    ///
+    /// The node receives a [`Vec`] of the data passed along all its incoming edges,
+    /// in the order in which the edges were added.
+    ///
+    /// # Returns
+    /// The index of the added node, which can be used in [`Edge`]s.
+    pub fn add_join_node<NodeType, NodeInput, NodeOutput, NodeError>(
+        &mut self,
+        node: NodeType,
+    ) -> usize
+    where
+        NodeType: Node<Vec<NodeInput>, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        NodeError: Into<Error> + 'static,
+        // Trait bounds for better and nicer errors
+        NodeType: Clone + Send + Sync + 'static,
+        NodeInput: Send + 'static,
+        NodeOutput: Clone + Send + 'static,
+    {
+        self.nodes.push(Box::new(Joined {
+            node,
+            _ioe: PhantomData::<fn() -> (NodeInput, NodeOutput, NodeError)>,
+        }));
+        self.nodes.len() - 1
+    }
+
+    /// Adds an edge connecting the flow input, nodes and the flow output.
+    ///
+    /// Data produced at the start of the edge is passed to its end.
+    /// When data is passed along multiple edges, it is cloned.
+    pub fn add_edge(&mut self, edge: Edge) -> &mut Self {
+        self.edges.push(edge);
+        self
+    }
+
+    /// Validates the graph and builds the [`DagFlow`].
+    ///
+    /// # Errors
+    /// Returns [`DagError`] when:
+    /// - an edge references a node that doesn't exist,
+    /// - a node has an invalid number of incoming edges,
+    /// - the flow doesn't have exactly one edge ending in the flow output,
+    /// - the type of data passed along an edge doesn't match,
+    /// - the edges form a cycle.
+    pub fn build(self) -> Result<DagFlow<Input, Output, Error, Context>, DagError> {
+        let node_count = self.nodes.len();
+        let mut inputs = vec![Vec::new(); node_count];
+        let mut consumers = vec![0usize; node_count];
+        let mut input_consumers = 0;
+        let mut output = Vec::new();
+
+        for (edge_idx, Edge { start, end }) in self.edges.iter().enumerate() {
+            let found = match start {
+                EdgeEnding::ToFlow => {
+                    input_consumers += 1;
+                    TypeInfo::of::<Input>()
+                }
+                EdgeEnding::ToNode { node_index } => {
+                    let node = self
+                        .nodes
+                        .get(*node_index)
+                        .ok_or(DagError::InvalidNodeIndex {
+                            edge: edge_idx,
+                            node_index: *node_index,
+                        })?;
+                    consumers[*node_index] += 1;
+                    node.output()
+                }
+            };
+            let expected = match end {
+                EdgeEnding::ToFlow => {
+                    output.push(start.clone());
+                    TypeInfo::of::<Output>()
+                }
+                EdgeEnding::ToNode { node_index } => {
+                    let node = self
+                        .nodes
+                        .get(*node_index)
+                        .ok_or(DagError::InvalidNodeIndex {
+                            edge: edge_idx,
+                            node_index: *node_index,
+                        })?;
+                    inputs[*node_index].push(start.clone());
+                    node.input()
+                }
+            };
+            if found.id != expected.id {
+                return Err(DagError::TypeMismatch {
+                    edge: edge_idx,
+                    expected: expected.name,
+                    found: found.name,
+                });
+            }
+        }
+
+        for (node_index, node) in self.nodes.iter().enumerate() {
+            let count = inputs[node_index].len();
+            if count == 0 || (count > 1 && !node.is_join()) {
+                return Err(DagError::InvalidInputCount { node_index, count });
+            }
+        }
+        let [output] =
+            <[_; 1]>::try_from(output).map_err(|output| DagError::InvalidOutputCount {
+                count: output.len(),
+            })?;
+        if let EdgeEnding::ToNode { node_index } = &output {
+            consumers[*node_index] += 1;
+        }
+
+        let levels = levels(&inputs).ok_or(DagError::Cycle)?;
+
+        Ok(DagFlow {
+            _ioec: PhantomData,
+            nodes: self.nodes.into(),
+            plan: Arc::new(Plan {
+                edges: self.edges,
+                levels,
+                inputs,
+                output,
+                consumers,
+                input_consumers,
+            }),
+        })
+    }
+}
+
+/// Groups nodes into levels, where each node depends only on nodes from previous levels.
+///
+/// Returns `None` if the graph contains a cycle.
+fn levels(inputs: &[Vec<EdgeEnding>]) -> Option<Vec<Vec<usize>>> {
+    let node_count = inputs.len();
+    let mut dependants = vec![Vec::new(); node_count];
+    let mut remaining = vec![0usize; node_count];
+    for (node_index, node_inputs) in inputs.iter().enumerate() {
+        for input in node_inputs {
+            if let EdgeEnding::ToNode { node_index: dep } = input {
+                dependants[*dep].push(node_index);
+                remaining[node_index] += 1;
+            }
+        }
+    }
+
+    let mut levels = Vec::new();
+    let mut current = (0..node_count)
+        .filter(|idx| remaining[*idx] == 0)
+        .collect::<Vec<_>>();
+    let mut processed = 0;
+    while !current.is_empty() {
+        processed += current.len();
+        let mut next = Vec::new();
+        for node_index in &current {
+            for dependant in &dependants[*node_index] {
+                remaining[*dependant] -= 1;
+                if remaining[*dependant] == 0 {
+                    next.push(*dependant);
+                }
+            }
+        }
+        levels.push(current);
+        current = next;
+    }
+
+    (processed == node_count).then_some(levels)
+}
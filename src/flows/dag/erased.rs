@@ -0,0 +1,139 @@
+use std::{
+    any::{Any, TypeId, type_name},
+    marker::PhantomData,
+    pin::Pin,
+};
+
+use crate::{
+    describe::Description,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+pub type AnyValue = Box<dyn Any + Send>;
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct TypeInfo {
+    pub id: TypeId,
+    pub name: &'static str,
+}
+
+impl TypeInfo {
+    pub fn of<T: 'static>() -> Self {
+        Self {
+            id: TypeId::of::<T>(),
+            name: type_name::<T>(),
+        }
+    }
+}
+
+pub fn clone_value<T: Clone + Send + 'static>(value: &AnyValue) -> AnyValue {
+    Box::new(value.downcast_ref::<T>().unwrap().clone())
+}
+
+pub trait ErasedNode<Error, Context>: Send + Sync {
+    fn input(&self) -> TypeInfo;
+    fn output(&self) -> TypeInfo;
+    fn is_join(&self) -> bool;
+    fn clone_output(&self, value: &AnyValue) -> AnyValue;
+    fn run<'a>(
+        &'a self,
+        inputs: Vec<AnyValue>,
+        context: &'a mut Context,
+    ) -> BoxFuture<'a, Result<Option<AnyValue>, Error>>
+    where
+        Error: 'a,
+        Context: 'a;
+    fn describe(&self) -> Description;
+}
+
+pub struct Single<NodeType, Input, Output, NodeError> {
+    pub node: NodeType,
+    #[expect(clippy::type_complexity)]
+    pub _ioe: PhantomData<fn() -> (Input, Output, NodeError)>,
+}
+
+pub struct Joined<NodeType, Input, Output, NodeError> {
+    pub node: NodeType,
+    #[expect(clippy::type_complexity)]
+    pub _ioe: PhantomData<fn() -> (Input, Output, NodeError)>,
+}
+
+async fn run_node<NodeType, Input, Output, NodeError, Error, Context>(
+    mut node: NodeType,
+    input: Input,
+    context: &mut Context,
+) -> Result<Option<AnyValue>, Error>
+where
+    NodeType: Node<Input, NodeOutputStruct<Output>, NodeError, Context>,
+    NodeError: Into<Error>,
+    Output: Send + 'static,
+{
+    Ok(match node.run(input, context).await.map_err(Into::into)? {
+        NodeOutputStruct::Ok(output) => Some(Box::new(output)),
+        NodeOutputStruct::SoftFail => None,
+    })
+}
+
+macro_rules! impl_erased_node {
+    ($name:ident, $node_input:ty, $is_join:literal, |$inputs:ident| $conv:expr) => {
+        impl<NodeType, Input, Output, NodeError, Error, Context> ErasedNode<Error, Context>
+            for $name<NodeType, Input, Output, NodeError>
+        where
+            NodeType: Node<$node_input, NodeOutputStruct<Output>, NodeError, Context>
+                + Clone
+                + Send
+                + Sync,
+            Input: Send + 'static,
+            Output: Clone + Send + 'static,
+            NodeError: Into<Error>,
+            Error: Send,
+            Context: Send,
+        {
+            fn input(&self) -> TypeInfo {
+                TypeInfo::of::<Input>()
+            }
+
+            fn output(&self) -> TypeInfo {
+                TypeInfo::of::<Output>()
+            }
+
+            fn is_join(&self) -> bool {
+                $is_join
+            }
+
+            fn clone_output(&self, value: &AnyValue) -> AnyValue {
+                clone_value::<Output>(value)
+            }
+
+            fn run<'a>(
+                &'a self,
+                $inputs: Vec<AnyValue>,
+                context: &'a mut Context,
+            ) -> BoxFuture<'a, Result<Option<AnyValue>, Error>>
+            where
+                Error: 'a,
+                Context: 'a,
+            {
+                // input types were checked when the flow was built
+                let input = $conv;
+                Box::pin(run_node(self.node.clone(), input, context))
+            }
+
+            fn describe(&self) -> Description {
+                self.node.describe()
+            }
+        }
+    };
+}
+
+impl_erased_node!(Single, Input, false, |inputs| *inputs
+    .into_iter()
+    .next()
+    .unwrap()
+    .downcast::<Input>()
+    .unwrap());
+impl_erased_node!(Joined, Vec<Input>, true, |inputs| inputs
+    .into_iter()
+    .map(|input| *input.downcast::<Input>().unwrap())
+    .collect::<Vec<_>>());
@@ -0,0 +1,65 @@
+use std::fmt::Display;
+
+/// Error returned when a [`DagFlow`](super::DagFlow) cannot be built.
+///
+/// See also [`Builder::build`](super::Builder::build).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DagError {
+    /// An edge references a node that doesn't exist.
+    InvalidNodeIndex {
+        /// The index of the edge.
+        edge: usize,
+        /// The invalid node index.
+        node_index: usize,
+    },
+    /// A node has an invalid number of incoming edges.
+    ///
+    /// Nodes added using [`Builder::add_node`](super::Builder::add_node) must have exactly one incoming edge,
+    /// nodes added using [`Builder::add_join_node`](super::Builder::add_join_node) must have at least one.
+    InvalidInputCount {
+        /// The index of the node.
+        node_index: usize,
+        /// The number of incoming edges.
+        count: usize,
+    },
+    /// The flow doesn't have exactly one edge ending in the flow output.
+    InvalidOutputCount {
+        /// The number of edges ending in the flow output.
+        count: usize,
+    },
+    /// The type of data passed along an edge doesn't match the type expected at its end.
+    TypeMismatch {
+        /// The index of the edge.
+        edge: usize,
+        /// The type expected at the end of the edge.
+        expected: &'static str,
+        /// The type produced at the start of the edge.
+        found: &'static str,
+    },
+    /// The edges form a cycle.
+    Cycle,
+}
+
+impl Display for DagError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidNodeIndex { edge, node_index } => {
+                write!(f, "edge {edge} references non-existent node {node_index}")
+            }
+            Self::InvalidInputCount { node_index, count } => {
+                write!(f, "node {node_index} has invalid number of inputs: {count}")
+            }
+            Self::InvalidOutputCount { count } => {
+                write!(f, "flow must have exactly one output edge, found: {count}")
+            }
+            Self::TypeMismatch {
+                edge,
+                expected,
+                found,
+            } => write!(f, "edge {edge} expected type `{expected}`, found `{found}`"),
+            Self::Cycle => f.write_str("edges contain a cycle"),
+        }
+    }
+}
+
+impl std::error::Error for DagError {}
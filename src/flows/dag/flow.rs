@@ -0,0 +1,403 @@
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use super::{
+    Builder,
+    erased::{AnyValue, ErasedNode, clone_value},
+};
+use crate::{
+    context::{Fork, Join},
+    describe::{Description, Edge, EdgeEnding, remove_generics_from_name},
+    flows::NodeResult,
+    future_utils::join_all,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+pub struct Plan {
+    pub edges: Vec<Edge>,
+    pub levels: Vec<Vec<usize>>,
+    pub inputs: Vec<Vec<EdgeEnding>>,
+    pub output: EdgeEnding,
+    pub consumers: Vec<usize>,
+    pub input_consumers: usize,
+}
+
+/// `DagFlow` executes nodes connected into an arbitrary **directed acyclic graph**.
+///
+/// Unlike other flows, the shape of `DagFlow` is not encoded in its type.
+/// Nodes are connected using [`Edge`]s (the same model used by [`Description`]),
+/// and types of data passed along the edges are checked when the flow is built.
+///
+/// Nodes are executed in topological order.
+/// Nodes that don't depend on each other are executed **concurrently**, each with its own forked context.
+/// The forked contexts are then joined back.
+/// - If a node returns [`NodeOutput::Ok`](crate::node::NodeOutput::Ok), the value is passed along its outgoing edges
+///   (it is cloned, when there are multiple).
+/// - If a node returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail),
+///   nodes depending on it are skipped.
+///   If the flow output depends on it, the flow returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail).
+/// - If a node returns an **error**, then that error is returned.
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// See also [`Builder`], [`DagError`](super::DagError).
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::DagFlow;
+/// use node_flow::describe::Edge;
+/// use node_flow::context::{Fork, Join};
+///
+/// #[derive(Clone)]
+/// struct AddOne;
+/// #[derive(Clone)]
+/// struct Double;
+/// #[derive(Clone)]
+/// struct Sum;
+///
+/// struct ExampleCtx;
+/// impl Fork for ExampleCtx // ...
+/// # { fn fork(&self) -> Self { Self } }
+/// impl Join for ExampleCtx // ...
+/// # { fn join(&mut self, others: Box<[Self]>) {} }
+///
+/// impl<Ctx: Send> Node<u32, NodeOutput<u32>, (), Ctx> for AddOne {
+///     async fn run(&mut self, input: u32, _: &mut Ctx) -> Result<NodeOutput<u32>, ()> {
+///         Ok(NodeOutput::Ok(input + 1))
+///     }
+/// }
+///
+/// impl<Ctx: Send> Node<u32, NodeOutput<u32>, (), Ctx> for Double {
+///     async fn run(&mut self, input: u32, _: &mut Ctx) -> Result<NodeOutput<u32>, ()> {
+///         Ok(NodeOutput::Ok(input * 2))
+///     }
+/// }
+///
+/// impl<Ctx: Send> Node<Vec<u32>, NodeOutput<u32>, (), Ctx> for Sum {
+///     async fn run(&mut self, input: Vec<u32>, _: &mut Ctx) -> Result<NodeOutput<u32>, ()> {
+///         Ok(NodeOutput::Ok(input.into_iter().sum()))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     //        ┌─> AddOne ─┐
+///     // input ─┤           ├─> Sum ─> output
+///     //        └─> Double ─┘
+///     let mut builder = DagFlow::<u32, u32, (), ExampleCtx>::builder();
+///     let add_one = builder.add_node(AddOne);
+///     let double = builder.add_node(Double);
+///     let sum = builder.add_join_node(Sum);
+///     builder
+///         .add_edge(Edge::flow_to_node(add_one))
+///         .add_edge(Edge::flow_to_node(double))
+///         .add_edge(Edge::node_to_node(add_one, sum))
+///         .add_edge(Edge::node_to_node(double, sum))
+///         .add_edge(Edge::node_to_flow(sum));
+///     let mut flow = builder.build().unwrap();
+///
+///     let mut ctx = ExampleCtx;
+///     let result = flow.run(5, &mut ctx).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(16)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct DagFlow<Input, Output, Error, Context> {
+    #[expect(clippy::type_complexity)]
+    pub(super) _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    pub(super) nodes: Arc<[Box<dyn ErasedNode<Error, Context>>]>,
+    pub(super) plan: Arc<Plan>,
+}
+
+impl<Input, Output, Error, Context> DagFlow<Input, Output, Error, Context>
+where
+    // Trait bounds for better and nicer errors
+    Input: Send + Clone + 'static,
+    Output: Send + 'static,
+    Error: Send,
+    Context: Fork + Join + Send,
+{
+    /// Creates a new [`Builder`] for constructing [`DagFlow`].
+    ///
+    /// See also [`DagFlow`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use node_flow::context::{Fork, Join};
+    /// # struct Ctx;
+    /// # impl Fork for Ctx { fn fork(&self) -> Self { Self } }
+    /// # impl Join for Ctx { fn join(&mut self, other: Box<[Self]>) {} }
+    /// #
+    /// use node_flow::flows::DagFlow;
+    ///
+    /// let builder = DagFlow::<u8, u16, (), Ctx>::builder();
+    /// ```
+    #[must_use]
+    pub fn builder() -> Builder<Input, Output, Error, Context> {
+        Builder::new()
+    }
+}
+
+impl<Input, Output, Error, Context> Clone for DagFlow<Input, Output, Error, Context> {
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            nodes: self.nodes.clone(),
+            plan: self.plan.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context> Debug for DagFlow<Input, Output, Error, Context> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DagFlow")
+            .field("node_count", &self.nodes.len())
+            .field("edges", &self.plan.edges)
+            .finish_non_exhaustive()
+    }
+}
+
+struct Values<'a, Error, Context> {
+    nodes: &'a [Box<dyn ErasedNode<Error, Context>>],
+    input: Option<AnyValue>,
+    input_clone: fn(&AnyValue) -> AnyValue,
+    input_consumers: usize,
+    outputs: Vec<Option<AnyValue>>,
+    consumers: Vec<usize>,
+}
+
+impl<Error, Context> Values<'_, Error, Context> {
+    fn take(&mut self, source: &EdgeEnding) -> Option<AnyValue> {
+        let (value, remaining) = match source {
+            EdgeEnding::ToFlow => (&mut self.input, &mut self.input_consumers),
+            EdgeEnding::ToNode { node_index } => (
+                &mut self.outputs[*node_index],
+                &mut self.consumers[*node_index],
+            ),
+        };
+        *remaining -= 1;
+        if *remaining == 0 {
+            return value.take();
+        }
+        let value = value.as_ref()?;
+        Some(match source {
+            EdgeEnding::ToFlow => (self.input_clone)(value),
+            EdgeEnding::ToNode { node_index } => self.nodes[*node_index].clone_output(value),
+        })
+    }
+}
+
+impl<Input, Output, Error, Context> Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for DagFlow<Input, Output, Error, Context>
+where
+    Input: Clone + Send + 'static,
+    Output: Send + 'static,
+    Error: Send,
+    Context: Fork + Join + Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        let plan = self.plan.as_ref();
+        let mut values = Values {
+            nodes: &self.nodes,
+            input: Some(Box::new(input)),
+            input_clone: clone_value::<Input>,
+            input_consumers: plan.input_consumers,
+            outputs: (0..self.nodes.len()).map(|_| None).collect(),
+            consumers: plan.consumers.clone(),
+        };
+
+        for level in &plan.levels {
+            let mut runnable = Vec::with_capacity(level.len());
+            for node_index in level {
+                let inputs = plan.inputs[*node_index]
+                    .iter()
+                    .map(|source| values.take(source))
+                    .collect::<Option<Vec<_>>>();
+                // skip nodes depending on soft-failed nodes
+                if let Some(inputs) = inputs {
+                    runnable.push((*node_index, inputs));
+                }
+            }
+
+            if let [(node_index, _)] = runnable.as_slice() {
+                let node_index = *node_index;
+                let (_, inputs) = runnable.pop().unwrap();
+                values.outputs[node_index] = self.nodes[node_index].run(inputs, context).await?;
+                continue;
+            }
+
+            let mut contexts = runnable.iter().map(|_| context.fork()).collect::<Vec<_>>();
+            let (node_indices, futures): (Vec<_>, Vec<_>) = runnable
+                .into_iter()
+                .zip(contexts.iter_mut())
+                .map(|((node_index, inputs), context)| {
+                    (node_index, self.nodes[node_index].run(inputs, context))
+                })
+                .unzip();
+            let results = join_all(futures).await;
+            context.join(contexts.into_boxed_slice());
+            for (node_index, result) in node_indices.into_iter().zip(results) {
+                values.outputs[node_index] = result?;
+            }
+        }
+
+        // output type was checked when the flow was built
+        Ok(values
+            .take(&plan.output)
+            .map_or(NodeOutputStruct::SoftFail, |output| {
+                NodeOutputStruct::Ok(*output.downcast::<Output>().unwrap())
+            }))
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_flow(
+            self,
+            self.nodes.iter().map(|node| node.describe()).collect(),
+            self.plan.edges.clone(),
+        )
+        .modify_name(remove_generics_from_name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DagFlow as Flow;
+    use crate::{
+        context::storage::local_storage::LocalStorageImpl,
+        describe::{Description, Edge},
+        flows::{
+            dag::DagError,
+            tests::{Passer, SoftFailNode},
+        },
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct Sum;
+
+    impl<C: Send> Node<Vec<u64>, NodeOutput<u64>, (), C> for Sum {
+        async fn run(&mut self, input: Vec<u64>, _context: &mut C) -> Result<NodeOutput<u64>, ()> {
+            Ok(NodeOutput::Ok(input.into_iter().sum()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow() {
+        let mut st = LocalStorageImpl::new();
+        let mut builder = Flow::<u8, u64, (), _>::builder();
+        let a = builder.add_node(Passer::<u8, u32, ()>::new());
+        let b = builder.add_node(Passer::<u32, u64, ()>::new());
+        let c = builder.add_node(Passer::<u32, u64, ()>::new());
+        let sum = builder.add_join_node(Sum);
+        builder
+            .add_edge(Edge::flow_to_node(a))
+            .add_edge(Edge::node_to_node(a, b))
+            .add_edge(Edge::node_to_node(a, c))
+            .add_edge(Edge::node_to_node(b, sum))
+            .add_edge(Edge::node_to_node(c, sum))
+            .add_edge(Edge::node_to_flow(sum));
+        let mut flow = builder.build().unwrap();
+
+        let start = std::time::Instant::now();
+        let res = flow.run(5, &mut st).await;
+        // a, (b, c) and sum => 2 * 150ms
+        assert!(start.elapsed().as_millis() < 450);
+        assert_eq!(res, Ok(NodeOutput::Ok(10)));
+    }
+
+    #[tokio::test]
+    async fn test_flow_soft_fail() {
+        let mut st = LocalStorageImpl::new();
+        let mut builder = Flow::<u8, u64, (), _>::builder();
+        let a = builder.add_node(SoftFailNode::<u8, u64, ()>::new());
+        let b = builder.add_node(Passer::<u8, u64, ()>::new());
+        let sum = builder.add_join_node(Sum);
+        builder
+            .add_edge(Edge::flow_to_node(a))
+            .add_edge(Edge::flow_to_node(b))
+            .add_edge(Edge::node_to_node(a, sum))
+            .add_edge(Edge::node_to_node(b, sum))
+            .add_edge(Edge::node_to_flow(sum));
+        let mut flow = builder.build().unwrap();
+
+        let res = flow.run(5, &mut st).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+    }
+
+    #[tokio::test]
+    async fn test_flow_passthrough() {
+        let mut st = LocalStorageImpl::new();
+        let mut builder = Flow::<u64, u64, (), LocalStorageImpl>::builder();
+        builder.add_edge(Edge::passthrough());
+        let mut flow = builder.build().unwrap();
+
+        let res = flow.run(5, &mut st).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(5)));
+    }
+
+    #[test]
+    fn test_build_errors() {
+        let mut builder = Flow::<u8, u64, (), LocalStorageImpl>::builder();
+        let a = builder.add_node(Passer::<u16, u64, ()>::new());
+        builder
+            .add_edge(Edge::flow_to_node(a))
+            .add_edge(Edge::node_to_flow(a));
+        assert!(matches!(
+            builder.build().unwrap_err(),
+            DagError::TypeMismatch { edge: 0, .. }
+        ));
+
+        let mut builder = Flow::<u8, u64, (), LocalStorageImpl>::builder();
+        let a = builder.add_node(Passer::<u8, u64, ()>::new());
+        builder.add_edge(Edge::flow_to_node(a));
+        assert_eq!(
+            builder.build().unwrap_err(),
+            DagError::InvalidOutputCount { count: 0 }
+        );
+
+        let mut builder = Flow::<u8, u64, (), LocalStorageImpl>::builder();
+        builder.add_edge(Edge::flow_to_node(1));
+        assert_eq!(
+            builder.build().unwrap_err(),
+            DagError::InvalidNodeIndex {
+                edge: 0,
+                node_index: 1
+            }
+        );
+
+        let mut builder = Flow::<u64, u64, (), LocalStorageImpl>::builder();
+        let a = builder.add_join_node(Sum);
+        let b = builder.add_node(Passer::<u64, u64, ()>::new());
+        builder
+            .add_edge(Edge::flow_to_node(a))
+            .add_edge(Edge::node_to_node(b, a))
+            .add_edge(Edge::node_to_node(a, b))
+            .add_edge(Edge::node_to_flow(b));
+        assert_eq!(builder.build().unwrap_err(), DagError::Cycle);
+    }
+
+    #[test]
+    fn test_describe() {
+        let mut builder = Flow::<u64, u64, (), LocalStorageImpl>::builder();
+        let a = builder.add_node(Passer::<u64, u64, ()>::new());
+        builder
+            .add_edge(Edge::flow_to_node(a))
+            .add_edge(Edge::node_to_flow(a));
+        let flow = builder.build().unwrap();
+
+        let Description::Flow { nodes, edges, .. } = flow.describe() else {
+            panic!("expected flow description");
+        };
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(edges.len(), 2);
+    }
+}
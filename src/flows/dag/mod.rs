@@ -0,0 +1,8 @@
+mod builder;
+pub use builder::*;
+mod error;
+pub use error::*;
+mod flow;
+pub use flow::DagFlow;
+
+mod erased;
@@ -0,0 +1,361 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    context::Clock,
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::{
+        NodeResult,
+        batch_result::{Batch, FinishOnDrop},
+    },
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// `DebounceFlow` coalesces bursts of runs into a **single** run of a node.
+///
+/// Every run records its input and waits until `window` elapses (measured using the [`Clock`] context trait).
+/// When no other run was started in the meantime, the node is executed with the **latest** input
+/// and its result is returned by all runs from the burst.
+/// Otherwise the run waits for the result of the later run.
+///
+/// The debounce state is shared between all clones of the flow,
+/// so concurrent runs of cloned flows are coalesced as well.
+/// The node is executed with the context of the last run of the burst.
+/// If the last run is canceled (while waiting for the window or before the node finishes),
+/// the other runs from the burst soft fail.
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::context::Clock;
+/// use node_flow::flows::DebounceFlow;
+/// use std::time::Duration;
+///
+/// #[derive(Clone)]
+/// struct Save;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for Save {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         Ok(NodeOutput::Ok(input))
+///     }
+/// }
+///
+/// struct ExampleCtx;
+/// impl Clock for ExampleCtx // ...
+/// # {
+/// #     fn sleep(duration: Duration) -> impl Future<Output = ()> + Send {
+/// #         tokio::time::sleep(duration)
+/// #     }
+/// # }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut first = DebounceFlow::<u8, u8, (), ExampleCtx>::new(Save, Duration::from_millis(10));
+///     let mut second = first.clone();
+///
+///     let (mut ctx1, mut ctx2) = (ExampleCtx, ExampleCtx);
+///     let (a, b) = tokio::join!(first.run(1, &mut ctx1), second.run(2, &mut ctx2));
+///     assert_eq!(a, Ok(NodeOutput::Ok(2)));
+///     assert_eq!(b, Ok(NodeOutput::Ok(2)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct DebounceFlow<
+    Input,
+    Output,
+    Error,
+    Context,
+    NodeType = (),
+    NodeOutput = (),
+    NodeError = (),
+> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    _node_oe: PhantomData<fn() -> (NodeOutput, NodeError)>,
+    node: NodeType,
+    state: Arc<Mutex<DebounceState<Input, Output, Error>>>,
+    window: Duration,
+}
+
+struct DebounceState<Input, Output, Error> {
+    /// Incremented on every run, the run that still sees its own generation after the window runs the node.
+    generation: u64,
+    /// Latest input of the current burst.
+    pending: Option<Input>,
+    /// Result of the current burst.
+    batch: Arc<Batch<Output, Error>>,
+}
+
+impl<Input, Output, Error> Debug for DebounceState<Input, Output, Error> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DebounceState")
+            .field("generation", &self.generation)
+            .field("pending", &self.pending.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Ends the burst with [`NodeOutput::SoftFail`](NodeOutputStruct::SoftFail)
+/// when the last run of the burst is canceled while waiting for the window.
+struct CancelOnDrop<'a, Input, Output, Error> {
+    state: &'a Mutex<DebounceState<Input, Output, Error>>,
+    generation: u64,
+    armed: bool,
+}
+
+impl<Input, Output, Error> Drop for CancelOnDrop<'_, Input, Output, Error> {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        if state.generation == self.generation {
+            // no other run will run the node for this burst
+            state.pending = None;
+            let batch = std::mem::replace(&mut state.batch, Arc::new(Batch::new()));
+            drop(state);
+            batch.finish(Ok(NodeOutputStruct::SoftFail));
+        }
+    }
+}
+
+impl<Input, Output, Error, Context> DebounceFlow<Input, Output, Error, Context> {
+    /// Creates a new [`DebounceFlow`] that runs `node` once per burst of runs separated by less than `window`.
+    pub fn new<NodeType, NodeOutput, NodeError>(
+        node: NodeType,
+        window: Duration,
+    ) -> DebounceFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    where
+        NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeOutput: Into<Output>,
+        NodeError: Into<Error>,
+    {
+        DebounceFlow {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node,
+            state: Arc::new(Mutex::new(DebounceState {
+                generation: 0,
+                pending: None,
+                batch: Arc::new(Batch::new()),
+            })),
+            window,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Debug
+    for DebounceFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DebounceFlow")
+            .field("node", &self.node)
+            .field("state", &self.state)
+            .field("window", &self.window)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Clone
+    for DebounceFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node: self.node.clone(),
+            state: self.state.clone(),
+            window: self.window,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for DebounceFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context> + Send,
+    NodeOutput: Into<Output>,
+    NodeError: Into<Error>,
+    Input: Send,
+    Output: Clone + Send,
+    Error: Clone + Send,
+    Context: Clock + Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        let (generation, batch) = {
+            let mut state = self.state.lock().unwrap();
+            state.generation += 1;
+            state.pending = Some(input);
+            (state.generation, state.batch.clone())
+        };
+
+        let mut cancel_guard = CancelOnDrop {
+            state: &self.state,
+            generation,
+            armed: true,
+        };
+        Context::sleep(self.window).await;
+        cancel_guard.armed = false;
+
+        let input = {
+            let mut state = self.state.lock().unwrap();
+            if state.generation == generation {
+                // last run of the burst, following runs start a new one
+                state.batch = Arc::new(Batch::new());
+                state.pending.take()
+            } else {
+                None
+            }
+        };
+        let Some(input) = input else {
+            return batch.wait().await;
+        };
+
+        let mut guard = FinishOnDrop {
+            batch: &batch,
+            finished: false,
+        };
+        let result = match self.node.run(input, context).await {
+            Ok(NodeOutputStruct::Ok(output)) => Ok(NodeOutputStruct::Ok(output.into())),
+            Ok(NodeOutputStruct::SoftFail) => Ok(NodeOutputStruct::SoftFail),
+            Err(err) => Err(err.into()),
+        };
+        batch.finish(result.clone());
+        guard.finished = true;
+        result
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_flow(
+            self,
+            vec![self.node.describe()],
+            vec![Edge::flow_to_node(0), Edge::node_to_flow(0)],
+        )
+        .modify_name(remove_generics_from_name)
+        .with_description(format!("Window: {:?}", self.window))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::{Duration, Instant},
+    };
+
+    use super::DebounceFlow;
+    use crate::{
+        context::test::TokioSpawner,
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone, Default)]
+    struct CountRuns(Arc<AtomicUsize>);
+
+    impl<C: Send> Node<u8, NodeOutput<u8>, (), C> for CountRuns {
+        async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u8>, ()> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(NodeOutput::Ok(input))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow() {
+        let node = CountRuns::default();
+        let mut first =
+            DebounceFlow::<u8, u64, (), _>::new(node.clone(), Duration::from_millis(50));
+        let mut second = first.clone();
+        let mut third = first.clone();
+
+        let (mut ctx1, mut ctx2, mut ctx3) = (TokioSpawner, TokioSpawner, TokioSpawner);
+
+        let start = Instant::now();
+        let (a, b, c) = tokio::join!(
+            first.run(1, &mut ctx1),
+            async {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                second.run(2, &mut ctx2).await
+            },
+            async {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                third.run(3, &mut ctx3).await
+            },
+        );
+        let took = start.elapsed();
+
+        assert_eq!(a, Ok(NodeOutput::Ok(3)));
+        assert_eq!(b, Ok(NodeOutput::Ok(3)));
+        assert_eq!(c, Ok(NodeOutput::Ok(3)));
+        assert_eq!(node.0.load(Ordering::SeqCst), 1);
+        assert!(took.as_millis() >= 68);
+    }
+
+    #[tokio::test]
+    async fn test_flow_separate_bursts() {
+        let node = CountRuns::default();
+        let mut flow = DebounceFlow::<u8, u64, (), _>::new(node.clone(), Duration::from_millis(10));
+
+        assert_eq!(flow.run(1, &mut TokioSpawner).await, Ok(NodeOutput::Ok(1)));
+        assert_eq!(flow.run(2, &mut TokioSpawner).await, Ok(NodeOutput::Ok(2)));
+        assert_eq!(node.0.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_flow_last_run_canceled() {
+        let node = CountRuns::default();
+        let mut first =
+            DebounceFlow::<u8, u64, (), _>::new(node.clone(), Duration::from_millis(50));
+        let mut second = first.clone();
+        let mut third = first.clone();
+
+        let (mut ctx1, mut ctx2, mut ctx3) = (TokioSpawner, TokioSpawner, TokioSpawner);
+
+        let (a, b, c) = tokio::join!(
+            first.run(1, &mut ctx1),
+            async {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                second.run(2, &mut ctx2).await
+            },
+            async {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                // canceled while waiting for the window
+                tokio::time::timeout(Duration::from_millis(10), third.run(3, &mut ctx3)).await
+            },
+        );
+
+        assert_eq!(a, Ok(NodeOutput::SoftFail));
+        assert_eq!(b, Ok(NodeOutput::SoftFail));
+        assert!(c.is_err());
+        assert_eq!(node.0.load(Ordering::SeqCst), 0);
+
+        // next burst is not affected
+        assert_eq!(first.run(4, &mut ctx1).await, Ok(NodeOutput::Ok(4)));
+        assert_eq!(node.0.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,200 @@
+use std::{fmt::Debug, marker::PhantomData, time::Duration};
+
+use crate::{
+    context::Clock,
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::NodeResult,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// `DelayFlow` waits for a fixed amount of time before running a node.
+///
+/// The flow sleeps (using the [`Clock`] context trait) and then runs the node
+/// with the untouched input and returns its result.
+/// This is useful for staggered startup or backoff experiments.
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::context::Clock;
+/// use node_flow::flows::DelayFlow;
+/// use std::time::Duration;
+///
+/// #[derive(Clone)]
+/// struct AddOne;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         Ok(NodeOutput::Ok(input + 1))
+///     }
+/// }
+///
+/// struct ExampleCtx;
+/// impl Clock for ExampleCtx // ...
+/// # {
+/// #     fn sleep(duration: Duration) -> impl Future<Output = ()> + Send {
+/// #         tokio::time::sleep(duration)
+/// #     }
+/// # }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = DelayFlow::<u8, u8, (), ExampleCtx>::new(AddOne, Duration::from_millis(10));
+///
+///     let mut ctx = ExampleCtx;
+///     let result = flow.run(5, &mut ctx).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(6)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct DelayFlow<Input, Output, Error, Context, NodeType = (), NodeOutput = (), NodeError = ()>
+{
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    _node_oe: PhantomData<fn() -> (NodeOutput, NodeError)>,
+    node: NodeType,
+    delay: Duration,
+}
+
+impl<Input, Output, Error, Context> DelayFlow<Input, Output, Error, Context> {
+    /// Creates a new [`DelayFlow`] that waits for `delay` before running `node`.
+    pub fn new<NodeType, NodeOutput, NodeError>(
+        node: NodeType,
+        delay: Duration,
+    ) -> DelayFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    where
+        NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeOutput: Into<Output>,
+        NodeError: Into<Error>,
+    {
+        DelayFlow {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node,
+            delay,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Debug
+    for DelayFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DelayFlow")
+            .field("node", &self.node)
+            .field("delay", &self.delay)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Clone
+    for DelayFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node: self.node.clone(),
+            delay: self.delay,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for DelayFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context> + Send,
+    NodeOutput: Into<Output>,
+    NodeError: Into<Error>,
+    Input: Send,
+    Context: Clock + Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        Context::sleep(self.delay).await;
+        match self.node.run(input, context).await {
+            Ok(NodeOutputStruct::Ok(output)) => Ok(NodeOutputStruct::Ok(output.into())),
+            Ok(NodeOutputStruct::SoftFail) => Ok(NodeOutputStruct::SoftFail),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_flow(
+            self,
+            vec![self.node.describe()],
+            vec![Edge::flow_to_node(0), Edge::node_to_flow(0)],
+        )
+        .modify_name(remove_generics_from_name)
+        .with_description(format!("Delay: {:?}", self.delay))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::DelayFlow;
+    use crate::{
+        context::test::TokioSpawner,
+        flows::tests::{Passer, SoftFailNode},
+        node::{Node, NodeOutput},
+    };
+
+    #[tokio::test]
+    async fn test_flow() {
+        let mut flow = DelayFlow::<u8, u16, (), TokioSpawner>::new(
+            Passer::<u8, u8, ()>::new(),
+            Duration::from_millis(50),
+        );
+
+        let start = Instant::now();
+        let res = flow.run(5, &mut TokioSpawner).await;
+        let took = start.elapsed();
+
+        assert_eq!(res, Ok(NodeOutput::Ok(5)));
+        assert!(took.as_millis() >= 50);
+    }
+
+    #[tokio::test]
+    async fn test_flow_soft_fail() {
+        let mut flow = DelayFlow::<u8, u16, (), TokioSpawner>::new(
+            SoftFailNode::<u8, u8, ()>::new(),
+            Duration::from_millis(1),
+        );
+        assert_eq!(
+            flow.run(5, &mut TokioSpawner).await,
+            Ok(NodeOutput::SoftFail)
+        );
+    }
+
+    #[test]
+    fn test_describe() {
+        let flow = DelayFlow::<u8, u16, (), TokioSpawner>::new(
+            Passer::<u8, u8, ()>::new(),
+            Duration::from_millis(50),
+        );
+        let desc = flow.describe();
+        assert_eq!(
+            desc.get_base_ref().description.as_deref(),
+            Some("Delay: 50ms")
+        );
+        assert!(desc.get_by_path(&[0]).is_some());
+    }
+}
@@ -1,7 +1,7 @@
 use std::{fmt::Debug, vec};
 
 use crate::{
-    context::{Fork, SpawnAsync},
+    context::{Fork, SpawnAsync, Task},
     describe::{Description, Edge, remove_generics_from_name},
     flows::NodeResult,
     node::{Node, NodeOutput as NodeOutputStruct},
@@ -11,6 +11,8 @@ use crate::{
 ///
 /// The node is executed in a **detached task** using the [`SpawnAsync`]
 /// context trait and any result or error from the detached node is ignored.
+/// The task is released using [`Task::detach`],
+/// so it keeps running even after this flow finishes.
 ///
 /// This flow is useful for side-effect operations such as logging, analytics, or background
 /// triggers that should not block or influence the main execution path.
@@ -36,7 +38,7 @@ use crate::{
 /// # { fn fork(&self) -> Self { Self } }
 /// impl SpawnAsync for ExampleCtx // ...
 /// # {
-/// #    fn spawn<F>(fut: F) -> impl Task<F::Output>
+/// #    fn spawn<F>(fut: F) -> impl Task<F::Output> + Send
 /// #     where
 /// #         F: Future + Send + 'static,
 /// #         F::Output: Send + 'static,
@@ -112,7 +114,7 @@ impl<Input, Error, Context> Detached<Input, Error, Context> {
     /// # struct Ctx;
     /// # impl Fork for Ctx { fn fork(&self) -> Self { Self } }
     /// # impl SpawnAsync for Ctx {
-    /// #    fn spawn<F>(fut: F) -> impl Task<F::Output>
+    /// #    fn spawn<F>(fut: F) -> impl Task<F::Output> + Send
     /// #     where
     /// #         F: Future + Send + 'static,
     /// #         F::Output: Send + 'static,
@@ -156,6 +158,41 @@ impl<Input, Error, Context> Detached<Input, Error, Context> {
     }
 }
 
+impl<Input, Error, Context, NodeType, NodeOutput, NodeError>
+    Detached<Input, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Node<Input, NodeOutput, NodeError, Context> + Clone + Send + 'static,
+    NodeOutput: Send + 'static,
+    NodeError: Send + 'static,
+    Context: SpawnAsync + Fork + Send + 'static,
+    Input: Clone + Send + 'static,
+{
+    /// Spawns the wrapped node like [`Node::run`] does,
+    /// but instead of detaching the task, it returns its handle alongside the passed through input.
+    ///
+    /// The handle can be stored and awaited later (for example on shutdown) to get the result of the node.
+    /// Dropping the handle has the same effect as [`Task::detach`], or cancels the task,
+    /// depending on the [`SpawnAsync`] implementation. Use [`Task::detach`] to release it explicitly.
+    pub fn run_with_handle(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> (
+        Input,
+        impl Task<Result<NodeOutput, NodeError>>
+        + Send
+        + use<Input, Error, Context, NodeType, NodeOutput, NodeError>,
+    ) {
+        let task = Context::spawn({
+            let mut node = self.node.as_ref().clone();
+            let input = input.clone();
+            let mut context = context.fork();
+            async move { node.run(input, &mut context).await }
+        });
+        (input, task)
+    }
+}
+
 impl<Input, Error, Context, NodeType, NodeOutput, NodeError> Debug
     for Detached<Input, Error, Context, NodeType, NodeOutput, NodeError>
 where
@@ -193,14 +230,15 @@ where
         input: Input,
         context: &mut Context,
     ) -> impl Future<Output = NodeResult<Input, Error>> + Send {
-        let _task = Context::spawn({
+        Context::spawn({
             let mut node = self.node.as_ref().clone();
             let input = input.clone();
             let mut context = context.fork();
             async move {
                 let _ = node.run(input, &mut context).await;
             }
-        });
+        })
+        .detach();
         async { Ok(NodeOutputStruct::Ok(input)) }
     }
 
@@ -211,6 +249,7 @@ where
             vec![Edge::passthrough(), Edge::flow_to_node(0)],
         )
         .modify_name(remove_generics_from_name)
+        .as_detached()
     }
 }
 
@@ -273,4 +312,48 @@ mod test {
         assert!(flow_took.as_millis() < 1);
         assert!(node_took.as_millis() > 15);
     }
+
+    #[cfg(feature = "smol")]
+    #[test]
+    fn test_flow_cancel_on_drop_spawner() {
+        #[derive(Clone)]
+        struct SendNode(std::sync::mpsc::Sender<u8>);
+
+        impl<C: Send> Node<u8, (), (), C> for SendNode {
+            async fn run(&mut self, input: u8, _context: &mut C) -> Result<(), ()> {
+                smol::Timer::after(Duration::from_millis(20)).await;
+                self.0.send(input).unwrap();
+                Ok(())
+            }
+        }
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let mut flow = Detached::<_, (), _>::new(SendNode(sender));
+        let res = smol::block_on(flow.run(3u8, &mut crate::context::SmolSpawner));
+
+        assert_eq!(res, Ok(NodeOutput::Ok(3)));
+        assert_eq!(receiver.recv_timeout(Duration::from_millis(500)), Ok(3));
+    }
+
+    #[tokio::test]
+    async fn test_run_with_handle() {
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(5);
+        let mut flow = Detached::<_, (), _>::new(TestNode(sender));
+
+        let (input, handle) = flow.run_with_handle(3u8, &mut TokioSpawner);
+        assert_eq!(input, 3);
+        assert!(receiver.try_recv().is_err());
+
+        assert_eq!(handle.await, Err(()));
+        assert_eq!(receiver.try_recv(), Ok(()));
+    }
+
+    #[test]
+    fn test_describe() {
+        let (sender, _receiver) = tokio::sync::mpsc::channel(5);
+        let flow = Detached::<u8, (), TokioSpawner>::new(TestNode(sender));
+        let desc = flow.describe();
+        assert!(desc.get_base_ref().detached);
+        assert!(!desc.get_by_path(&[0]).unwrap().get_base_ref().detached);
+    }
 }
@@ -0,0 +1,279 @@
+use std::{fmt::Debug, vec};
+
+use crate::{
+    context::{Fork, SpawnLocal, Task},
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::NodeResult,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// `DetachedLocal` executes a node **asynchronously and independently** of the main flow
+/// on the current thread.
+///
+/// It behaves like [`Detached`](crate::flows::Detached), but the node is executed
+/// in a detached task using the [`SpawnLocal`] context trait instead of [`SpawnAsync`](crate::context::SpawnAsync).
+/// This means that neither the node nor the context have to be `Send`,
+/// so nodes holding `!Send` state can still be detached on a single-threaded executor
+/// (for example on a Tokio `LocalSet`).
+/// Any result or error from the detached node is ignored.
+/// The task is released using [`Task::detach`],
+/// so it keeps running even after this flow finishes.
+///
+/// # Type Parameters
+/// - `Input`: The type of data **accepted and produced** by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::context::{SpawnLocal, Fork};
+/// # use node_flow::context::Task;
+/// use node_flow::flows::DetachedLocal;
+/// use std::{cell::Cell, future::Future, rc::Rc};
+///
+/// #[derive(Clone)]
+/// struct CountNode(Rc<Cell<u8>>);
+///
+/// struct ExampleCtx;
+/// impl Fork for ExampleCtx // ...
+/// # { fn fork(&self) -> Self { Self } }
+/// impl SpawnLocal for ExampleCtx // ...
+/// # {
+/// #    fn spawn_local<F>(fut: F) -> impl Task<F::Output>
+/// #     where
+/// #         F: Future + 'static,
+/// #         F::Output: 'static,
+/// #     {
+/// #         DummyTask(std::marker::PhantomData)
+/// #     }
+/// # }
+/// # struct DummyTask<T>(std::marker::PhantomData<T>);
+/// # impl<T> Future for DummyTask<T> {
+/// #     type Output = T;
+/// #     fn poll(
+/// #         self: std::pin::Pin<&mut Self>,
+/// #         _: &mut std::task::Context<'_>
+/// #     ) -> std::task::Poll<Self::Output> {
+/// #         std::task::Poll::Pending
+/// #     }
+/// # }
+/// # impl<T> Task<T> for DummyTask<T> {
+/// #     fn is_finished(&self) -> bool { false }
+/// #     fn cancel(self) {}
+/// # }
+///
+/// impl<Ctx> Node<u8, NodeOutput<()>, (), Ctx> for CountNode {
+///     fn run(
+///         &mut self,
+///         input: u8,
+///         _: &mut Ctx,
+///     ) -> impl Future<Output = Result<NodeOutput<()>, ()>> + Send {
+///         self.0.set(self.0.get() + input);
+///         async { Ok(NodeOutput::Ok(())) }
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let counter = Rc::new(Cell::new(0));
+///     let mut detached = DetachedLocal::<u8, (), _>::new(CountNode(counter));
+///
+///     let mut ctx = ExampleCtx;
+///     let result = detached.run(7, &mut ctx).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(7)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct DetachedLocal<Input, Error, Context, NodeType = (), NodeOutput = (), NodeError = ()> {
+    #[expect(clippy::type_complexity)]
+    _iec: std::marker::PhantomData<fn() -> (Input, Error, Context)>,
+    _node_oe: std::marker::PhantomData<fn() -> (NodeOutput, NodeError)>,
+    node: std::rc::Rc<NodeType>,
+}
+
+impl<Input, Error, Context> DetachedLocal<Input, Error, Context> {
+    /// Creates a new [`DetachedLocal`] flow by wrapping the given node.
+    ///
+    /// See also [`DetachedLocal`].
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::flows::DetachedLocal;
+    /// use node_flow::node::{Node, NodeOutput};
+    /// # use node_flow::context::{SpawnLocal, Fork};
+    /// # use node_flow::context::Task;
+    /// # use std::future::Future;
+    ///
+    /// #[derive(Clone)]
+    /// struct BackgroundTask;
+    /// impl<Ctx: Send> Node<(), NodeOutput<()>, (), Ctx> for BackgroundTask // ...
+    /// # {
+    /// #     async fn run(&mut self, _: (), _: &mut Ctx) -> Result<NodeOutput<()>, ()> {
+    /// #         todo!()
+    /// #     }
+    /// # }
+    /// # struct Ctx;
+    /// # impl Fork for Ctx { fn fork(&self) -> Self { Self } }
+    /// # impl SpawnLocal for Ctx {
+    /// #    fn spawn_local<F>(fut: F) -> impl Task<F::Output>
+    /// #     where
+    /// #         F: Future + 'static,
+    /// #         F::Output: 'static,
+    /// #     {
+    /// #         DummyTask(std::marker::PhantomData)
+    /// #     }
+    /// # }
+    /// # struct DummyTask<T>(std::marker::PhantomData<T>);
+    /// # impl<T> Future for DummyTask<T> // ...
+    /// # {
+    /// #     type Output = T;
+    /// #     fn poll(
+    /// #         self: std::pin::Pin<&mut Self>,
+    /// #         _: &mut std::task::Context<'_>
+    /// #     ) -> std::task::Poll<Self::Output> {
+    /// #         std::task::Poll::Pending
+    /// #     }
+    /// # }
+    /// # impl<T> Task<T> for DummyTask<T> {
+    /// #     fn is_finished(&self) -> bool { false }
+    /// #     fn cancel(self) {}
+    /// # }
+    ///
+    /// let detached = DetachedLocal::<(), (), Ctx>::new(BackgroundTask);
+    /// ```
+    #[expect(clippy::type_repetition_in_bounds)]
+    pub fn new<NodeType, NodeOutput, NodeError>(
+        node: NodeType,
+    ) -> DetachedLocal<Input, Error, Context, NodeType, NodeOutput, NodeError>
+    where
+        NodeType: Node<Input, NodeOutput, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeType: Clone,
+        Input: Clone + Send,
+    {
+        DetachedLocal {
+            _iec: std::marker::PhantomData,
+            _node_oe: std::marker::PhantomData,
+            node: std::rc::Rc::new(node),
+        }
+    }
+}
+
+impl<Input, Error, Context, NodeType, NodeOutput, NodeError> Debug
+    for DetachedLocal<Input, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DetachedLocal")
+            .field("node", &self.node)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Error, Context, NodeType, NodeOutput, NodeError> Clone
+    for DetachedLocal<Input, Error, Context, NodeType, NodeOutput, NodeError>
+{
+    fn clone(&self) -> Self {
+        Self {
+            _iec: std::marker::PhantomData,
+            _node_oe: std::marker::PhantomData,
+            node: self.node.clone(),
+        }
+    }
+}
+
+impl<Input, Error, Context, NodeType, NodeOutput, NodeError>
+    Node<Input, NodeOutputStruct<Input>, Error, Context>
+    for DetachedLocal<Input, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Node<Input, NodeOutput, NodeError, Context> + Clone + 'static,
+    Context: SpawnLocal + Fork + 'static,
+    Input: Clone + Send + 'static,
+{
+    fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = NodeResult<Input, Error>> + Send {
+        Context::spawn_local({
+            let mut node = self.node.as_ref().clone();
+            let input = input.clone();
+            let mut context = context.fork();
+            async move {
+                let _ = node.run(input, &mut context).await;
+            }
+        })
+        .detach();
+        async { Ok(NodeOutputStruct::Ok(input)) }
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_flow(
+            self,
+            vec![self.node.describe()],
+            vec![Edge::passthrough(), Edge::flow_to_node(0)],
+        )
+        .modify_name(remove_generics_from_name)
+        .as_detached()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, rc::Rc, time::Duration};
+
+    use super::DetachedLocal;
+    use crate::{
+        context::test::TokioSpawner,
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    pub struct TestNode(Rc<Cell<u8>>);
+
+    impl<C> Node<u8, (), (), C> for TestNode {
+        fn run(
+            &mut self,
+            input: u8,
+            _context: &mut C,
+        ) -> impl Future<Output = Result<(), ()>> + Send {
+            self.0.set(self.0.get() + input);
+            async { Err(()) }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow() {
+        let counter = Rc::new(Cell::new(0));
+        let mut ctx = TokioSpawner;
+        let mut flow = DetachedLocal::<_, (), _>::new(TestNode(counter.clone()));
+
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let res = flow.run(3u8, &mut ctx).await;
+                assert_eq!(res, Ok(NodeOutput::Ok(3)));
+                // detached task has not been polled yet
+                assert_eq!(counter.get(), 0);
+
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                assert_eq!(counter.get(), 3);
+            })
+            .await;
+    }
+
+    #[test]
+    fn test_describe() {
+        let flow = DetachedLocal::<u8, (), TokioSpawner>::new(TestNode(Rc::default()));
+        let desc = flow.describe();
+        assert!(desc.get_base_ref().detached);
+        assert!(!desc.get_by_path(&[0]).unwrap().get_base_ref().detached);
+    }
+}
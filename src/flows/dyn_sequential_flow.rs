@@ -0,0 +1,298 @@
+use std::fmt::Debug;
+
+use crate::{
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::NodeResult,
+    node::{BoxedNode, IdentityLike, Node, NodeOutput as NodeOutputStruct},
+};
+
+type DynNode<Input, Error, Context> =
+    Box<dyn BoxedNode<Input, NodeOutputStruct<Input>, Error, Context> + Send + Sync>;
+
+struct Entry<Input, Error, Context> {
+    node: DynNode<Input, Error, Context>,
+    identity_like: bool,
+}
+
+/// `DynSequentialFlow` executes nodes added at runtime **sequentially**, like a pipeline.
+///
+/// It behaves like [`SequentialFlow`](crate::flows::SequentialFlow),
+/// but the nodes are stored as boxed [`BoxedNode`] trait objects,
+/// so the flow can be assembled at runtime (for example from a configuration).
+/// This trades compile-time knowledge of node types for dynamism,
+/// which means that all nodes must accept and produce the same `Input` type.
+///
+/// - If a node returns [`NodeOutput::Ok`](crate::node::NodeOutput::Ok), that value is then fed into the next node.
+/// - If a node returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail), the flow soft-fails.
+/// - If a node returns an **error**, then that error is returned.
+///
+/// The output of the last node is converted into `Output`.
+/// A flow without nodes returns its input.
+///
+/// Nodes added with [`DynSequentialFlow::push_identity_like`] can be removed
+/// by [`DynSequentialFlow::optimize`].
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow and by all of its nodes.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow and by all of its nodes.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::DynSequentialFlow;
+///
+/// #[derive(Clone)]
+/// struct Add(u32);
+///
+/// impl<Ctx: Send> Node<u32, NodeOutput<u32>, (), Ctx> for Add {
+///     async fn run(&mut self, input: u32, _: &mut Ctx) -> Result<NodeOutput<u32>, ()> {
+///         Ok(NodeOutput::Ok(input + self.0))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let config = [1, 2, 3];
+///
+///     let mut flow = DynSequentialFlow::<u32, u64, (), ()>::new();
+///     for add in config {
+///         flow.push(Add(add));
+///     }
+///
+///     let result = flow.run(10, &mut ()).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(16)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct DynSequentialFlow<Input, Output, Error, Context> {
+    #[expect(clippy::type_complexity)]
+    _ioec: std::marker::PhantomData<fn() -> (Input, Output, Error, Context)>,
+    nodes: Vec<Entry<Input, Error, Context>>,
+}
+
+impl<Input, Output, Error, Context> DynSequentialFlow<Input, Output, Error, Context> {
+    /// Creates a new [`DynSequentialFlow`] without any nodes.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            _ioec: std::marker::PhantomData,
+            nodes: Vec::new(),
+        }
+    }
+
+    /// Adds a new node at the end of the flow.
+    pub fn push<NodeType>(&mut self, node: NodeType)
+    where
+        NodeType: Node<Input, NodeOutputStruct<Input>, Error, Context> + Send + Sync + 'static,
+    {
+        self.nodes.push(Entry {
+            node: Box::new(node),
+            identity_like: false,
+        });
+    }
+
+    /// Adds a new [`IdentityLike`] node at the end of the flow.
+    ///
+    /// The node is remembered as a passthrough node, so it can be removed by [`DynSequentialFlow::optimize`].
+    pub fn push_identity_like<NodeType>(&mut self, node: NodeType)
+    where
+        NodeType: Node<Input, NodeOutputStruct<Input>, Error, Context>
+            + IdentityLike
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.nodes.push(Entry {
+            node: Box::new(node),
+            identity_like: true,
+        });
+    }
+
+    /// Removes all nodes added with [`DynSequentialFlow::push_identity_like`].
+    ///
+    /// [`IdentityLike`] nodes return their input unchanged and have no side effects,
+    /// so removing them doesn't change the result of the flow, only the overhead of running it.
+    /// This is useful for machine-generated flows, which often contain redundant passthrough nodes.
+    ///
+    /// This optimization is only available for dynamic flows.
+    /// Flows built from the typed builders (like [`SequentialFlow`](crate::flows::SequentialFlow))
+    /// store their nodes as part of their type, so nodes cannot be removed from them.
+    ///
+    /// Returns the number of removed nodes.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::{Identity, Node, NodeOutput};
+    /// use node_flow::flows::DynSequentialFlow;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread()
+    /// #     .enable_all()
+    /// #     .build()
+    /// #     .unwrap()
+    /// #     .block_on(async {
+    /// let mut flow = DynSequentialFlow::<u32, u32, (), ()>::new();
+    /// flow.push_identity_like(Identity);
+    /// flow.push_identity_like(Identity);
+    ///
+    /// assert_eq!(flow.optimize(), 2);
+    /// assert!(flow.is_empty());
+    /// assert_eq!(flow.run(5, &mut ()).await, Ok(NodeOutput::Ok(5)));
+    /// # });
+    /// ```
+    pub fn optimize(&mut self) -> usize {
+        let len = self.nodes.len();
+        self.nodes.retain(|entry| !entry.identity_like);
+        len - self.nodes.len()
+    }
+
+    /// Returns the number of nodes in the flow.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the flow has no nodes.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+impl<Input, Output, Error, Context> Default for DynSequentialFlow<Input, Output, Error, Context> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Input, Output, Error, Context> Debug for DynSequentialFlow<Input, Output, Error, Context> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynSequentialFlow")
+            .field("len", &self.nodes.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context> Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for DynSequentialFlow<Input, Output, Error, Context>
+where
+    Input: Into<Output> + Send,
+    Error: Send,
+    Context: Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        let mut value = input;
+        for Entry { node, .. } in &mut self.nodes {
+            match node.run_boxed(value, context).await? {
+                NodeOutputStruct::Ok(output) => value = output,
+                NodeOutputStruct::SoftFail => return Ok(NodeOutputStruct::SoftFail),
+            }
+        }
+        Ok(NodeOutputStruct::Ok(value.into()))
+    }
+
+    fn describe(&self) -> Description {
+        let node_descriptions = self
+            .nodes
+            .iter()
+            .map(|entry| entry.node.describe())
+            .collect::<Vec<_>>();
+
+        let edges = if node_descriptions.is_empty() {
+            vec![Edge::passthrough()]
+        } else {
+            let node_count = node_descriptions.len();
+            let mut edges = Vec::with_capacity(node_count + 1);
+            edges.push(Edge::flow_to_node(0));
+            for i in 0..node_count - 1 {
+                edges.push(Edge::node_to_node(i, i + 1));
+            }
+            edges.push(Edge::node_to_flow(node_count - 1));
+            edges
+        };
+
+        Description::new_flow(self, node_descriptions, edges).modify_name(remove_generics_from_name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DynSequentialFlow as Flow;
+    use crate::{
+        describe::Description,
+        flows::tests::{Passer, SoftFailNode},
+        node::{Identity, Node, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct Add(u32);
+
+    impl<C: Send> Node<u32, NodeOutput<u32>, (), C> for Add {
+        async fn run(&mut self, input: u32, _context: &mut C) -> Result<NodeOutput<u32>, ()> {
+            Ok(NodeOutput::Ok(input + self.0))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow() {
+        let mut flow = Flow::<u32, u64, (), ()>::new();
+        for i in 1..=4 {
+            flow.push(Add(i));
+        }
+        flow.push(Passer::<u32, u32, ()>::new());
+
+        assert_eq!(flow.len(), 5);
+        assert_eq!(flow.run(0, &mut ()).await, Ok(NodeOutput::Ok(10)));
+
+        let Description::Flow { nodes, edges, .. } = flow.describe() else {
+            panic!("expected flow description");
+        };
+        assert_eq!(nodes.len(), 5);
+        assert_eq!(edges.len(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_flow_soft_fail() {
+        let mut flow = Flow::<u32, u64, (), ()>::new();
+        flow.push(Add(1));
+        flow.push(SoftFailNode::<u32, u32, ()>::new());
+        flow.push(Add(1));
+
+        assert_eq!(flow.run(0, &mut ()).await, Ok(NodeOutput::SoftFail));
+    }
+
+    #[tokio::test]
+    async fn test_flow_empty() {
+        let mut flow = Flow::<u32, u64, (), ()>::default();
+        assert!(flow.is_empty());
+        assert_eq!(flow.run(7, &mut ()).await, Ok(NodeOutput::Ok(7)));
+    }
+
+    #[tokio::test]
+    async fn test_flow_optimize() {
+        let mut flow = Flow::<u32, u64, (), ()>::new();
+        flow.push_identity_like(Identity);
+        flow.push(Add(1));
+        flow.push_identity_like(Identity);
+        flow.push_identity_like(Identity);
+        flow.push(Add(2));
+        flow.push_identity_like(Identity);
+
+        assert_eq!(flow.run(0, &mut ()).await, Ok(NodeOutput::Ok(3)));
+        assert_eq!(flow.optimize(), 4);
+        assert_eq!(flow.len(), 2);
+        assert_eq!(flow.run(0, &mut ()).await, Ok(NodeOutput::Ok(3)));
+        assert_eq!(flow.optimize(), 0);
+
+        let Description::Flow { nodes, .. } = flow.describe() else {
+            panic!("expected flow description");
+        };
+        assert_eq!(nodes.len(), 2);
+    }
+}
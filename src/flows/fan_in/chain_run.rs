@@ -0,0 +1,107 @@
+use crate::{
+    context::{Fork, Join, SpawnAsync, Task},
+    describe::Description,
+    flows::{NodeIOE, NodeResult},
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+pub trait ChainRunFanIn<Input, Output, Error, Context, T> {
+    const COUNT: usize;
+
+    fn run(
+        &self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = NodeResult<Output, Error>> + Send;
+
+    fn describe(&self, description_acc: &mut Vec<Description>);
+}
+
+macro_rules! impl_chain_run_fan_in {
+    ($count:literal; $($idx:tt $node:ident $input:ident $output:ident $node_in:ident $node_out:ident $node_err:ident $var:ident),+) => {
+        impl<Error, Context, $($node, $input, $output, $node_in, $node_out, $node_err),+>
+            ChainRunFanIn<
+                ($($input,)+),
+                ($($output,)+),
+                Error,
+                Context,
+                ($(NodeIOE<$node_in, $node_out, $node_err>,)+),
+            > for ($($node,)+)
+        where
+            $(
+                $node: Node<$node_in, NodeOutputStruct<$node_out>, $node_err, Context>
+                    + Clone
+                    + Send
+                    + Sync
+                    + 'static,
+                $input: Into<$node_in> + Send,
+                $output: Send,
+                $node_in: Send + 'static,
+                $node_out: Into<$output> + Send + 'static,
+                $node_err: Into<Error> + Send + 'static,
+            )+
+            Error: Send,
+            Context: SpawnAsync + Fork + Join + Send + 'static,
+        {
+            const COUNT: usize = $count;
+
+            async fn run(
+                &self,
+                input: ($($input,)+),
+                context: &mut Context,
+            ) -> NodeResult<($($output,)+), Error> {
+                let tasks = ($(
+                    Context::spawn({
+                        let mut node = self.$idx.clone();
+                        let input: $node_in = input.$idx.into();
+                        let mut context = context.fork();
+                        async move {
+                            let res = node.run(input, &mut context).await;
+                            (res, context)
+                        }
+                    }),
+                )+);
+
+                let mut context_acc = Vec::with_capacity($count);
+                let mut failed: Option<NodeResult<($($output,)+), Error>> = None;
+                $(
+                    let $var = if failed.is_none() {
+                        match tasks.$idx.await {
+                            (Ok(NodeOutputStruct::Ok(output)), node_context) => {
+                                context_acc.push(node_context);
+                                Some(output)
+                            }
+                            (Ok(NodeOutputStruct::SoftFail), _) => {
+                                failed = Some(Ok(NodeOutputStruct::SoftFail));
+                                None
+                            }
+                            (Err(err), _) => {
+                                failed = Some(Err(err.into()));
+                                None
+                            }
+                        }
+                    } else {
+                        // some branch already failed, cancel the rest
+                        tasks.$idx.cancel();
+                        None
+                    };
+                )+
+                if let Some(res) = failed {
+                    return res;
+                }
+
+                context.join(context_acc.into_boxed_slice());
+                Ok(NodeOutputStruct::Ok(($($var.unwrap().into(),)+)))
+            }
+
+            fn describe(&self, description_acc: &mut Vec<Description>) {
+                $(description_acc.push(self.$idx.describe());)+
+            }
+        }
+    };
+}
+
+impl_chain_run_fan_in!(1; 0 A AI AO AN ANO ANE a);
+impl_chain_run_fan_in!(2; 0 A AI AO AN ANO ANE a, 1 B BI BO BN BNO BNE b);
+impl_chain_run_fan_in!(3; 0 A AI AO AN ANO ANE a, 1 B BI BO BN BNO BNE b, 2 C CI CO CN CNO CNE c);
+impl_chain_run_fan_in!(4; 0 A AI AO AN ANO ANE a, 1 B BI BO BN BNO BNE b, 2 C CI CO CN CNO CNE c, 3 D DI DO DN DNO DNE d);
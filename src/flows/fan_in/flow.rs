@@ -0,0 +1,291 @@
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use super::chain_run::ChainRunFanIn;
+use crate::{
+    context::{Fork, Join, SpawnAsync},
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::NodeResult,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// `FanInFlow` executes nodes (branches) **in parallel**, each with its own input.
+///
+/// The input of this flow is a tuple `(I1, I2, ...)` and each element is routed to the node (branch)
+/// at the same position. Each node (branch) is executed in a separate task
+/// using the [`SpawnAsync`] context trait with a forked context.
+/// Outputs of all nodes (branches) are collected into an output tuple `(O1, O2, ...)`.
+///
+/// Unlike [`ParallelFlow`](crate::flows::ParallelFlow), where all nodes share the same input,
+/// every node (branch) here receives a different (and possibly differently typed) input.
+///
+/// The flow completes when **all** nodes succeed or **any** node fails.
+/// - If all nodes return [`NodeOutput::Ok`](crate::node::NodeOutput::Ok),
+///   their outputs are returned and their contexts are joined back.
+/// - If a node returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail),
+///   the flow soft fails as well.
+/// - If a node returns an **error**, then that error is returned.
+///
+/// Nodes are awaited in the order in which they were given.
+/// When a node fails, nodes that were not awaited yet are canceled
+/// and no context is joined back.
+///
+/// It is implemented for up to 4 nodes (branches).
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow (a tuple).
+/// - `Output`: The type of data produced by this flow (a tuple).
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::FanInFlow;
+/// use node_flow::context::{Fork, Join, SpawnAsync, Task};
+///
+/// #[derive(Clone)]
+/// struct Double;
+/// #[derive(Clone)]
+/// struct Len;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u16>, (), Ctx> for Double {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u16>, ()> {
+///         Ok(NodeOutput::Ok(u16::from(input) * 2))
+///     }
+/// }
+///
+/// impl<Ctx: Send> Node<String, NodeOutput<usize>, (), Ctx> for Len {
+///     async fn run(&mut self, input: String, _: &mut Ctx) -> Result<NodeOutput<usize>, ()> {
+///         Ok(NodeOutput::Ok(input.len()))
+///     }
+/// }
+///
+/// struct ExampleCtx;
+/// impl Fork for ExampleCtx // ...
+/// # { fn fork(&self) -> Self { Self } }
+/// impl Join for ExampleCtx // ...
+/// # { fn join(&mut self, others: Box<[Self]>) {} }
+/// impl SpawnAsync for ExampleCtx // ...
+/// # {
+/// #     fn spawn<F>(fut: F) -> impl Task<F::Output> + Send
+/// #     where
+/// #         F: Future + Send + 'static,
+/// #         F::Output: Send + 'static,
+/// #     {
+/// #         ExampleTask(tokio::spawn(fut))
+/// #     }
+/// # }
+/// # struct ExampleTask<T>(tokio::task::JoinHandle<T>);
+/// # impl<T> Future for ExampleTask<T> {
+/// #     type Output = T;
+/// #     fn poll(
+/// #         self: std::pin::Pin<&mut Self>,
+/// #         cx: &mut std::task::Context<'_>
+/// #     ) -> std::task::Poll<Self::Output> {
+/// #         std::pin::Pin::new(&mut self.get_mut().0).poll(cx).map(Result::unwrap)
+/// #     }
+/// # }
+/// # impl<T> Task<T> for ExampleTask<T> {
+/// #     fn is_finished(&self) -> bool { self.0.is_finished() }
+/// #     fn cancel(self) { self.0.abort() }
+/// # }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = FanInFlow::<(u8, String), (u16, usize), (), ExampleCtx>::new((Double, Len));
+///
+///     let mut ctx = ExampleCtx;
+///     let result = flow.run((4, "abc".to_owned()), &mut ctx).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok((8, 3))));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct FanInFlow<Input, Output, Error, Context, NodeTypes = (), NodeIOETypes = ()> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    _nodes_io: PhantomData<fn() -> NodeIOETypes>,
+    nodes: Arc<NodeTypes>,
+}
+
+impl<Input, Output, Error, Context> FanInFlow<Input, Output, Error, Context>
+where
+    // Trait bounds for better and nicer errors
+    Error: Send,
+    Context: SpawnAsync + Fork + Join + Send,
+{
+    /// Creates a new [`FanInFlow`] from a tuple of nodes (branches).
+    ///
+    /// The node at each position receives the element of the input tuple at the same position.
+    ///
+    /// See also [`FanInFlow`].
+    pub fn new<NodeTypes, NodeIOETypes>(
+        nodes: NodeTypes,
+    ) -> FanInFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+    where
+        NodeTypes: ChainRunFanIn<Input, Output, Error, Context, NodeIOETypes>,
+    {
+        FanInFlow {
+            _ioec: PhantomData,
+            _nodes_io: PhantomData,
+            nodes: Arc::new(nodes),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes> Clone
+    for FanInFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _nodes_io: PhantomData,
+            nodes: self.nodes.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes> Debug
+    for FanInFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+where
+    NodeTypes: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FanInFlow")
+            .field("nodes", &self.nodes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for FanInFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+where
+    NodeTypes: ChainRunFanIn<Input, Output, Error, Context, NodeIOETypes> + Send + Sync,
+{
+    fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = NodeResult<Output, Error>> + Send {
+        self.nodes.run(input, context)
+    }
+
+    fn describe(&self) -> Description {
+        let node_count = NodeTypes::COUNT;
+        let mut node_descriptions = Vec::with_capacity(node_count);
+        self.nodes.describe(&mut node_descriptions);
+
+        let mut edges = Vec::with_capacity(node_count * 2);
+        for i in 0..node_count {
+            edges.push(Edge::flow_to_node(i));
+            edges.push(Edge::node_to_flow(i));
+        }
+
+        Description::new_flow(self, node_descriptions, edges).modify_name(remove_generics_from_name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::FanInFlow;
+    use crate::{
+        context::{Join, test::TokioSpawner},
+        node::{Node, NodeOutput},
+    };
+
+    impl Join for TokioSpawner {
+        fn join(&mut self, _others: Box<[Self]>) {}
+    }
+
+    #[derive(Clone)]
+    struct SleepDouble(u64);
+
+    impl<C: Send> Node<u8, NodeOutput<u16>, (), C> for SleepDouble {
+        async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u16>, ()> {
+            tokio::time::sleep(Duration::from_millis(self.0)).await;
+            Ok(NodeOutput::Ok(u16::from(input) * 2))
+        }
+    }
+
+    #[derive(Clone)]
+    struct SleepLen(u64);
+
+    impl<C: Send> Node<String, NodeOutput<usize>, (), C> for SleepLen {
+        async fn run(&mut self, input: String, _context: &mut C) -> Result<NodeOutput<usize>, ()> {
+            tokio::time::sleep(Duration::from_millis(self.0)).await;
+            Ok(NodeOutput::Ok(input.len()))
+        }
+    }
+
+    /// Soft fails or fails (when `hard` is set).
+    #[derive(Clone)]
+    struct FailLen {
+        hard: bool,
+    }
+
+    impl<C: Send> Node<String, NodeOutput<usize>, (), C> for FailLen {
+        async fn run(&mut self, _input: String, _context: &mut C) -> Result<NodeOutput<usize>, ()> {
+            if self.hard {
+                Err(())
+            } else {
+                Ok(NodeOutput::SoftFail)
+            }
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_flow() {
+        let mut flow =
+            FanInFlow::<(u8, String), (u32, usize), (), _>::new((SleepDouble(100), SleepLen(100)));
+
+        let start = Instant::now();
+        let res = flow.run((4, "abc".to_owned()), &mut TokioSpawner).await;
+        let took = start.elapsed();
+
+        assert_eq!(res, Ok(NodeOutput::Ok((8, 3))));
+        assert!(took.as_millis() < 190);
+    }
+
+    #[tokio::test]
+    async fn test_flow_soft_fail() {
+        let mut flow = FanInFlow::<(u8, String), (u16, usize), (), _>::new((
+            SleepDouble(0),
+            FailLen { hard: false },
+        ));
+        let res = flow.run((4, "abc".to_owned()), &mut TokioSpawner).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+    }
+
+    #[tokio::test]
+    async fn test_flow_error() {
+        let mut flow = FanInFlow::<(String, u8), (usize, u16), (), _>::new((
+            FailLen { hard: true },
+            SleepDouble(200),
+        ));
+
+        let start = Instant::now();
+        let res = flow.run(("abc".to_owned(), 4), &mut TokioSpawner).await;
+        let took = start.elapsed();
+
+        assert_eq!(res, Err(()));
+        assert!(took.as_millis() < 150);
+    }
+
+    #[test]
+    fn test_describe() {
+        let flow = FanInFlow::<(u8, String), (u16, usize), (), TokioSpawner>::new((
+            SleepDouble(0),
+            SleepLen(0),
+        ));
+        let desc = flow.describe();
+        assert!(desc.get_by_path(&[1]).is_some());
+        assert!(desc.get_by_path(&[2]).is_none());
+    }
+}
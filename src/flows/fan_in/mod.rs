@@ -0,0 +1,4 @@
+mod chain_run;
+mod flow;
+
+pub use flow::FanInFlow;
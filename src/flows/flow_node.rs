@@ -0,0 +1,36 @@
+/// Marker trait checking that a node can be added into a flow.
+///
+/// Flows clone their nodes for every run and run them on possibly multiple threads,
+/// so nodes must be `Clone + Send + Sync`.
+/// It is implemented for every type implementing these traits
+/// and it's used by builders in place of the plain bounds,
+/// so that adding an unsuitable node produces an error naming the node.
+///
+/// # Examples
+/// ```compile_fail,E0277
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::SequentialFlow;
+///
+/// // missing `#[derive(Clone)]`
+/// struct AddOne;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         Ok(NodeOutput::Ok(input + 1))
+///     }
+/// }
+///
+/// let flow = SequentialFlow::<u8, u8, (), ()>::builder()
+///     .add_node(AddOne)
+///     .build();
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used as a node of a flow",
+    label = "nodes must be `Clone + Send + Sync`",
+    note = "flows clone their nodes for every run and may run them on other threads",
+    note = "consider deriving `Clone` for `{Self}` and making sure all of its fields are `Send + Sync`"
+)]
+pub trait FlowNode: Clone + Send + Sync {}
+
+#[diagnostic::do_not_recommend]
+impl<T> FlowNode for T where T: Clone + Send + Sync {}
@@ -155,8 +155,62 @@ where
             runner,
         }
     }
+
+    /// Creates a new [`FnFlow`] from a given runner which receives a **reference** to the inner data.
+    ///
+    /// Unlike [`FnFlow::new`], the inner data is not cloned on every run
+    /// and thus it doesn't need to implement [`Clone`].
+    ///
+    /// The runner must satisfy:
+    /// - `Self`: `Runner<'_, Input, Output, Error, _, &InnerData>`
+    ///
+    /// The same requirements for closures as in [`FnFlow::new`] apply.
+    ///
+    /// # Examples
+    /// ```
+    /// # use node_flow::flows::FnFlow;
+    /// # use node_flow::node::{Node, NodeOutput};
+    /// struct LookupTable(Vec<u16>);
+    ///
+    /// # tokio::runtime::Builder::new_current_thread()
+    /// #     .enable_all()
+    /// #     .build()
+    /// #     .unwrap()
+    /// #     .block_on(async {
+    /// let mut flow = FnFlow::<u8, u16, (), ()>::new_ref(
+    ///     LookupTable((0..256).map(|i| i * 2).collect()),
+    ///     async |table: &LookupTable, input, _: &mut _| {
+    ///         Ok(NodeOutput::Ok(table.0[input as usize]))
+    ///     },
+    /// );
+    ///
+    /// let result = flow.run(21, &mut ()).await;
+    /// assert_eq!(result, Ok(NodeOutput::Ok(42)));
+    /// # });
+    /// ```
+    pub fn new_ref<InnerData, R>(
+        inner_data: InnerData,
+        runner: R,
+    ) -> FnFlow<Input, Output, Error, Context, ByRef<InnerData>, R>
+    where
+        InnerData: Send + Sync,
+        for<'a> R: Runner<'a, Input, Output, Error, Context, &'a InnerData>,
+    {
+        FnFlow {
+            _ioec: std::marker::PhantomData,
+            inner_data: std::sync::Arc::new(ByRef(inner_data)),
+            runner_description: None,
+            runner,
+        }
+    }
 }
 
+/// Inner data of [`FnFlow`] which is passed to the runner by reference.
+///
+/// See also [`FnFlow::new_ref`].
+#[derive(Debug)]
+pub struct ByRef<InnerData>(InnerData);
+
 impl<Input, Output, Error, Context, InnerData, R>
     FnFlow<Input, Output, Error, Context, InnerData, R>
 {
@@ -227,45 +281,68 @@ where
     }
 
     fn describe(&self) -> crate::describe::Description {
+        self.describe_with_data::<InnerData>()
+    }
+}
+
+impl<Input, Output, Error, Context, InnerData, R> Node<Input, NodeOutput<Output>, Error, Context>
+    for FnFlow<Input, Output, Error, Context, ByRef<InnerData>, R>
+where
+    Input: Send,
+    Context: Send,
+    InnerData: Sync,
+    for<'a> R: Runner<'a, Input, Output, Error, Context, &'a InnerData>,
+{
+    fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = Result<NodeOutput<Output>, Error>> + Send {
+        let runner = &self.runner;
+        let inner_data = &self.inner_data.0;
+        // the async block allows the data and context borrows to share a single lifetime
+        async move { runner.run(inner_data, input, context).await }
+    }
+
+    fn describe(&self) -> crate::describe::Description {
+        self.describe_with_data::<InnerData>()
+    }
+}
+
+impl<Input, Output, Error, Context, InnerData, R>
+    FnFlow<Input, Output, Error, Context, InnerData, R>
+{
+    fn describe_with_data<Data>(&self) -> Description
+    where
+        Self: Node<Input, NodeOutput<Output>, Error, Context>,
+    {
         if let Some(desc) = self.runner_description.as_ref() {
             return desc.as_ref().clone();
         }
 
         let runner = Description::Node {
             base: DescriptionBase {
-                r#type: Type {
-                    name: "Runner".to_owned(),
-                },
-                input: Type {
-                    name: String::new(),
-                },
-                output: Type {
-                    name: String::new(),
-                },
+                r#type: Type::from_name("Runner"),
+                input: Type::unknown(),
+                output: Type::unknown(),
                 error: Type::of::<Error>(),
                 context: Type::of::<Context>(),
                 description: None,
                 externals: None,
+                detached: false,
             },
         };
 
         let inner_data = Description::Node {
             base: DescriptionBase {
-                r#type: Type::of::<InnerData>(),
-                input: Type {
-                    name: String::new(),
-                },
-                output: Type {
-                    name: String::new(),
-                },
-                error: Type {
-                    name: String::new(),
-                },
-                context: Type {
-                    name: String::new(),
-                },
+                r#type: Type::of::<Data>(),
+                input: Type::unknown(),
+                output: Type::unknown(),
+                error: Type::unknown(),
+                context: Type::unknown(),
                 description: None,
                 externals: None,
+                detached: false,
             },
         };
 
@@ -308,4 +385,24 @@ mod test {
         let res = flow.run(3, &mut st).await;
         assert_eq!(res, Ok(NodeOutput::Ok(23)));
     }
+
+    #[tokio::test]
+    async fn test_flow_ref() {
+        struct NotClone(Vec<u64>);
+
+        let mut st = LocalStorageImpl::new();
+        let mut flow = Flow::<u8, u64, (), _>::new_ref(
+            NotClone(vec![5, 12]),
+            async |data: &NotClone, input: u8, context: &mut LocalStorageImpl| {
+                context.insert(MyVal::default());
+                Ok(NodeOutput::Ok(
+                    data.0.iter().sum::<u64>() + u64::from(input),
+                ))
+            },
+        );
+        let res = flow.run(3, &mut st).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(20)));
+        let res = flow.run(4, &mut st).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(21)));
+    }
 }
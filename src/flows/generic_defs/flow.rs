@@ -9,6 +9,7 @@ macro_rules! define_flow {
             pub(super) _ioec: std::marker::PhantomData<fn() -> (Input, Output, Error, Context)>,
             pub(super) _nodes_io: std::marker::PhantomData<fn() -> NodeIOETypes>,
             pub(super) nodes: std::sync::Arc<NodeTypes>,
+            pub(super) description: Option<String>,
         }
 
         $crate::flows::generic_defs::debug::impl_debug_for_flow!(stringify!($flow_name), $flow_name);
@@ -21,10 +22,25 @@ macro_rules! define_flow {
                     _ioec: std::marker::PhantomData,
                     _nodes_io: std::marker::PhantomData,
                     nodes: self.nodes.clone(),
+                    description: self.description.clone(),
                 }
             }
         }
 
+        impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+            $flow_name<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+        {
+            /// Sets a human-readable description of this flow.
+            ///
+            /// The description is attached to the [`DescriptionBase`](crate::describe::DescriptionBase)
+            /// of the [`Description`](crate::describe::Description) returned by `describe`.
+            #[must_use]
+            pub fn with_description(mut self, description: impl Into<String>) -> Self {
+                self.description = Some(description.into());
+                self
+            }
+        }
+
         impl<Input, Output, Error, Context> $flow_name<Input, Output, Error, Context>
         where
             // Trait bounds for better and nicer errors
@@ -67,7 +83,11 @@ macro_rules! define_flow {
             }
 
             fn describe(& $self) -> $crate::describe::Description {
-                $describe_code
+                let description = $describe_code;
+                match &$self.description {
+                    Some(text) => description.with_description(text.clone()),
+                    None => description,
+                }
             }
         }
     };
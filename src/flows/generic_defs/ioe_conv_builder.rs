@@ -159,6 +159,7 @@ macro_rules! define_builder {
                     _ioec: std::marker::PhantomData,
                     _nodes_io: std::marker::PhantomData,
                     nodes: std::sync::Arc::new(self.nodes),
+                    description: None,
                 }
             }
         }
@@ -0,0 +1,97 @@
+use crate::{
+    describe::Description,
+    flows::{ChainLink, NodeIOE, NodeResult},
+    node::{LocalNode, NodeOutput as NodeOutputStruct},
+};
+
+pub trait ChainRunLocal<Input, Output, Context, T> {
+    const COUNT: usize;
+
+    fn run(&self, input: Input, context: &mut Context) -> impl Future<Output = Output>;
+
+    fn describe(&self, description_acc: &mut Vec<Description>);
+}
+
+impl<
+    Input,
+    Output,
+    Error,
+    Context,
+    HeadIOETypes,
+    TailNodeInType,
+    TailNodeOutType,
+    TailNodeErrType,
+    Head,
+    Tail,
+>
+    ChainRunLocal<
+        Input,
+        NodeResult<Output, Error>,
+        Context,
+        ChainLink<HeadIOETypes, NodeIOE<TailNodeInType, TailNodeOutType, TailNodeErrType>>,
+    > for (Head, Tail)
+where
+    Head: ChainRunLocal<Input, NodeResult<TailNodeInType, Error>, Context, HeadIOETypes>,
+    Tail: LocalNode<TailNodeInType, NodeOutputStruct<TailNodeOutType>, TailNodeErrType, Context>
+        + Clone,
+    TailNodeErrType: Into<Error>,
+    TailNodeOutType: Into<Output>,
+{
+    const COUNT: usize = Head::COUNT + 1;
+
+    // nodes and the context don't have to be `Send`
+    #[expect(clippy::future_not_send)]
+    async fn run(&self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        let (head, tail) = self;
+        if let NodeOutputStruct::Ok(input) = head.run(input, context).await? {
+            let output = tail.clone().run(input, context).await.map_err(Into::into)?;
+            return Ok(match output {
+                NodeOutputStruct::SoftFail => NodeOutputStruct::SoftFail,
+                NodeOutputStruct::Ok(output) => NodeOutputStruct::Ok(output.into()),
+            });
+        }
+        Ok(NodeOutputStruct::SoftFail)
+    }
+
+    fn describe(&self, description_acc: &mut Vec<Description>) {
+        let (head, tail) = self;
+        head.describe(description_acc);
+        description_acc.push(tail.describe());
+    }
+}
+
+impl<Input, Output, Error, Context, HeadNodeInType, HeadNodeOutType, HeadNodeErrType, Head>
+    ChainRunLocal<
+        Input,
+        NodeResult<Output, Error>,
+        Context,
+        ChainLink<(), NodeIOE<HeadNodeInType, HeadNodeOutType, HeadNodeErrType>>,
+    > for (Head,)
+where
+    Input: Into<HeadNodeInType>,
+    Head: LocalNode<HeadNodeInType, NodeOutputStruct<HeadNodeOutType>, HeadNodeErrType, Context>
+        + Clone,
+    HeadNodeErrType: Into<Error>,
+    HeadNodeOutType: Into<Output>,
+{
+    const COUNT: usize = 1;
+
+    // nodes and the context don't have to be `Send`
+    #[expect(clippy::future_not_send)]
+    async fn run(&self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        let output = self
+            .0
+            .clone()
+            .run(input.into(), context)
+            .await
+            .map_err(Into::into)?;
+        Ok(match output {
+            NodeOutputStruct::SoftFail => NodeOutputStruct::SoftFail,
+            NodeOutputStruct::Ok(output) => NodeOutputStruct::Ok(output.into()),
+        })
+    }
+
+    fn describe(&self, description_acc: &mut Vec<Description>) {
+        description_acc.push(self.0.describe());
+    }
+}
@@ -0,0 +1,218 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use super::{Builder, chain_run::ChainRunLocal as ChainRun};
+use crate::{
+    describe::{Description, DescriptionBase, Edge, remove_generics_from_name},
+    flows::{NodeResult, generic_defs::debug::impl_debug_for_flow},
+    node::{LocalNode, NodeOutput as NodeOutputStruct},
+};
+
+/// `LocalSequentialFlow` executes [`LocalNode`]s **sequentially**, like a pipeline.
+///
+/// It behaves the same as [`SequentialFlow`](crate::flows::SequentialFlow),
+/// but it's meant for single-threaded usage.
+/// Nodes, inputs, outputs and the context don't have to be [`Send`] or [`Sync`],
+/// so nodes can hold [`Rc`]-based state.
+/// Because of that, the flow implements only [`LocalNode`] and not [`Node`](crate::node::Node).
+///
+/// - If a node returns [`NodeOutput::Ok`](crate::node::NodeOutput::Ok), that value is then fed into the next node.
+/// - If a node returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail), the flow soft-fails.
+/// - If a node returns an **error**, then that error is returned.
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+/// use node_flow::node::{LocalNode, NodeOutput};
+/// use node_flow::flows::LocalSequentialFlow;
+///
+/// #[derive(Clone)]
+/// struct Record(Rc<RefCell<Vec<u8>>>);
+///
+/// impl<Ctx> LocalNode<u8, NodeOutput<u8>, (), Ctx> for Record {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         self.0.borrow_mut().push(input);
+///         Ok(NodeOutput::Ok(input + 1))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let seen = Rc::new(RefCell::new(Vec::new()));
+///     let mut flow = LocalSequentialFlow::<u8, u8, (), ()>::builder()
+///         .add_node(Record(seen.clone()))
+///         .add_node(Record(seen.clone()))
+///         .build();
+///
+///     let result = flow.run(5, &mut ()).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(7)));
+///     assert_eq!(*seen.borrow(), vec![5, 6]);
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct LocalSequentialFlow<Input, Output, Error, Context, NodeTypes = (), NodeIOETypes = ()> {
+    #[expect(clippy::type_complexity)]
+    pub(super) _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    pub(super) _nodes_io: PhantomData<fn() -> NodeIOETypes>,
+    pub(super) nodes: Rc<NodeTypes>,
+    pub(super) description: Option<String>,
+}
+
+impl_debug_for_flow!("LocalSequentialFlow", LocalSequentialFlow);
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes> Clone
+    for LocalSequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _nodes_io: PhantomData,
+            nodes: self.nodes.clone(),
+            description: self.description.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context> LocalSequentialFlow<Input, Output, Error, Context> {
+    /// Creates a new [`Builder`] for constructing [`LocalSequentialFlow`].
+    ///
+    /// See also [`LocalSequentialFlow`].
+    #[must_use]
+    pub fn builder() -> Builder<Input, Output, Error, Context> {
+        Builder::new()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+    LocalSequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+{
+    /// Sets a human-readable description of this flow.
+    ///
+    /// The description is attached to the [`DescriptionBase`](crate::describe::DescriptionBase)
+    /// of the [`Description`] returned by `describe`.
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+    LocalNode<Input, NodeOutputStruct<Output>, Error, Context>
+    for LocalSequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+where
+    NodeTypes: ChainRun<Input, NodeResult<Output, Error>, Context, NodeIOETypes>,
+{
+    fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = NodeResult<Output, Error>> {
+        ChainRun::run(self.nodes.as_ref(), input, context)
+    }
+
+    fn describe(&self) -> Description {
+        let node_count = <NodeTypes as ChainRun<_, _, Context, NodeIOETypes>>::COUNT;
+        let mut node_descriptions = Vec::with_capacity(node_count);
+        ChainRun::describe(self.nodes.as_ref(), &mut node_descriptions);
+
+        let mut edges = Vec::with_capacity(node_count + 1);
+        edges.push(Edge::flow_to_node(0));
+        for i in 0..node_count - 1 {
+            edges.push(Edge::node_to_node(i, i + 1));
+        }
+        edges.push(Edge::node_to_flow(node_count - 1));
+
+        let description = Description::Flow {
+            base: DescriptionBase::from::<Self, Input, Output, Error, Context>(),
+            nodes: node_descriptions,
+            edges,
+        }
+        .modify_name(remove_generics_from_name);
+        match &self.description {
+            Some(text) => description.with_description(text.clone()),
+            None => description,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::LocalSequentialFlow as Flow;
+    use crate::{
+        describe::Description,
+        flows::tests::{Passer, SoftFailNode},
+        node::{AsLocal, LocalNode, NodeOutput},
+    };
+
+    /// Holds non-`Send` state, so it can't implement `Node`.
+    #[derive(Clone)]
+    struct RcNode(Rc<RefCell<Vec<u8>>>);
+
+    impl<C> LocalNode<u8, NodeOutput<u8>, (), C> for RcNode {
+        #[expect(clippy::future_not_send)]
+        async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u8>, ()> {
+            self.0.borrow_mut().push(input);
+            // holding an `Rc` across an await point
+            let seen = self.0.clone();
+            std::future::ready(()).await;
+            let len = u8::try_from(seen.borrow().len()).unwrap();
+            Ok(NodeOutput::Ok(input + len))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let mut flow = Flow::<u8, u64, (), ()>::builder()
+            .add_node(RcNode(seen.clone()))
+            .add_node(AsLocal(Passer::<u8, u8, ()>::new()))
+            .add_node(RcNode(seen.clone()))
+            .build();
+
+        let res = flow.run(1, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(4)));
+        assert_eq!(*seen.borrow(), vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_flow_soft_fail_and_nesting() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let inner = Flow::<u8, u8, (), ()>::builder()
+            .add_node(RcNode(seen.clone()))
+            .build();
+        let mut flow = Flow::<u8, u8, (), ()>::builder()
+            .add_node(inner.clone())
+            .add_node(AsLocal(SoftFailNode::<u8, u8, ()>::new()))
+            .add_node(RcNode(seen.clone()))
+            .build();
+
+        let res = flow.run(1, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+        assert_eq!(*seen.borrow(), vec![1]);
+
+        let desc = flow.describe();
+        let Description::Flow { base, nodes, edges } = &desc else {
+            panic!("expected flow description");
+        };
+        assert_eq!(
+            base.r#type.name,
+            "node_flow::flows::local_sequential_flow::flow::LocalSequentialFlow"
+        );
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(edges.len(), 4);
+        assert!(matches!(nodes[0], Description::Flow { .. }));
+    }
+}
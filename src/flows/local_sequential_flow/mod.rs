@@ -0,0 +1,5 @@
+mod builder;
+pub use builder::*;
+mod chain_run;
+mod flow;
+pub use flow::*;
@@ -0,0 +1,239 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::NodeResult,
+    node::{Node, NodeOutput},
+};
+
+/// `LoopFlow` repeatedly feeds the output of a node back into it.
+///
+/// On each iteration the node is run with the current state,
+/// then the predicate is checked against the new state.
+/// - If the predicate returns `true`, the next iteration is run.
+/// - If the predicate returns `false`, the new state is returned.
+/// - If the node returns [`NodeOutput::SoftFail`], the loop ends and the flow returns [`NodeOutput::SoftFail`].
+/// - If the node returns an **error**, then that error is returned.
+///
+/// Optionally, the number of iterations can be limited using [`LoopFlow::with_max_iterations`].
+/// When the limit is reached, the flow returns [`NodeOutput::SoftFail`].
+///
+/// # Type Parameters
+/// - `State`: The type of data **accepted and produced** by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::LoopFlow;
+///
+/// #[derive(Clone)]
+/// struct Halve;
+///
+/// impl<Ctx: Send> Node<u32, NodeOutput<u32>, (), Ctx> for Halve {
+///     async fn run(&mut self, input: u32, _: &mut Ctx) -> Result<NodeOutput<u32>, ()> {
+///         Ok(NodeOutput::Ok(input / 2))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = LoopFlow::<u32, (), ()>::new(Halve, |state: &u32| *state > 10)
+///         .with_max_iterations(100);
+///
+///     let result = flow.run(100, &mut ()).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(6)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct LoopFlow<State, Error, Context, NodeType = (), Predicate = (), NodeError = ()> {
+    #[expect(clippy::type_complexity)]
+    _sec: PhantomData<fn() -> (State, Error, Context)>,
+    _node_e: PhantomData<fn() -> NodeError>,
+    node: NodeType,
+    predicate: Predicate,
+    max_iterations: Option<usize>,
+}
+
+impl<State, Error, Context> LoopFlow<State, Error, Context> {
+    /// Creates a new [`LoopFlow`].
+    ///
+    /// # Parameters
+    /// - `node`: The node that is run on each iteration.
+    /// - `predicate`: Returns `true` if the loop should continue with the given state.
+    pub fn new<NodeType, Predicate, NodeError>(
+        node: NodeType,
+        predicate: Predicate,
+    ) -> LoopFlow<State, Error, Context, NodeType, Predicate, NodeError>
+    where
+        NodeType: Node<State, NodeOutput<State>, NodeError, Context>,
+        Predicate: Fn(&State) -> bool,
+        // Trait bounds for better and nicer errors
+        NodeError: Into<Error>,
+    {
+        LoopFlow {
+            _sec: PhantomData,
+            _node_e: PhantomData,
+            node,
+            predicate,
+            max_iterations: None,
+        }
+    }
+}
+
+impl<State, Error, Context, NodeType, Predicate, NodeError>
+    LoopFlow<State, Error, Context, NodeType, Predicate, NodeError>
+{
+    /// Limits the number of iterations.
+    ///
+    /// When the limit is reached and the predicate still returns `true`,
+    /// the flow returns [`NodeOutput::SoftFail`].
+    #[must_use]
+    pub const fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = Some(max_iterations);
+        self
+    }
+}
+
+impl<State, Error, Context, NodeType, Predicate, NodeError> Debug
+    for LoopFlow<State, Error, Context, NodeType, Predicate, NodeError>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoopFlow")
+            .field("node", &self.node)
+            .field("max_iterations", &self.max_iterations)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<State, Error, Context, NodeType, Predicate, NodeError> Clone
+    for LoopFlow<State, Error, Context, NodeType, Predicate, NodeError>
+where
+    NodeType: Clone,
+    Predicate: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _sec: PhantomData,
+            _node_e: PhantomData,
+            node: self.node.clone(),
+            predicate: self.predicate.clone(),
+            max_iterations: self.max_iterations,
+        }
+    }
+}
+
+impl<State, Error, Context, NodeType, Predicate, NodeError>
+    Node<State, NodeOutput<State>, Error, Context>
+    for LoopFlow<State, Error, Context, NodeType, Predicate, NodeError>
+where
+    NodeType: Node<State, NodeOutput<State>, NodeError, Context> + Clone + Send + Sync,
+    Predicate: Fn(&State) -> bool + Send + Sync,
+    NodeError: Into<Error>,
+    State: Send,
+    Context: Send,
+{
+    async fn run(&mut self, input: State, context: &mut Context) -> NodeResult<State, Error> {
+        let mut node = self.node.clone();
+        let mut state = input;
+        let mut iterations = 0;
+        loop {
+            if self.max_iterations.is_some_and(|max| iterations >= max) {
+                return Ok(NodeOutput::SoftFail);
+            }
+            iterations += 1;
+
+            let NodeOutput::Ok(new_state) = node.run(state, context).await.map_err(Into::into)?
+            else {
+                return Ok(NodeOutput::SoftFail);
+            };
+            if !(self.predicate)(&new_state) {
+                return Ok(NodeOutput::Ok(new_state));
+            }
+            state = new_state;
+        }
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_flow(
+            self,
+            vec![self.node.describe()],
+            vec![
+                Edge::flow_to_node(0),
+                Edge::node_to_node(0, 0),
+                Edge::node_to_flow(0),
+            ],
+        )
+        .modify_name(remove_generics_from_name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LoopFlow;
+    use crate::{
+        context::storage::local_storage::LocalStorageImpl,
+        describe::{Description, EdgeEnding},
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct Increment;
+
+    impl<C: Send> Node<u32, NodeOutput<u32>, (), C> for Increment {
+        async fn run(&mut self, input: u32, _context: &mut C) -> Result<NodeOutput<u32>, ()> {
+            if input == 100 {
+                return Ok(NodeOutput::SoftFail);
+            }
+            Ok(NodeOutput::Ok(input + 1))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow() {
+        let mut st = LocalStorageImpl::new();
+        let mut flow = LoopFlow::<u32, (), _>::new(Increment, |state: &u32| *state < 10);
+        assert_eq!(flow.run(0, &mut st).await, Ok(NodeOutput::Ok(10)));
+        // node is always run at least once
+        assert_eq!(flow.run(20, &mut st).await, Ok(NodeOutput::Ok(21)));
+    }
+
+    #[tokio::test]
+    async fn test_flow_soft_fail() {
+        let mut st = LocalStorageImpl::new();
+        let mut flow = LoopFlow::<u32, (), _>::new(Increment, |_: &u32| true);
+        assert_eq!(flow.run(0, &mut st).await, Ok(NodeOutput::SoftFail));
+    }
+
+    #[tokio::test]
+    async fn test_flow_max_iterations() {
+        let mut st = LocalStorageImpl::new();
+        let mut flow = LoopFlow::<u32, (), _>::new(Increment, |state: &u32| *state < 10)
+            .with_max_iterations(5);
+        assert_eq!(flow.run(0, &mut st).await, Ok(NodeOutput::SoftFail));
+        assert_eq!(flow.run(5, &mut st).await, Ok(NodeOutput::Ok(10)));
+    }
+
+    #[test]
+    fn test_describe() {
+        let flow = LoopFlow::<u32, (), LocalStorageImpl>::new(Increment, |_: &u32| true);
+        let Description::Flow { edges, .. } = flow.describe() else {
+            panic!("expected flow description");
+        };
+        assert!(edges.iter().any(|edge| matches!(
+            (&edge.start, &edge.end),
+            (
+                EdgeEnding::ToNode { node_index: 0 },
+                EdgeEnding::ToNode { node_index: 0 }
+            )
+        )));
+    }
+}
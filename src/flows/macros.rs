@@ -0,0 +1,69 @@
+/// A helper macro for declaring a whole [`SequentialFlow`](crate::flows::SequentialFlow) inline.
+///
+/// Nodes are added in the given order using [`add_node`](crate::flows::sequential_flow::Builder::add_node)
+/// and the flow is finalized using [`build`](crate::flows::sequential_flow::Builder::build).
+/// The `Input`, `Output`, `Error` and `Context` types can be passed in angle brackets,
+/// otherwise they are inferred from the context the flow is used in.
+///
+/// # Parameters
+/// - `$input`, `$output`, `$error`, `$context`: Optional type parameters of the flow.
+/// - `$node`: The nodes of the flow.
+///
+/// See also [`SequentialFlow`](crate::flows::SequentialFlow).
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::sequential_flow;
+///
+/// #[derive(Clone)]
+/// struct AddOne;
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         Ok(NodeOutput::Ok(input + 1))
+///     }
+/// }
+///
+/// #[derive(Clone)]
+/// struct Double;
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for Double {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         Ok(NodeOutput::Ok(input * 2))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = sequential_flow![<u8, u8, (), ()> AddOne, AddOne, Double];
+///     assert_eq!(flow.run(5, &mut ()).await, Ok(NodeOutput::Ok(14)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+///
+/// # Expansion
+/// ```ignore
+/// sequential_flow![<$input, $output, $error, $context> $node0, $node1]
+/// // expands to:
+/// node_flow::flows::SequentialFlow::<$input, $output, $error, $context>::builder()
+///     .add_node($node0)
+///     .add_node($node1)
+///     .build()
+/// ```
+#[macro_export]
+macro_rules! sequential_flow {
+    (<$input:ty, $output:ty, $error:ty, $context:ty> $($node:expr),+ $(,)?) => {
+        $crate::flows::SequentialFlow::<$input, $output, $error, $context>::builder()
+            $(.add_node($node))+
+            .build()
+    };
+    ($($node:expr),+ $(,)?) => {
+        $crate::flows::SequentialFlow::builder()
+            $(.add_node($node))+
+            .build()
+    };
+}
@@ -0,0 +1,487 @@
+use std::{fmt::Debug, future::poll_fn, marker::PhantomData, pin::Pin, task::Poll};
+
+use crate::{
+    context::{Fork, Join},
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::NodeResult,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// Marker for [`MapFlow`] that runs elements one after another.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sequential;
+
+/// Marker for [`MapFlow`] that runs elements concurrently.
+///
+/// Holds the maximum number of elements that run at the same time
+/// and the maximum number of forked contexts that exist at the same time.
+#[derive(Debug, Clone, Copy)]
+pub struct Concurrent {
+    max_concurrency: usize,
+    fork_pool: usize,
+}
+
+/// `MapFlow` runs a node over **each element** of the input and collects the outputs.
+///
+/// By default ([`MapFlow::new`]), elements are processed **sequentially**.
+/// With [`MapFlow::new_concurrent`], elements are processed **concurrently**, each with its own forked context,
+/// and at most the specified number of elements run at the same time.
+/// Forked contexts are then joined back.
+///
+/// By default every forked context is kept until all elements finish.
+/// For large inputs with heavy contexts, [`MapFlow::with_fork_pool`] bounds the number of forked contexts
+/// that exist at the same time by joining finished contexts back in waves.
+///
+/// - If the node returns [`NodeOutput::Ok`](crate::node::NodeOutput::Ok) for all elements, the outputs are returned
+///   in the same order as the input elements.
+/// - If the node returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail) for any element,
+///   the flow returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail).
+/// - If the node returns an **error**, then that error is returned.
+///
+/// # Type Parameters
+/// - `Item`: The type of input elements, the flow accepts `Vec<Item>`.
+/// - `Out`: The type of output elements, the flow produces `Vec<Out>`.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::MapFlow;
+/// use node_flow::context::{Fork, Join};
+///
+/// #[derive(Clone)]
+/// struct Square;
+///
+/// impl<Ctx: Send> Node<u32, NodeOutput<u64>, (), Ctx> for Square {
+///     async fn run(&mut self, input: u32, _: &mut Ctx) -> Result<NodeOutput<u64>, ()> {
+///         Ok(NodeOutput::Ok(u64::from(input).pow(2)))
+///     }
+/// }
+///
+/// struct ExampleCtx;
+/// impl Fork for ExampleCtx // ...
+/// # { fn fork(&self) -> Self { Self } }
+/// impl Join for ExampleCtx // ...
+/// # { fn join(&mut self, others: Box<[Self]>) {} }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut ctx = ExampleCtx;
+///
+///     let mut flow = MapFlow::<u32, u64, (), ExampleCtx>::new(Square);
+///     let result = flow.run(vec![1, 2, 3], &mut ctx).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(vec![1, 4, 9])));
+///
+///     let mut flow = MapFlow::<u32, u64, (), ExampleCtx>::new_concurrent(Square, 2);
+///     let result = flow.run(vec![1, 2, 3], &mut ctx).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(vec![1, 4, 9])));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct MapFlow<
+    Item,
+    Out,
+    Error,
+    Context,
+    NodeType = (),
+    NodeOutput = (),
+    NodeError = (),
+    Mode = Sequential,
+> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Item, Out, Error, Context)>,
+    _node_oe: PhantomData<fn() -> (NodeOutput, NodeError)>,
+    node: NodeType,
+    mode: Mode,
+}
+
+impl<Item, Out, Error, Context> MapFlow<Item, Out, Error, Context> {
+    /// Creates a new [`MapFlow`] that processes elements sequentially.
+    pub fn new<NodeType, NodeOutput, NodeError>(
+        node: NodeType,
+    ) -> MapFlow<Item, Out, Error, Context, NodeType, NodeOutput, NodeError, Sequential>
+    where
+        NodeType: Node<Item, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeOutput: Into<Out>,
+        NodeError: Into<Error>,
+    {
+        MapFlow {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node,
+            mode: Sequential,
+        }
+    }
+
+    /// Creates a new [`MapFlow`] that processes at most `max_concurrency` elements concurrently.
+    ///
+    /// # Panics
+    /// Panics if `max_concurrency` is `0`.
+    pub fn new_concurrent<NodeType, NodeOutput, NodeError>(
+        node: NodeType,
+        max_concurrency: usize,
+    ) -> MapFlow<Item, Out, Error, Context, NodeType, NodeOutput, NodeError, Concurrent>
+    where
+        NodeType: Node<Item, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeOutput: Into<Out>,
+        NodeError: Into<Error>,
+        Context: Fork + Join,
+    {
+        assert!(
+            max_concurrency > 0,
+            "max_concurrency must be greater than 0"
+        );
+        MapFlow {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node,
+            mode: Concurrent {
+                max_concurrency,
+                fork_pool: usize::MAX,
+            },
+        }
+    }
+}
+
+impl<Item, Out, Error, Context, NodeType, NodeOutput, NodeError>
+    MapFlow<Item, Out, Error, Context, NodeType, NodeOutput, NodeError, Concurrent>
+{
+    /// Limits the number of forked contexts that exist at the same time to `fork_pool`.
+    ///
+    /// Forked contexts of running and finished elements both count towards the limit.
+    /// When the limit is reached, contexts of finished elements are joined back
+    /// before new elements are started, so elements are processed in waves.
+    /// This trades parallelism for memory, since at most `fork_pool` elements run at the same time.
+    ///
+    /// Note that elements started after a wave was joined fork a context
+    /// that already contains the changes made by the elements of that wave.
+    ///
+    /// # Panics
+    /// Panics if `fork_pool` is `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use node_flow::node::{Node, NodeOutput};
+    /// # use node_flow::flows::MapFlow;
+    /// # use node_flow::context::{Fork, Join};
+    /// #
+    /// # #[derive(Clone)]
+    /// # struct Square;
+    /// #
+    /// # impl<Ctx: Send> Node<u32, NodeOutput<u64>, (), Ctx> for Square {
+    /// #     async fn run(&mut self, input: u32, _: &mut Ctx) -> Result<NodeOutput<u64>, ()> {
+    /// #         Ok(NodeOutput::Ok(u64::from(input).pow(2)))
+    /// #     }
+    /// # }
+    /// #
+    /// # struct ExampleCtx;
+    /// # impl Fork for ExampleCtx { fn fork(&self) -> Self { Self } }
+    /// # impl Join for ExampleCtx { fn join(&mut self, others: Box<[Self]>) {} }
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread()
+    /// #     .enable_all()
+    /// #     .build()
+    /// #     .unwrap()
+    /// #     .block_on(async {
+    /// let mut flow = MapFlow::<u32, u64, (), ExampleCtx>::new_concurrent(Square, 16)
+    ///     .with_fork_pool(4);
+    /// let result = flow.run((1..=8).collect(), &mut ExampleCtx).await;
+    /// assert_eq!(result, Ok(NodeOutput::Ok(vec![1, 4, 9, 16, 25, 36, 49, 64])));
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn with_fork_pool(mut self, fork_pool: usize) -> Self {
+        assert!(fork_pool > 0, "fork_pool must be greater than 0");
+        self.mode.fork_pool = fork_pool;
+        self
+    }
+}
+
+impl<Item, Out, Error, Context, NodeType, NodeOutput, NodeError, Mode> Debug
+    for MapFlow<Item, Out, Error, Context, NodeType, NodeOutput, NodeError, Mode>
+where
+    NodeType: Debug,
+    Mode: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MapFlow")
+            .field("node", &self.node)
+            .field("mode", &self.mode)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Item, Out, Error, Context, NodeType, NodeOutput, NodeError, Mode> Clone
+    for MapFlow<Item, Out, Error, Context, NodeType, NodeOutput, NodeError, Mode>
+where
+    NodeType: Clone,
+    Mode: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node: self.node.clone(),
+            mode: self.mode.clone(),
+        }
+    }
+}
+
+impl<Item, Out, Error, Context, NodeType, NodeOutput, NodeError, Mode>
+    MapFlow<Item, Out, Error, Context, NodeType, NodeOutput, NodeError, Mode>
+{
+    fn describe_inner(&self) -> Description
+    where
+        Self: Node<Vec<Item>, NodeOutputStruct<Vec<Out>>, Error, Context>,
+        NodeType: Node<Item, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+    {
+        let mut node_description = self.node.describe();
+        let description = &mut node_description.get_base_mut().description;
+        *description = Some(description.take().map_or_else(
+            || "Applied per element".to_owned(),
+            |description| format!("{description}\n\nApplied per element"),
+        ));
+
+        Description::new_flow(
+            self,
+            vec![node_description],
+            vec![Edge::flow_to_node(0), Edge::node_to_flow(0)],
+        )
+        .modify_name(remove_generics_from_name)
+    }
+}
+
+impl<Item, Out, Error, Context, NodeType, NodeOutput, NodeError>
+    Node<Vec<Item>, NodeOutputStruct<Vec<Out>>, Error, Context>
+    for MapFlow<Item, Out, Error, Context, NodeType, NodeOutput, NodeError, Sequential>
+where
+    NodeType: Node<Item, NodeOutputStruct<NodeOutput>, NodeError, Context> + Clone + Send + Sync,
+    NodeOutput: Into<Out>,
+    NodeError: Into<Error>,
+    Item: Send,
+    Out: Send,
+    Context: Send,
+{
+    async fn run(
+        &mut self,
+        input: Vec<Item>,
+        context: &mut Context,
+    ) -> NodeResult<Vec<Out>, Error> {
+        let mut node = self.node.clone();
+        let mut outputs = Vec::with_capacity(input.len());
+        for item in input {
+            match node.run(item, context).await.map_err(Into::into)? {
+                NodeOutputStruct::Ok(output) => outputs.push(output.into()),
+                NodeOutputStruct::SoftFail => return Ok(NodeOutputStruct::SoftFail),
+            }
+        }
+        Ok(NodeOutputStruct::Ok(outputs))
+    }
+
+    fn describe(&self) -> Description {
+        self.describe_inner()
+    }
+}
+
+impl<Item, Out, Error, Context, NodeType, NodeOutput, NodeError>
+    Node<Vec<Item>, NodeOutputStruct<Vec<Out>>, Error, Context>
+    for MapFlow<Item, Out, Error, Context, NodeType, NodeOutput, NodeError, Concurrent>
+where
+    NodeType: Node<Item, NodeOutputStruct<NodeOutput>, NodeError, Context> + Clone + Send + Sync,
+    NodeOutput: Into<Out> + Send,
+    NodeError: Into<Error> + Send,
+    Item: Send,
+    Out: Send,
+    Context: Fork + Join + Send,
+{
+    async fn run(
+        &mut self,
+        input: Vec<Item>,
+        context: &mut Context,
+    ) -> NodeResult<Vec<Out>, Error> {
+        let item_count = input.len();
+        let mut outputs = (0..item_count).map(|_| None).collect::<Vec<_>>();
+        let mut contexts = Vec::with_capacity(item_count);
+
+        let mut pending = input.into_iter().enumerate();
+        let mut running = Vec::with_capacity(self.mode.max_concurrency.min(item_count));
+        let res = loop {
+            while running.len() < self.mode.max_concurrency && pending.len() > 0 {
+                if running.len() + contexts.len() >= self.mode.fork_pool {
+                    if contexts.is_empty() {
+                        break;
+                    }
+                    // join finished wave to free the pool
+                    context.join(std::mem::take(&mut contexts).into_boxed_slice());
+                }
+                let Some((idx, item)) = pending.next() else {
+                    break;
+                };
+                let mut node = self.node.clone();
+                let mut node_context = context.fork();
+                running.push(Box::pin(async move {
+                    let res = node.run(item, &mut node_context).await;
+                    (idx, res, node_context)
+                }));
+            }
+            if running.is_empty() {
+                break Ok(true);
+            }
+
+            let (idx, res, node_context) = poll_fn(|cx| {
+                let Some((i, output)) = running.iter_mut().enumerate().find_map(|(i, fut)| {
+                    match Pin::as_mut(fut).poll(cx) {
+                        Poll::Ready(output) => Some((i, output)),
+                        Poll::Pending => None,
+                    }
+                }) else {
+                    return Poll::Pending;
+                };
+                drop(running.swap_remove(i));
+                Poll::Ready(output)
+            })
+            .await;
+            contexts.push(node_context);
+            match res {
+                Ok(NodeOutputStruct::Ok(output)) => outputs[idx] = Some(output.into()),
+                Ok(NodeOutputStruct::SoftFail) => break Ok(false),
+                Err(err) => break Err(err.into()),
+            }
+        };
+        // cancel still running elements
+        drop(running);
+        context.join(contexts.into_boxed_slice());
+
+        if !res? {
+            return Ok(NodeOutputStruct::SoftFail);
+        }
+        Ok(NodeOutputStruct::Ok(
+            outputs.into_iter().map(Option::unwrap).collect(),
+        ))
+    }
+
+    fn describe(&self) -> Description {
+        self.describe_inner()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Instant,
+    };
+
+    use super::MapFlow;
+    use crate::{
+        context::{
+            Fork, Join,
+            storage::local_storage::{LocalStorage, LocalStorageImpl, tests::MyVal},
+        },
+        flows::tests::{InsertIntoStorageAssertWasNotInStorage, Passer, SoftFailNode},
+        node::{Node, NodeOutput},
+    };
+
+    #[tokio::test]
+    async fn test_flow() {
+        let mut st = LocalStorageImpl::new();
+        let mut flow = MapFlow::<u8, u64, (), _>::new(Passer::<u8, u32, ()>::new());
+        let start = Instant::now();
+        let res = flow.run(vec![1, 2, 3], &mut st).await;
+        assert!(start.elapsed().as_millis() >= 450);
+        assert_eq!(res, Ok(NodeOutput::Ok(vec![1, 2, 3])));
+
+        let mut flow = MapFlow::<u8, u64, (), _>::new(SoftFailNode::<u8, u32, ()>::new());
+        let res = flow.run(vec![1, 2, 3], &mut st).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+    }
+
+    #[tokio::test]
+    async fn test_flow_concurrent() {
+        let mut st = LocalStorageImpl::new();
+        let mut flow = MapFlow::<u8, u64, (), _>::new_concurrent(Passer::<u8, u32, ()>::new(), 2);
+        let start = Instant::now();
+        let res = flow.run(vec![1, 2, 3, 4], &mut st).await;
+        let took = start.elapsed().as_millis();
+        assert!((300..450).contains(&took));
+        assert_eq!(res, Ok(NodeOutput::Ok(vec![1, 2, 3, 4])));
+
+        let mut flow =
+            MapFlow::<u8, u64, (), _>::new_concurrent(SoftFailNode::<u8, u32, ()>::new(), 2);
+        let res = flow.run(vec![1, 2, 3], &mut st).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+    }
+
+    #[tokio::test]
+    async fn test_flow_concurrent_storage() {
+        let mut st = LocalStorageImpl::new();
+        let mut flow = MapFlow::<u8, u64, (), _>::new_concurrent(
+            InsertIntoStorageAssertWasNotInStorage::<u8, u32, (), MyVal>::new(),
+            3,
+        );
+        let res = flow.run(vec![1, 2, 3], &mut st).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+        // context of the soft-failed element was joined back
+        assert_eq!(st.get::<MyVal>(), Some(&MyVal::default()));
+    }
+
+    #[derive(Default)]
+    struct CountingCtx {
+        live: Arc<AtomicUsize>,
+        max_live: Arc<AtomicUsize>,
+        joined: usize,
+        forked: bool,
+    }
+
+    impl Fork for CountingCtx {
+        fn fork(&self) -> Self {
+            let live = self.live.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_live.fetch_max(live, Ordering::SeqCst);
+            Self {
+                live: self.live.clone(),
+                max_live: self.max_live.clone(),
+                joined: 0,
+                forked: true,
+            }
+        }
+    }
+
+    impl Join for CountingCtx {
+        fn join(&mut self, others: Box<[Self]>) {
+            self.joined += others.len();
+        }
+    }
+
+    impl Drop for CountingCtx {
+        fn drop(&mut self) {
+            if self.forked {
+                self.live.fetch_sub(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow_concurrent_fork_pool() {
+        let mut ctx = CountingCtx::default();
+        let mut flow = MapFlow::<u8, u64, (), _>::new_concurrent(Passer::<u8, u32, ()>::new(), 4)
+            .with_fork_pool(2);
+        let start = Instant::now();
+        let res = flow.run(vec![1, 2, 3, 4, 5, 6], &mut ctx).await;
+        assert!(start.elapsed().as_millis() >= 450);
+        assert_eq!(res, Ok(NodeOutput::Ok(vec![1, 2, 3, 4, 5, 6])));
+        assert_eq!(ctx.max_live.load(Ordering::SeqCst), 2);
+        assert_eq!(ctx.live.load(Ordering::SeqCst), 0);
+        assert_eq!(ctx.joined, 6);
+    }
+}
@@ -0,0 +1,2 @@
+mod flow;
+pub use flow::*;
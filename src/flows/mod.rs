@@ -24,6 +24,12 @@ pub use one_of_sequential_flow::OneOfSequentialFlow;
 pub mod one_of_parallel_flow;
 pub use one_of_parallel_flow::OneOfParallelFlow;
 
+/// This module contains everything needed for constructing [`QuorumFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`QuorumFlow`] and [`Builder`](quorum_flow::Builder).
+pub mod quorum_flow;
+pub use quorum_flow::QuorumFlow;
+
 /// This module contains everything needed for constructing [`ParallelFlow`].
 ///
 /// For detailed behavior and examples, see the documentation of [`ParallelFlow`], [`Builder`](parallel_flow::Builder) and [`Joiner`](parallel_flow::Joiner).
@@ -42,6 +48,143 @@ pub use fn_flow::FnFlow;
 pub mod detached;
 pub use detached::Detached;
 
+/// This module contains everything needed for constructing [`DetachedLocal`].
+///
+/// For detailed behavior and examples, see the documentation of [`DetachedLocal`].
+pub mod detached_local;
+pub use detached_local::DetachedLocal;
+
+/// This module contains everything needed for constructing [`WindowFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`WindowFlow`], [`CountWindow`](window_flow::CountWindow) and [`TimeWindow`](window_flow::TimeWindow).
+pub mod window_flow;
+pub use window_flow::WindowFlow;
+
+/// This module contains everything needed for constructing [`DagFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`DagFlow`], [`Builder`](dag::Builder) and [`DagError`](dag::DagError).
+pub mod dag;
+pub use dag::DagFlow;
+
+/// This module contains everything needed for constructing [`RetryFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`RetryFlow`] and [`Backoff`](retry_flow::Backoff).
+pub mod retry_flow;
+pub use retry_flow::RetryFlow;
+
+/// This module contains everything needed for constructing [`TimeoutFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`TimeoutFlow`].
+pub mod timeout_flow;
+pub use timeout_flow::TimeoutFlow;
+
+/// This module contains everything needed for constructing [`LoopFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`LoopFlow`].
+pub mod loop_flow;
+pub use loop_flow::LoopFlow;
+
+/// This module contains everything needed for constructing [`ConditionalFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`ConditionalFlow`].
+pub mod conditional_flow;
+pub use conditional_flow::ConditionalFlow;
+
+/// This module contains everything needed for constructing [`MapFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`MapFlow`].
+pub mod map_flow;
+pub use map_flow::MapFlow;
+
+/// This module contains everything needed for constructing [`CircuitBreakerFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`CircuitBreakerFlow`].
+pub mod circuit_breaker;
+pub use circuit_breaker::CircuitBreakerFlow;
+
+/// This module contains everything needed for constructing [`RateLimitFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`RateLimitFlow`].
+pub mod rate_limit;
+pub use rate_limit::RateLimitFlow;
+
+/// This module contains everything needed for constructing [`TracedFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`TracedFlow`].
+#[cfg(feature = "tracing")]
+pub mod traced;
+#[cfg(feature = "tracing")]
+pub use traced::TracedFlow;
+
+/// This module contains everything needed for constructing [`FanInFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`FanInFlow`].
+pub mod fan_in;
+pub use fan_in::FanInFlow;
+
+/// This module contains everything needed for constructing [`DebounceFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`DebounceFlow`].
+pub mod debounce;
+pub use debounce::DebounceFlow;
+
+/// This module contains everything needed for constructing [`ObservedFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`ObservedFlow`] and [`Observer`](crate::observe::Observer).
+pub mod observed;
+pub use observed::ObservedFlow;
+
+/// This module contains everything needed for constructing [`DynSequentialFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`DynSequentialFlow`].
+#[cfg(feature = "boxed_node")]
+pub mod dyn_sequential_flow;
+#[cfg(feature = "boxed_node")]
+pub use dyn_sequential_flow::DynSequentialFlow;
+
+/// This module contains everything needed for constructing [`BlockingNode`].
+///
+/// For detailed behavior and examples, see the documentation of [`BlockingNode`].
+pub mod blocking;
+pub use blocking::BlockingNode;
+
+/// This module contains everything needed for constructing [`PeekFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`PeekFlow`].
+pub mod peek;
+pub use peek::PeekFlow;
+
+/// This module contains everything needed for constructing [`DelayFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`DelayFlow`].
+pub mod delay;
+pub use delay::DelayFlow;
+
+/// This module contains everything needed for constructing [`ValidateNode`].
+///
+/// For detailed behavior and examples, see the documentation of [`ValidateNode`].
+pub mod validate;
+pub use validate::ValidateNode;
+
+/// This module contains everything needed for constructing [`LocalSequentialFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`LocalSequentialFlow`] and [`Builder`](local_sequential_flow::Builder).
+pub mod local_sequential_flow;
+pub use local_sequential_flow::LocalSequentialFlow;
+
+/// This module contains everything needed for constructing [`BatchFlow`].
+///
+/// For detailed behavior and examples, see the documentation of [`BatchFlow`].
+pub mod batch;
+pub use batch::BatchFlow;
+
+mod batch_result;
+mod flow_node;
+pub use flow_node::FlowNode;
+mod macros;
+mod shared;
+pub use shared::Shared;
+
 use crate::node::NodeOutput;
 type NodeIOE<Input, Output, Error> = (Input, NodeOutput<Output>, Error);
 type ChainLink<Head, Tail> = (Head, Tail);
@@ -0,0 +1,252 @@
+use std::{fmt::Debug, marker::PhantomData, sync::Arc, time::Instant};
+
+use crate::{
+    describe::Description,
+    flows::NodeResult,
+    node::{Node, NodeOutput as NodeOutputStruct},
+    observe::{Observer, Outcome},
+};
+
+/// `ObservedFlow` wraps a node and reports its runs to an [`Observer`].
+///
+/// [`Observer::on_start`] is called before every run of the wrapped node
+/// and [`Observer::on_finish`] is called with the [`Outcome`] and the duration of the run once it finishes.
+/// The node is identified by its type name taken from its [`Description`].
+///
+/// The description of this flow is the description of the wrapped node,
+/// so observing doesn't show up in visualizations.
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::ObservedFlow;
+/// use node_flow::observe::{Observer, Outcome};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// #[derive(Clone)]
+/// struct AddOne;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         Ok(NodeOutput::Ok(input + 1))
+///     }
+/// }
+///
+/// struct PrintObserver;
+///
+/// impl Observer for PrintObserver {
+///     fn on_start(&self, node_type: &str) {
+///         println!("{node_type} started");
+///     }
+///
+///     fn on_finish(&self, node_type: &str, outcome: Outcome, elapsed: Duration) {
+///         println!("{node_type} finished with {outcome:?} in {elapsed:?}");
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = ObservedFlow::<u8, u8, (), ()>::new(AddOne, Arc::new(PrintObserver));
+///
+///     let result = flow.run(5, &mut ()).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(6)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct ObservedFlow<
+    Input,
+    Output,
+    Error,
+    Context,
+    NodeType = (),
+    NodeOutput = (),
+    NodeError = (),
+> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    _node_oe: PhantomData<fn() -> (NodeOutput, NodeError)>,
+    node: NodeType,
+    observer: Arc<dyn Observer>,
+    /// Type name of the wrapped node.
+    name: String,
+}
+
+impl<Input, Output, Error, Context> ObservedFlow<Input, Output, Error, Context> {
+    /// Creates a new [`ObservedFlow`] that reports runs of `node` to `observer`.
+    pub fn new<NodeType, NodeOutput, NodeError>(
+        node: NodeType,
+        observer: Arc<dyn Observer>,
+    ) -> ObservedFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    where
+        NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeOutput: Into<Output>,
+        NodeError: Into<Error>,
+    {
+        let name = node.describe().get_base_ref().r#type.name.clone();
+        ObservedFlow {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node,
+            observer,
+            name,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Debug
+    for ObservedFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObservedFlow")
+            .field("node", &self.node)
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Clone
+    for ObservedFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node: self.node.clone(),
+            observer: self.observer.clone(),
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for ObservedFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context> + Send,
+    NodeOutput: Into<Output>,
+    NodeError: Into<Error>,
+    Input: Send,
+    Context: Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        self.observer.on_start(&self.name);
+        let start = Instant::now();
+        let res = self.node.run(input, context).await;
+        self.observer
+            .on_finish(&self.name, Outcome::of(&res), start.elapsed());
+
+        match res {
+            Ok(NodeOutputStruct::Ok(output)) => Ok(NodeOutputStruct::Ok(output.into())),
+            Ok(NodeOutputStruct::SoftFail) => Ok(NodeOutputStruct::SoftFail),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn describe(&self) -> Description {
+        self.node.describe()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use super::ObservedFlow;
+    use crate::{
+        flows::tests::{Passer, SoftFailNode},
+        node::{Node, NodeOutput},
+        observe::{Observer, Outcome},
+    };
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Event {
+        Start(String),
+        Finish(String, Outcome),
+    }
+
+    /// Records all events and the elapsed time of finished runs.
+    #[derive(Default)]
+    struct RecordingObserver {
+        events: Mutex<Vec<Event>>,
+        elapsed: Mutex<Vec<Duration>>,
+    }
+
+    impl Observer for RecordingObserver {
+        fn on_start(&self, node_type: &str) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(Event::Start(node_type.to_owned()));
+        }
+
+        fn on_finish(&self, node_type: &str, outcome: Outcome, elapsed: Duration) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(Event::Finish(node_type.to_owned(), outcome));
+            self.elapsed.lock().unwrap().push(elapsed);
+        }
+    }
+
+    #[derive(Clone)]
+    struct FailNode;
+
+    impl<C: Send> Node<u8, NodeOutput<u8>, (), C> for FailNode {
+        async fn run(&mut self, _input: u8, _context: &mut C) -> Result<NodeOutput<u8>, ()> {
+            Err(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow() {
+        let observer = Arc::new(RecordingObserver::default());
+
+        let mut flow =
+            ObservedFlow::<u8, u64, (), ()>::new(Passer::<u8, u16, ()>::new(), observer.clone());
+        assert_eq!(flow.run(5, &mut ()).await, Ok(NodeOutput::Ok(5)));
+        let mut flow = ObservedFlow::<u8, u64, (), ()>::new(
+            SoftFailNode::<u8, u16, ()>::new(),
+            observer.clone(),
+        );
+        assert_eq!(flow.run(5, &mut ()).await, Ok(NodeOutput::SoftFail));
+        let mut flow = ObservedFlow::<u8, u64, (), ()>::new(FailNode, observer.clone());
+        assert_eq!(flow.run(5, &mut ()).await, Err(()));
+
+        let passer = std::any::type_name::<Passer<u8, u16, ()>>().to_owned();
+        let soft_fail = std::any::type_name::<SoftFailNode<u8, u16, ()>>().to_owned();
+        let fail = std::any::type_name::<FailNode>().to_owned();
+        assert_eq!(
+            *observer.events.lock().unwrap(),
+            [
+                Event::Start(passer.clone()),
+                Event::Finish(passer, Outcome::Ok),
+                Event::Start(soft_fail.clone()),
+                Event::Finish(soft_fail, Outcome::SoftFail),
+                Event::Start(fail.clone()),
+                Event::Finish(fail, Outcome::Err),
+            ]
+        );
+        // `Passer` sleeps for 150ms
+        assert!(observer.elapsed.lock().unwrap()[0] >= Duration::from_millis(140));
+    }
+}
@@ -8,7 +8,12 @@ use crate::{
 };
 
 pub trait ChainPollOneOfParallel<Output, NodeContext>: Send {
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> SoftFailPoll<Output>;
+    /// Polls unfinished nodes in the order in which they were added.
+    ///
+    /// Every polled node takes one of the `slots` and nodes that soft fail give it back,
+    /// so at most `slots` unfinished nodes are started.
+    /// Nodes without a slot are not polled, which means that they are not started yet.
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>, slots: &mut usize) -> SoftFailPoll<Output>;
 }
 
 impl<Head, Tail, Output, Error, NodeContext>
@@ -24,10 +29,11 @@ where
     fn poll(
         self: Pin<&mut Self>,
         cx: &mut Context<'_>,
+        slots: &mut usize,
     ) -> SoftFailPoll<FutOutput<Output, Error, NodeContext>> {
         let (head, tail) = unsafe { self.get_unchecked_mut() };
         let head = unsafe { Pin::new_unchecked(head) };
-        let head_pending = match ChainPollOneOfParallel::poll(head, cx) {
+        let head_pending = match ChainPollOneOfParallel::poll(head, cx, slots) {
             SoftFailPoll::Pending => true,
             SoftFailPoll::Ready(res) => return SoftFailPoll::Ready(res),
             SoftFailPoll::SoftFail => false,
@@ -38,14 +44,25 @@ where
             (true, false) => return SoftFailPoll::SoftFail,
             (false, _) => {}
         }
+        if *slots == 0 {
+            // no slot left, the tail will be started when some node soft fails
+            return SoftFailPoll::Pending;
+        }
+        *slots -= 1;
         let mut tail = unsafe { Pin::new_unchecked(tail) };
         if tail.as_mut().poll(cx).is_ready() {
             match tail.take_output().unwrap() {
                 output if matches!(output, Ok((NodeOutputStruct::Ok(_), _)) | Err(_)) => {
                     SoftFailPoll::Ready(output)
                 }
-                _ if head_pending => SoftFailPoll::Pending,
-                _ => SoftFailPoll::SoftFail,
+                _ => {
+                    *slots += 1;
+                    if head_pending {
+                        SoftFailPoll::Pending
+                    } else {
+                        SoftFailPoll::SoftFail
+                    }
+                }
             }
         } else {
             SoftFailPoll::Pending
@@ -64,17 +81,25 @@ where
     fn poll(
         self: Pin<&mut Self>,
         cx: &mut Context<'_>,
+        slots: &mut usize,
     ) -> SoftFailPoll<FutOutput<Output, Error, NodeContext>> {
         if matches!(self.0, MaybeDone::Gone) {
             return SoftFailPoll::SoftFail;
         }
+        if *slots == 0 {
+            return SoftFailPoll::Pending;
+        }
+        *slots -= 1;
         let mut head = unsafe { Pin::new_unchecked(&mut self.get_unchecked_mut().0) };
         if head.as_mut().poll(cx).is_ready() {
             match head.take_output().unwrap() {
                 output if matches!(output, Ok((NodeOutputStruct::Ok(_), _)) | Err(_)) => {
                     SoftFailPoll::Ready(output)
                 }
-                _ => SoftFailPoll::SoftFail,
+                _ => {
+                    *slots += 1;
+                    SoftFailPoll::SoftFail
+                }
             }
         } else {
             SoftFailPoll::Pending
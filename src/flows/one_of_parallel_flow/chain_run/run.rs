@@ -14,7 +14,16 @@ use crate::{
 };
 
 pub trait ChainRunOneOfParallel<Input, Output, Context, T> {
-    fn run(&self, input: Input, context: &mut Context) -> impl Future<Output = Output> + Send;
+    fn run(&self, input: Input, context: &mut Context) -> impl Future<Output = Output> + Send {
+        self.run_throttled(input, context, usize::MAX)
+    }
+
+    fn run_throttled(
+        &self,
+        input: Input,
+        context: &mut Context,
+        max_in_flight: usize,
+    ) -> impl Future<Output = Output> + Send;
 }
 
 impl<Input, Output, Error, Context, T, U>
@@ -25,11 +34,17 @@ where
     Input: Send,
     Context: Fork + Update + Send,
 {
-    async fn run(&self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+    async fn run_throttled(
+        &self,
+        input: Input,
+        context: &mut Context,
+        max_in_flight: usize,
+    ) -> NodeResult<Output, Error> {
         let fut_chain = self.run(input, context.fork());
         let mut fut_chain = pin!(fut_chain);
-        poll_fn(
-            move |cx| match ChainPollOneOfParallel::poll(fut_chain.as_mut(), cx) {
+        poll_fn(move |cx| {
+            let mut slots = max_in_flight;
+            match ChainPollOneOfParallel::poll(fut_chain.as_mut(), cx, &mut slots) {
                 SoftFailPoll::Pending => Poll::Pending,
                 SoftFailPoll::Ready(res) => {
                     let res = res.map(|(res, new_context)| {
@@ -39,8 +54,8 @@ where
                     Poll::Ready(res)
                 }
                 SoftFailPoll::SoftFail => Poll::Ready(Ok(NodeOutputStruct::SoftFail)),
-            },
-        )
+            }
+        })
         .await
     }
 }
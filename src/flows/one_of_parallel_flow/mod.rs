@@ -1,9 +1,13 @@
 mod chain_run;
+mod throttled_flow;
+pub use throttled_flow::*;
 
 use crate::{
     context::{Fork, Update},
     describe::{Description, Edge, remove_generics_from_name},
-    flows::{chain_describe::ChainDescribe, generic_defs::define_flow_and_ioe_conv_builder},
+    flows::{
+        FlowNode, chain_describe::ChainDescribe, generic_defs::define_flow_and_ioe_conv_builder,
+    },
     node::NodeOutput as NodeOutputStruct,
 };
 use chain_run::ChainRunOneOfParallel as ChainRun;
@@ -27,7 +31,7 @@ define_flow_and_ioe_conv_builder!(
     >Output: Send,
     >Error: Send,
     >Context: Fork + Update + Send,
-    #NodeType: Send + Sync + Clone
+    #NodeType: FlowNode
     /// `OneOfParallelFlow` executes nodes (branches) **in parallel**, returning when one succeeds or fails.
     ///
     /// Nodes (branches) are executed concurrently.
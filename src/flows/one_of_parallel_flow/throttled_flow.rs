@@ -0,0 +1,292 @@
+use std::fmt::Debug;
+
+use super::{Builder, ChainRun};
+use crate::{
+    context::{Fork, Update},
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::{ChainLink, NodeResult, chain_debug::ChainDebug, chain_describe::ChainDescribe},
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// `ThrottledOneOfParallelFlow` executes nodes (branches) **in parallel** in waves,
+/// returning when one succeeds or fails.
+///
+/// It is created by [`Builder::build_throttled`].
+///
+/// It behaves like [`OneOfParallelFlow`](super::OneOfParallelFlow),
+/// but at most `max_in_flight` nodes (branches) are running at the same time.
+/// Nodes are started in the order in which they were added
+/// and the next node is started when some running node soft fails.
+/// - If a node returns [`NodeOutput::Ok`](crate::node::NodeOutput::Ok), that value is returned
+///   and no other nodes are started.
+/// - If a node returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail),
+///   the next node (branch) is started.
+/// - If a node returns an **error**, then that error is returned.
+///
+/// If all nodes (branches) soft-fail, the flow itself returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail).
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// For examples, see [`Builder::build_throttled`].
+pub struct ThrottledOneOfParallelFlow<
+    Input,
+    Output,
+    Error,
+    Context,
+    NodeTypes = (),
+    NodeIOETypes = (),
+> {
+    #[expect(clippy::type_complexity)]
+    _ioec: std::marker::PhantomData<fn() -> (Input, Output, Error, Context)>,
+    _nodes_io: std::marker::PhantomData<fn() -> NodeIOETypes>,
+    nodes: std::sync::Arc<NodeTypes>,
+    max_in_flight: usize,
+}
+
+impl<Input, Output, Error, Context, NodeTypes, LastNodeIOETypes, OtherNodeIOETypes>
+    Builder<
+        Input,
+        Output,
+        Error,
+        Context,
+        NodeTypes,
+        ChainLink<OtherNodeIOETypes, LastNodeIOETypes>,
+    >
+where
+    // Trait bounds for better and nicer errors
+    Input: Send + Clone,
+    Output: Send,
+    Error: Send,
+    Context: Fork + Update + Send,
+{
+    /// Finalizes the builder and produces a [`ThrottledOneOfParallelFlow`] instance,
+    /// which runs at most `max_in_flight` nodes (branches) at the same time.
+    ///
+    /// When `max_in_flight` is greater than or equal to the number of nodes,
+    /// the flow behaves the same as the one created by [`Builder::build`].
+    ///
+    /// # Panics
+    /// Panics if `max_in_flight` is `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::{Node, NodeOutput};
+    /// use node_flow::flows::OneOfParallelFlow;
+    /// use node_flow::context::{Fork, Update};
+    ///
+    /// #[derive(Clone)]
+    /// struct Mirror(u8);
+    ///
+    /// struct ExampleCtx;
+    /// impl Fork for ExampleCtx // ...
+    /// # { fn fork(&self) -> Self { Self } }
+    /// impl Update for ExampleCtx // ...
+    /// # { fn update_from(&mut self, other: Self) {} }
+    ///
+    /// impl<Ctx: Send> Node<(), NodeOutput<u8>, (), Ctx> for Mirror {
+    ///     async fn run(&mut self, _: (), _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+    ///         if self.0 == 0 {
+    ///             return Ok(NodeOutput::SoftFail); // Unavailable, try the next one
+    ///         }
+    ///         Ok(NodeOutput::Ok(self.0))
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread()
+    /// #     .enable_all()
+    /// #     .build()
+    /// #     .unwrap()
+    /// #     .block_on(async {
+    /// async fn main() {
+    ///     let mut flow = OneOfParallelFlow::<(), u8, (), _>::builder()
+    ///         .add_node(Mirror(0))
+    ///         .add_node(Mirror(1))
+    ///         .add_node(Mirror(2)) // Never started
+    ///         .build_throttled(1);
+    ///
+    ///     let mut ctx = ExampleCtx;
+    ///     let result = flow.run((), &mut ctx).await;
+    ///     assert_eq!(result, Ok(NodeOutput::Ok(1)));
+    /// }
+    /// # main().await;
+    /// # });
+    /// ```
+    pub fn build_throttled(
+        self,
+        max_in_flight: usize,
+    ) -> ThrottledOneOfParallelFlow<
+        Input,
+        Output,
+        Error,
+        Context,
+        NodeTypes,
+        ChainLink<OtherNodeIOETypes, LastNodeIOETypes>,
+    > {
+        assert!(max_in_flight > 0, "max_in_flight must be greater than 0");
+        ThrottledOneOfParallelFlow {
+            _ioec: std::marker::PhantomData,
+            _nodes_io: std::marker::PhantomData,
+            nodes: std::sync::Arc::new(self.nodes),
+            max_in_flight,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes> Clone
+    for ThrottledOneOfParallelFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: std::marker::PhantomData,
+            _nodes_io: std::marker::PhantomData,
+            nodes: self.nodes.clone(),
+            max_in_flight: self.max_in_flight,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes> Debug
+    for ThrottledOneOfParallelFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+where
+    NodeTypes: ChainDebug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ThrottledOneOfParallelFlow")
+            .field("nodes", &self.nodes.as_list())
+            .field("max_in_flight", &self.max_in_flight)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for ThrottledOneOfParallelFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+where
+    NodeTypes: ChainRun<Input, NodeResult<Output, Error>, Context, NodeIOETypes>
+        + ChainDescribe<Context, NodeIOETypes>,
+{
+    fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = NodeResult<Output, Error>> + Send {
+        self.nodes
+            .as_ref()
+            .run_throttled(input, context, self.max_in_flight)
+    }
+
+    fn describe(&self) -> Description {
+        let node_count = <NodeTypes as ChainDescribe<Context, NodeIOETypes>>::COUNT;
+        let mut node_descriptions = Vec::with_capacity(node_count);
+        self.nodes.describe(&mut node_descriptions);
+        let edges = (0..node_count)
+            .flat_map(|i| [Edge::flow_to_node(i), Edge::node_to_flow(i)])
+            .collect::<Vec<_>>();
+
+        Description::new_flow(self, node_descriptions, edges)
+            .modify_name(remove_generics_from_name)
+            .with_description(format!("Max in flight: {}", self.max_in_flight))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::{Duration, Instant},
+    };
+
+    use crate::{
+        context::storage::local_storage::LocalStorageImpl,
+        flows::OneOfParallelFlow as Flow,
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone, Default)]
+    struct InFlight {
+        started: Arc<AtomicUsize>,
+        running: Arc<AtomicUsize>,
+        max_running: Arc<AtomicUsize>,
+    }
+
+    /// Sleeps and soft fails or succeeds (when `ok` is set) while tracking the running branches.
+    #[derive(Clone)]
+    struct Branch {
+        in_flight: InFlight,
+        ok: bool,
+    }
+
+    impl<C: Send> Node<u8, NodeOutput<u8>, (), C> for Branch {
+        async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u8>, ()> {
+            let InFlight {
+                started,
+                running,
+                max_running,
+            } = &self.in_flight;
+            started.fetch_add(1, Ordering::SeqCst);
+            let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+            max_running.fetch_max(now_running, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            running.fetch_sub(1, Ordering::SeqCst);
+            if self.ok {
+                Ok(NodeOutput::Ok(input))
+            } else {
+                Ok(NodeOutput::SoftFail)
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow_throttled() {
+        let in_flight = InFlight::default();
+        let branch = |ok| Branch {
+            in_flight: in_flight.clone(),
+            ok,
+        };
+        let mut flow = Flow::<u8, u8, (), _>::builder()
+            .add_node(branch(false))
+            .add_node(branch(false))
+            .add_node(branch(false))
+            .add_node(branch(true))
+            .add_node(branch(false))
+            .add_node(branch(false))
+            .build_throttled(2);
+
+        let start = Instant::now();
+        let res = flow.run(5, &mut LocalStorageImpl::new()).await;
+        let took = start.elapsed();
+
+        assert_eq!(res, Ok(NodeOutput::Ok(5)));
+        assert_eq!(in_flight.max_running.load(Ordering::SeqCst), 2);
+        // the fourth branch succeeded in the second wave together with the third one
+        assert_eq!(in_flight.started.load(Ordering::SeqCst), 4);
+        assert!(took.as_millis() >= 95);
+    }
+
+    #[tokio::test]
+    async fn test_flow_throttled_all_soft_fail() {
+        let in_flight = InFlight::default();
+        let branch = || Branch {
+            in_flight: in_flight.clone(),
+            ok: false,
+        };
+        let mut flow = Flow::<u8, u8, (), _>::builder()
+            .add_node(branch())
+            .add_node(branch())
+            .add_node(branch())
+            .build_throttled(1);
+
+        let res = flow.run(5, &mut LocalStorageImpl::new()).await;
+
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+        assert_eq!(in_flight.max_running.load(Ordering::SeqCst), 1);
+        assert_eq!(in_flight.started.load(Ordering::SeqCst), 3);
+    }
+}
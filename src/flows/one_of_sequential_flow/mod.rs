@@ -3,7 +3,9 @@ mod chain_run;
 use crate::{
     context::{Fork, Update},
     describe::{Description, Edge, remove_generics_from_name},
-    flows::{chain_describe::ChainDescribe, generic_defs::define_flow_and_ioe_conv_builder},
+    flows::{
+        FlowNode, chain_describe::ChainDescribe, generic_defs::define_flow_and_ioe_conv_builder,
+    },
 };
 use chain_run::ChainRunOneOfSequential as ChainRun;
 
@@ -22,7 +24,7 @@ define_flow_and_ioe_conv_builder!(
     },
     >Input: Send + Clone,
     >Context: Fork + Update + Send,
-    #NodeType: Send + Sync + Clone
+    #NodeType: FlowNode
     /// `OneOfSequentialFlow` executes nodes (branches) **sequentially**, returning when one succeeds or fails.
     ///
     /// Nodes (branches) are executed sequentially in order of insertion until **one** succeeds or "hard" fails.
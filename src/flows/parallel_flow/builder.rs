@@ -1,12 +1,16 @@
 use std::{marker::PhantomData, sync::Arc};
 
-use super::ParallelFlow as Flow;
+use super::{EarlyExitParallelFlow, ParallelFlow as Flow};
 use crate::{
     context::{Fork, Join},
     flows::{
-        ChainLink, NodeIOE,
+        ChainLink, FlowNode, NodeIOE,
         generic_defs::debug::impl_debug_for_builder,
-        parallel_flow::{Joiner, chain_run::ChainRunParallel as ChainRun},
+        parallel_flow::{
+            EarlyExitJoiner, Joiner,
+            chain_run::{ChainRunEarlyExit, ChainRunParallel as ChainRun},
+            concurrency::Concurrency,
+        },
     },
     node::{Node, NodeOutput as NodeOutputStruct},
 };
@@ -30,6 +34,7 @@ where
     _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
     _nodes_io: PhantomData<fn() -> NodeIOETypes>,
     nodes: NodeTypes,
+    concurrency: Concurrency,
 }
 
 impl_debug_for_builder!(
@@ -66,6 +71,7 @@ where
             _ioec: PhantomData,
             _nodes_io: PhantomData,
             nodes: (),
+            concurrency: Concurrency::UNBOUNDED,
         }
     }
 
@@ -95,15 +101,52 @@ where
         NodeError: Into<Error>,
         NodeType: Node<NodeInput, NodeOutputStruct<NodeOutput>, NodeError, Context>,
         // Trait bounds for better and nicer errors
-        NodeType: Send + Sync + Clone,
+        NodeType: FlowNode,
         NodeOutput: Send,
     {
+        let mut concurrency = self.concurrency;
+        concurrency.add_node();
         Builder {
             _ioec: PhantomData,
             _nodes_io: PhantomData,
             nodes: (node,),
+            concurrency,
         }
     }
+
+    /// Adds a new node into a concurrency `group`.
+    ///
+    /// The same as [`Builder::add_node`],
+    /// but the number of nodes from `group` running at the same time
+    /// can be limited using [`Builder::with_group_limit`].
+    ///
+    /// # Returns
+    /// A new [`Builder`] with the added node.
+    #[expect(clippy::type_complexity, clippy::type_repetition_in_bounds)]
+    pub fn add_node_in_group<NodeType, NodeInput, NodeOutput, NodeError>(
+        self,
+        node: NodeType,
+        group: &'static str,
+    ) -> Builder<
+        Input,
+        Output,
+        Error,
+        Context,
+        (NodeType,),
+        ChainLink<(), NodeIOE<NodeInput, NodeOutput, NodeError>>,
+    >
+    where
+        Input: Into<NodeInput>,
+        NodeError: Into<Error>,
+        NodeType: Node<NodeInput, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeType: FlowNode,
+        NodeOutput: Send,
+    {
+        let mut builder = self.add_node(node);
+        builder.concurrency.set_last_node_group(group);
+        builder
+    }
 }
 
 impl<Input, Output, Error, Context, NodeTypes, OtherNodeIOETypes, LastNodeIOETypes>
@@ -150,16 +193,56 @@ where
         NodeError: Into<Error>,
         NodeType: Node<NodeInput, NodeOutputStruct<NodeOutput>, NodeError, Context>,
         // Trait bounds for better and nicer errors
-        NodeType: Send + Sync + Clone,
+        NodeType: FlowNode,
         NodeOutput: Send,
     {
+        let mut concurrency = self.concurrency;
+        concurrency.add_node();
         Builder {
             _ioec: PhantomData,
             _nodes_io: PhantomData,
             nodes: (self.nodes, node),
+            concurrency,
         }
     }
 
+    /// Adds a new node into a concurrency `group`.
+    ///
+    /// The same as [`Builder::add_node`],
+    /// but the number of nodes from `group` running at the same time
+    /// can be limited using [`Builder::with_group_limit`].
+    ///
+    /// # Returns
+    /// A new [`Builder`] with the added node.
+    #[expect(clippy::type_complexity, clippy::type_repetition_in_bounds)]
+    pub fn add_node_in_group<NodeType, NodeInput, NodeOutput, NodeError>(
+        self,
+        node: NodeType,
+        group: &'static str,
+    ) -> Builder<
+        Input,
+        Output,
+        Error,
+        Context,
+        ChainLink<NodeTypes, NodeType>,
+        ChainLink<
+            ChainLink<OtherNodeIOETypes, LastNodeIOETypes>,
+            NodeIOE<NodeInput, NodeOutput, NodeError>,
+        >,
+    >
+    where
+        Input: Into<NodeInput>,
+        NodeError: Into<Error>,
+        NodeType: Node<NodeInput, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeType: FlowNode,
+        NodeOutput: Send,
+    {
+        let mut builder = self.add_node(node);
+        builder.concurrency.set_last_node_group(group);
+        builder
+    }
+
     /// Finalizes the builder and produces a [`ParallelFlow`](Flow) instance.
     ///
     /// The joiner must satisfy:
@@ -227,6 +310,229 @@ where
             nodes: Arc::new(self.nodes),
             _joiner_input: PhantomData,
             joiner,
+            deadline: (),
+            concurrency: Arc::new(self.concurrency),
         }
     }
+
+    /// Finalizes the builder and produces a [`ParallelFlow`](Flow) instance
+    /// that runs at most `max_concurrency` nodes (branches) at the same time.
+    ///
+    /// Nodes are started in the order in which they were added,
+    /// the next node is started when some running node finishes.
+    /// If a node returns an **error**, no other nodes are started.
+    /// When `max_concurrency` is greater than or equal to the number of nodes,
+    /// the flow behaves the same as the one created by [`Builder::build`].
+    ///
+    /// The joiner and the outputs passed to it are the same as in [`Builder::build`].
+    ///
+    /// # Panics
+    /// Panics if `max_concurrency` is `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use node_flow::node::{Node, NodeOutput};
+    /// # use node_flow::flows::ParallelFlow;
+    /// # use node_flow::context::{Fork, Join};
+    /// # #[derive(Clone)]
+    /// # struct A;
+    /// # struct Context;
+    /// # impl Fork for Context { fn fork(&self) -> Self { Self } }
+    /// # impl Join for Context { fn join(&mut self, others: Box<[Self]>) {} }
+    /// # impl<Ctx: Send> Node<(), NodeOutput<i32>, (), Ctx> for A {
+    /// #     async fn run(&mut self, _: (), _: &mut Ctx) -> Result<NodeOutput<i32>, ()> { todo!() }
+    /// # }
+    /// # let flow = ParallelFlow::<(), i32, (), Context>::builder()
+    /// #     .add_node(A)
+    /// #     .add_node(A)
+    /// #     .add_node(A)
+    /// // ...
+    /// .build_bounded(async |_, _: &mut _| {
+    ///     Ok(NodeOutput::Ok(120))
+    /// }, 2);
+    /// ```
+    pub fn build_bounded<J, ChainRunOutput>(
+        self,
+        joiner: J,
+        max_concurrency: usize,
+    ) -> Flow<
+        Input,
+        Output,
+        Error,
+        Context,
+        ChainRunOutput,
+        J,
+        NodeTypes,
+        ChainLink<OtherNodeIOETypes, LastNodeIOETypes>,
+    >
+    where
+        for<'a> J: Joiner<'a, ChainRunOutput, Output, Error, Context>,
+        NodeTypes: ChainRun<
+                Input,
+                Result<ChainRunOutput, Error>,
+                Context,
+                ChainLink<OtherNodeIOETypes, LastNodeIOETypes>,
+            >,
+    {
+        assert!(
+            max_concurrency > 0,
+            "max_concurrency must be greater than 0"
+        );
+        let mut builder = self;
+        builder.concurrency.max = max_concurrency;
+        builder.build(joiner)
+    }
+
+    /// Finalizes the builder and produces an [`EarlyExitParallelFlow`] instance
+    /// that passes outputs of nodes (branches) into the `joiner` as soon as they finish.
+    ///
+    /// When [`EarlyExitJoiner::on_output`] returns `Some`, the flow returns that result right away
+    /// and the nodes (branches) that are still running are canceled (dropped).
+    /// Contexts of the canceled nodes (branches) are not joined back.
+    /// Otherwise [`EarlyExitJoiner::finish`] is called once all nodes finish.
+    ///
+    /// Output of every node must be convertible into `Item`.
+    /// If a node returns an **error**, that error is returned and the joiner is not called anymore.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::{Node, NodeOutput};
+    /// use node_flow::flows::{ParallelFlow, parallel_flow::EarlyExitJoiner};
+    /// use node_flow::context::{Fork, Join};
+    ///
+    /// #[derive(Clone)]
+    /// struct Fetch(u8);
+    /// impl<Ctx: Send> Node<(), NodeOutput<u8>, (), Ctx> for Fetch {
+    ///     async fn run(&mut self, _: (), _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+    ///         Ok(NodeOutput::Ok(self.0))
+    ///     }
+    /// }
+    ///
+    /// /// Finishes with the sum of the first `n` successful outputs.
+    /// struct SumFirst(usize);
+    /// impl EarlyExitJoiner<u8, u16, ()> for SumFirst {
+    ///     fn on_output(&self, outputs: &mut Vec<NodeOutput<u8>>) -> Option<Result<NodeOutput<u16>, ()>> {
+    ///         outputs.retain(NodeOutput::is_ok);
+    ///         (outputs.len() == self.0).then(|| self.finish(std::mem::take(outputs)))
+    ///     }
+    ///
+    ///     fn finish(&self, outputs: Vec<NodeOutput<u8>>) -> Result<NodeOutput<u16>, ()> {
+    ///         Ok(NodeOutput::Ok(outputs.into_iter().filter_map(NodeOutput::ok).map(u16::from).sum()))
+    ///     }
+    /// }
+    ///
+    /// struct ExampleCtx;
+    /// impl Fork for ExampleCtx // ...
+    /// # { fn fork(&self) -> Self { Self } }
+    /// impl Join for ExampleCtx // ...
+    /// # { fn join(&mut self, others: Box<[Self]>) {} }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread()
+    /// #     .enable_all()
+    /// #     .build()
+    /// #     .unwrap()
+    /// #     .block_on(async {
+    /// async fn main() {
+    ///     let mut flow = ParallelFlow::<(), u16, (), ExampleCtx>::builder()
+    ///         .add_node(Fetch(1))
+    ///         .add_node(Fetch(2))
+    ///         .add_node(Fetch(3))
+    ///         .build_with_early_exit(SumFirst(2));
+    ///
+    ///     let result = flow.run((), &mut ExampleCtx).await;
+    ///     assert_eq!(result, Ok(NodeOutput::Ok(3)));
+    /// }
+    /// # main().await;
+    /// # });
+    /// ```
+    pub fn build_with_early_exit<J, Item>(
+        self,
+        joiner: J,
+    ) -> EarlyExitParallelFlow<
+        Input,
+        Output,
+        Error,
+        Context,
+        Item,
+        J,
+        NodeTypes,
+        ChainLink<OtherNodeIOETypes, LastNodeIOETypes>,
+    >
+    where
+        J: EarlyExitJoiner<Item, Output, Error>,
+        NodeTypes: ChainRunEarlyExit<
+                Input,
+                Item,
+                Output,
+                Error,
+                Context,
+                ChainLink<OtherNodeIOETypes, LastNodeIOETypes>,
+            >,
+    {
+        EarlyExitParallelFlow {
+            _ioec: PhantomData,
+            _nodes_io: PhantomData,
+            nodes: Arc::new(self.nodes),
+            _item: PhantomData,
+            joiner,
+            deadline: (),
+            concurrency: Arc::new(self.concurrency),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+    Builder<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+where
+    // Trait bounds for better and nicer errors
+    Input: Send + Clone,
+    Error: Send,
+    Context: Fork + Join + Send,
+{
+    /// Limits the number of nodes from concurrency `group` running at the same time to `max`.
+    ///
+    /// Nodes are put into groups using [`Builder::add_node_in_group`].
+    /// The group limit is enforced alongside the global limit set by [`Builder::build_bounded`],
+    /// so a node is started only when both limits allow it.
+    /// Groups without a limit are not limited.
+    ///
+    /// # Panics
+    /// Panics if `max` is `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use node_flow::node::{Node, NodeOutput};
+    /// # use node_flow::flows::ParallelFlow;
+    /// # use node_flow::context::{Fork, Join};
+    /// # #[derive(Clone)]
+    /// # struct Query;
+    /// # #[derive(Clone)]
+    /// # struct Fetch;
+    /// # struct Context;
+    /// # impl Fork for Context { fn fork(&self) -> Self { Self } }
+    /// # impl Join for Context { fn join(&mut self, others: Box<[Self]>) {} }
+    /// # impl<Ctx: Send> Node<(), NodeOutput<i32>, (), Ctx> for Query {
+    /// #     async fn run(&mut self, _: (), _: &mut Ctx) -> Result<NodeOutput<i32>, ()> { todo!() }
+    /// # }
+    /// # impl<Ctx: Send> Node<(), NodeOutput<i32>, (), Ctx> for Fetch {
+    /// #     async fn run(&mut self, _: (), _: &mut Ctx) -> Result<NodeOutput<i32>, ()> { todo!() }
+    /// # }
+    /// let flow = ParallelFlow::<(), i32, (), Context>::builder()
+    ///     .add_node_in_group(Query, "db")
+    ///     .add_node_in_group(Query, "db")
+    ///     .add_node_in_group(Query, "db")
+    ///     .add_node_in_group(Fetch, "http")
+    ///     .add_node_in_group(Fetch, "http")
+    ///     .with_group_limit("db", 2)
+    ///     .with_group_limit("http", 1)
+    ///     .build_bounded(async |_, _: &mut _| {
+    ///         Ok(NodeOutput::Ok(120))
+    ///     }, 3);
+    /// ```
+    #[must_use]
+    pub fn with_group_limit(mut self, group: &'static str, max: usize) -> Self {
+        assert!(max > 0, "max must be greater than 0");
+        self.concurrency.set_group_limit(group, max);
+        self
+    }
 }
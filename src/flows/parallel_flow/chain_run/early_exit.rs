@@ -0,0 +1,294 @@
+use std::{
+    future::poll_fn,
+    pin::{Pin, pin},
+    task::{Context, Poll},
+};
+
+use futures_util::future::MaybeDone;
+
+use super::poll::{PollLimits, poll_node};
+use crate::{
+    context::{Fork, Join},
+    flows::{
+        ChainLink, NodeIOE, NodeResult,
+        parallel_flow::{EarlyExitJoiner, concurrency::Concurrency},
+    },
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+pub trait ChainPollEarlyExit<Item, Error, NodeContext>: Send {
+    /// Polls unfinished nodes in the order in which they were added.
+    ///
+    /// Returns `true` if all nodes are done or the limits expired.
+    fn drive(self: Pin<&mut Self>, cx: &mut Context<'_>, limits: &mut PollLimits<'_>) -> bool;
+
+    /// Takes outputs of finished nodes, which were not taken yet.
+    ///
+    /// Returns an error if some finished node returned an error.
+    fn take_finished(
+        self: Pin<&mut Self>,
+        outputs: &mut Vec<NodeOutputStruct<Item>>,
+        context_acc: &mut Vec<NodeContext>,
+    ) -> Result<(), Error>;
+}
+
+/// Takes output of a node if it is done.
+fn take_finished_node<Fut, Item, NodeContext, Error>(
+    mut node: Pin<&mut MaybeDone<Fut>>,
+    outputs: &mut Vec<NodeOutputStruct<Item>>,
+    context_acc: &mut Vec<NodeContext>,
+) -> Result<(), Error>
+where
+    Fut: Future<Output = Result<(NodeOutputStruct<Item>, NodeContext), Error>>,
+{
+    if !matches!(node.as_ref().get_ref(), MaybeDone::Done(_)) {
+        return Ok(());
+    }
+    let (output, node_context) = node.as_mut().take_output().unwrap()?;
+    outputs.push(output);
+    context_acc.push(node_context);
+    Ok(())
+}
+
+impl<Head, Tail, Item, Error, NodeContext> ChainPollEarlyExit<Item, Error, NodeContext>
+    for (Head, MaybeDone<Tail>)
+where
+    Head: ChainPollEarlyExit<Item, Error, NodeContext>,
+    Tail: Future<Output = Result<(NodeOutputStruct<Item>, NodeContext), Error>> + Send,
+    Item: Send,
+    Error: Send,
+    NodeContext: Send,
+{
+    fn drive(self: Pin<&mut Self>, cx: &mut Context<'_>, limits: &mut PollLimits<'_>) -> bool {
+        let (head, tail) = unsafe { self.get_unchecked_mut() };
+        let (head, tail) = unsafe { (Pin::new_unchecked(head), Pin::new_unchecked(tail)) };
+        let head_ready = head.drive(cx, limits);
+        poll_node(tail, cx, limits) && head_ready
+    }
+
+    fn take_finished(
+        self: Pin<&mut Self>,
+        outputs: &mut Vec<NodeOutputStruct<Item>>,
+        context_acc: &mut Vec<NodeContext>,
+    ) -> Result<(), Error> {
+        let (head, tail) = unsafe { self.get_unchecked_mut() };
+        let (head, tail) = unsafe { (Pin::new_unchecked(head), Pin::new_unchecked(tail)) };
+        head.take_finished(outputs, context_acc)?;
+        take_finished_node(tail, outputs, context_acc)
+    }
+}
+
+impl<Head, Item, Error, NodeContext> ChainPollEarlyExit<Item, Error, NodeContext>
+    for (MaybeDone<Head>,)
+where
+    Head: Future<Output = Result<(NodeOutputStruct<Item>, NodeContext), Error>> + Send,
+    Item: Send,
+    Error: Send,
+    NodeContext: Send,
+{
+    fn drive(self: Pin<&mut Self>, cx: &mut Context<'_>, limits: &mut PollLimits<'_>) -> bool {
+        let head = unsafe { Pin::new_unchecked(&mut self.get_unchecked_mut().0) };
+        poll_node(head, cx, limits)
+    }
+
+    fn take_finished(
+        self: Pin<&mut Self>,
+        outputs: &mut Vec<NodeOutputStruct<Item>>,
+        context_acc: &mut Vec<NodeContext>,
+    ) -> Result<(), Error> {
+        let head = unsafe { Pin::new_unchecked(&mut self.get_unchecked_mut().0) };
+        take_finished_node(head, outputs, context_acc)
+    }
+}
+
+pub trait ChainSpawnEarlyExit<Input, Item, Error, Context, T> {
+    const NUM_FUTURES: usize;
+
+    fn spawn(
+        &self,
+        input: Input,
+        context: Context,
+    ) -> impl ChainPollEarlyExit<Item, Error, Context>;
+}
+
+/// Runs the node and converts its output into `Item`.
+async fn run_node<NodeType, NodeInput, NodeOutput, NodeError, Item, Error, Context>(
+    mut node: NodeType,
+    input: NodeInput,
+    mut context: Context,
+) -> Result<(NodeOutputStruct<Item>, Context), Error>
+where
+    NodeType: Node<NodeInput, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+    NodeOutput: Into<Item>,
+    NodeError: Into<Error>,
+{
+    let output = match node.run(input, &mut context).await.map_err(Into::into)? {
+        NodeOutputStruct::Ok(output) => NodeOutputStruct::Ok(output.into()),
+        NodeOutputStruct::SoftFail => NodeOutputStruct::SoftFail,
+    };
+    Ok((output, context))
+}
+
+impl<
+    Input,
+    Item,
+    Error,
+    Context,
+    HeadIOETypes,
+    TailNodeInType,
+    TailNodeOutType,
+    TailNodeErrType,
+    Head,
+    Tail,
+>
+    ChainSpawnEarlyExit<
+        Input,
+        Item,
+        Error,
+        Context,
+        ChainLink<HeadIOETypes, NodeIOE<TailNodeInType, TailNodeOutType, TailNodeErrType>>,
+    > for (Head, Tail)
+where
+    Head: ChainSpawnEarlyExit<Input, Item, Error, Context, HeadIOETypes> + Sync,
+    Tail: Node<TailNodeInType, NodeOutputStruct<TailNodeOutType>, TailNodeErrType, Context>
+        + Clone
+        + Send
+        + Sync,
+    TailNodeOutType: Into<Item>,
+    TailNodeErrType: Into<Error>,
+    Input: Into<TailNodeInType> + Clone + Send,
+    TailNodeInType: Send,
+    Item: Send,
+    Error: Send,
+    Context: Fork + Send,
+{
+    const NUM_FUTURES: usize = Head::NUM_FUTURES + 1;
+
+    fn spawn(
+        &self,
+        input: Input,
+        context: Context,
+    ) -> impl ChainPollEarlyExit<Item, Error, Context> {
+        let (head, tail) = self;
+        let new_context = context.fork();
+
+        let head_res = head.spawn(input.clone(), context);
+        let tail_fut = run_node(tail.clone(), input.into(), new_context);
+        (head_res, MaybeDone::Future(tail_fut))
+    }
+}
+
+impl<Input, Item, Error, Context, HeadNodeInType, HeadNodeOutType, HeadNodeErrType, Head>
+    ChainSpawnEarlyExit<
+        Input,
+        Item,
+        Error,
+        Context,
+        ChainLink<(), NodeIOE<HeadNodeInType, HeadNodeOutType, HeadNodeErrType>>,
+    > for (Head,)
+where
+    Input: Into<HeadNodeInType> + Send,
+    Head: Node<HeadNodeInType, NodeOutputStruct<HeadNodeOutType>, HeadNodeErrType, Context>
+        + Clone
+        + Send
+        + Sync,
+    HeadNodeOutType: Into<Item>,
+    HeadNodeErrType: Into<Error>,
+    HeadNodeInType: Send,
+    Item: Send,
+    Error: Send,
+    Context: Send,
+{
+    const NUM_FUTURES: usize = 1;
+
+    fn spawn(
+        &self,
+        input: Input,
+        context: Context,
+    ) -> impl ChainPollEarlyExit<Item, Error, Context> {
+        (MaybeDone::Future(run_node(
+            self.0.clone(),
+            input.into(),
+            context,
+        )),)
+    }
+}
+
+pub trait ChainRunEarlyExit<Input, Item, Output, Error, Context, T> {
+    fn run_early_exit<J>(
+        &self,
+        input: Input,
+        context: &mut Context,
+        deadline: impl Future<Output = ()> + Send,
+        concurrency: &Concurrency,
+        joiner: &J,
+    ) -> impl Future<Output = NodeResult<Output, Error>> + Send
+    where
+        J: EarlyExitJoiner<Item, Output, Error>;
+}
+
+impl<Input, Item, Output, Error, Context, T, U>
+    ChainRunEarlyExit<Input, Item, Output, Error, Context, T> for U
+where
+    U: ChainSpawnEarlyExit<Input, Item, Error, Context, T> + Sync,
+    Input: Send,
+    Item: Send,
+    Output: Send,
+    Error: Send,
+    Context: Fork + Join + Send,
+{
+    async fn run_early_exit<J>(
+        &self,
+        input: Input,
+        context: &mut Context,
+        deadline: impl Future<Output = ()> + Send,
+        concurrency: &Concurrency,
+        joiner: &J,
+    ) -> NodeResult<Output, Error>
+    where
+        J: EarlyExitJoiner<Item, Output, Error>,
+    {
+        let mut context_acc = Vec::with_capacity(U::NUM_FUTURES);
+        let mut outputs = Vec::with_capacity(U::NUM_FUTURES);
+        let res = {
+            let fut_chain = self.spawn(input, context.fork());
+            let mut fut_chain = pin!(fut_chain);
+            let mut deadline = pin!(deadline);
+            let mut limits = PollLimits::new(concurrency);
+            let mut finished = Vec::with_capacity(U::NUM_FUTURES);
+            poll_fn(|cx| {
+                limits.expired = limits.expired || deadline.as_mut().poll(cx).is_ready();
+                loop {
+                    limits.reset();
+                    let done = fut_chain.as_mut().drive(cx, &mut limits);
+                    if let Err(err) = fut_chain
+                        .as_mut()
+                        .take_finished(&mut finished, &mut context_acc)
+                    {
+                        return Poll::Ready(Err(err));
+                    }
+                    for output in std::mem::take(&mut finished) {
+                        outputs.push(output);
+                        if let Some(res) = joiner.on_output(&mut outputs) {
+                            return Poll::Ready(Ok(Some(res)));
+                        }
+                    }
+                    if done {
+                        return Poll::Ready(Ok(None));
+                    }
+                    if !limits.should_repoll() {
+                        return Poll::Pending;
+                    }
+                }
+            })
+            .await
+            // unfinished nodes are canceled here
+        };
+        context.join(context_acc.into_boxed_slice());
+        match res {
+            Ok(Some(res)) => res,
+            Ok(None) => joiner.finish(outputs),
+            Err(err) => Err(err),
+        }
+    }
+}
@@ -1,5 +1,7 @@
+mod early_exit;
 mod poll;
 mod run;
 mod spawn;
 
+pub use early_exit::ChainRunEarlyExit;
 pub use run::ChainRunParallel;
@@ -5,76 +5,216 @@ use std::{
 
 use futures_util::future::MaybeDone;
 
+use crate::{flows::parallel_flow::concurrency::Concurrency, node::NodeOutput as NodeOutputStruct};
+
+#[derive(Debug)]
+pub struct PollLimits<'a> {
+    concurrency: &'a Concurrency,
+    /// Unfinished nodes are treated as canceled.
+    pub expired: bool,
+    /// How many unfinished nodes can still be polled in this round.
+    pub slots: usize,
+    /// How many unfinished nodes of each group can still be polled in this round.
+    group_slots: Vec<usize>,
+    /// Index of the node which is polled next.
+    node_index: usize,
+    /// Some unfinished node was not polled, because there were no slots left.
+    pub skipped: bool,
+    /// Some node finished in this round.
+    pub finished: bool,
+}
+
+impl<'a> PollLimits<'a> {
+    pub const fn new(concurrency: &'a Concurrency) -> Self {
+        Self {
+            concurrency,
+            expired: false,
+            slots: usize::MAX,
+            group_slots: Vec::new(),
+            node_index: 0,
+            skipped: false,
+            finished: false,
+        }
+    }
+
+    /// Resets limits for a new poll round.
+    pub fn reset(&mut self) {
+        self.slots = self.concurrency.max;
+        self.group_slots.clear();
+        self.group_slots
+            .extend(self.concurrency.groups.iter().map(|(_, max)| *max));
+        self.node_index = 0;
+        self.skipped = false;
+        self.finished = false;
+    }
+
+    /// Returns the group of the node which is polled next.
+    fn next_node_group(&mut self) -> Option<usize> {
+        let group = self
+            .concurrency
+            .node_groups
+            .get(self.node_index)
+            .copied()
+            .flatten();
+        self.node_index += 1;
+        group
+    }
+
+    /// Takes a slot for a node from `group`.
+    ///
+    /// Returns `false` if there is no slot left.
+    fn take_slot(&mut self, group: Option<usize>) -> bool {
+        let group_slots = group.map(|group| &mut self.group_slots[group]);
+        if self.slots == 0 || group_slots.as_ref().is_some_and(|slots| **slots == 0) {
+            return false;
+        }
+        self.slots -= 1;
+        if let Some(group_slots) = group_slots {
+            *group_slots -= 1;
+        }
+        true
+    }
+
+    /// Returns `true` if the chain should be polled again right away,
+    /// because some node finished and freed its slot for a node that was skipped.
+    pub const fn should_repoll(&self) -> bool {
+        self.skipped && self.finished
+    }
+}
+
+/// Polls a node if there is a slot left for it.
+///
+/// Returns `true` if the node is done or the limits expired.
+pub fn poll_node<Fut, Output, Error>(
+    mut node: Pin<&mut MaybeDone<Fut>>,
+    cx: &mut Context<'_>,
+    limits: &mut PollLimits<'_>,
+) -> bool
+where
+    Fut: Future<Output = Result<Output, Error>>,
+{
+    let group = limits.next_node_group();
+    let done = match node.as_ref().get_ref() {
+        MaybeDone::Future(_) if !limits.take_slot(group) => {
+            limits.skipped = true;
+            false
+        }
+        MaybeDone::Future(_) => {
+            let done = node.as_mut().poll(cx).is_ready();
+            limits.finished |= done;
+            done
+        }
+        MaybeDone::Done(_) | MaybeDone::Gone => true,
+    };
+    if done && !limits.expired && matches!(node.output_mut(), Some(Err(_))) {
+        // node hard failed, cancel unfinished nodes
+        limits.expired = true;
+        cx.waker().wake_by_ref();
+    }
+    done || limits.expired
+}
+
 pub trait ChainPollParallel<Output, NodeContext>: Send {
+    /// Polls unfinished nodes in the order in which they were added.
+    ///
+    /// Returns `true` if all nodes are done or the limits expired.
+    fn drive(self: Pin<&mut Self>, cx: &mut Context<'_>, limits: &mut PollLimits<'_>) -> bool;
+
+    /// Takes outputs of all nodes.
+    ///
+    /// Should be called only after [`ChainPollParallel::drive`] returned `true`.
+    fn collect(self: Pin<&mut Self>, context_acc: &mut Vec<NodeContext>) -> Output;
+
     fn poll(
-        self: Pin<&mut Self>,
+        mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
-        tail_ready: bool,
+        limits: &mut PollLimits<'_>,
         context_acc: &mut Vec<NodeContext>,
-    ) -> Poll<Output>;
+    ) -> Poll<Output> {
+        if self.as_mut().drive(cx, limits) {
+            Poll::Ready(self.collect(context_acc))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Takes output of a node, unfinished node is canceled.
+fn take_node_output<Fut, Output, NodeContext, Error>(
+    mut node: Pin<&mut MaybeDone<Fut>>,
+    context_acc: &mut Vec<NodeContext>,
+) -> Result<NodeOutputStruct<Output>, Error>
+where
+    Fut: Future<Output = Result<(NodeOutputStruct<Output>, NodeContext), Error>>,
+{
+    match node.as_mut().take_output() {
+        Some(Ok((output, node_context))) => {
+            context_acc.push(node_context);
+            Ok(output)
+        }
+        Some(Err(e)) => Err(e),
+        // limits expired, cancel the unfinished node
+        None => {
+            node.set(MaybeDone::Gone);
+            Ok(NodeOutputStruct::SoftFail)
+        }
+    }
 }
 
 impl<Head, Tail, HeadOutput, TailOutput, Error, NodeContext>
-    ChainPollParallel<Result<(HeadOutput, TailOutput), Error>, NodeContext>
+    ChainPollParallel<Result<(HeadOutput, NodeOutputStruct<TailOutput>), Error>, NodeContext>
     for (Head, MaybeDone<Tail>)
 where
     TailOutput: Send,
     Error: Send,
     NodeContext: Send,
     Head: ChainPollParallel<Result<HeadOutput, Error>, NodeContext>,
-    Tail: Future<Output = Result<(TailOutput, NodeContext), Error>> + Send,
+    Tail: Future<Output = Result<(NodeOutputStruct<TailOutput>, NodeContext), Error>> + Send,
 {
-    fn poll(
+    fn drive(self: Pin<&mut Self>, cx: &mut Context<'_>, limits: &mut PollLimits<'_>) -> bool {
+        let (head, tail) = unsafe { self.get_unchecked_mut() };
+        let (head, tail) = unsafe { (Pin::new_unchecked(head), Pin::new_unchecked(tail)) };
+        let head_ready = head.drive(cx, limits);
+        poll_node(tail, cx, limits) && head_ready
+    }
+
+    fn collect(
         self: Pin<&mut Self>,
-        cx: &mut Context<'_>,
-        tail_ready: bool,
         context_acc: &mut Vec<NodeContext>,
-    ) -> Poll<Result<(HeadOutput, TailOutput), Error>> {
+    ) -> Result<(HeadOutput, NodeOutputStruct<TailOutput>), Error> {
         let (head, tail) = unsafe { self.get_unchecked_mut() };
         let (head, mut tail) = unsafe { (Pin::new_unchecked(head), Pin::new_unchecked(tail)) };
-        let tail_ready = tail.as_mut().poll(cx).is_ready() && tail_ready;
-
-        let Poll::Ready(res) = ChainPollParallel::poll(head, cx, tail_ready, context_acc) else {
-            return Poll::Pending;
+        let head_out = match head.collect(context_acc) {
+            Ok(head_out) => head_out,
+            Err(err) => {
+                // some node hard failed, cancel the node right away
+                tail.set(MaybeDone::Gone);
+                return Err(err);
+            }
         };
-        match res {
-            Ok(head_out) => match tail.take_output().unwrap() {
-                Ok((tail_out, node_context)) => {
-                    context_acc.push(node_context);
-                    Poll::Ready(Ok((head_out, tail_out)))
-                }
-                Err(e) => Poll::Ready(Err(e)),
-            },
-            Err(e) => Poll::Ready(Err(e)),
-        }
+        Ok((head_out, take_node_output(tail, context_acc)?))
     }
 }
 
 impl<Head, HeadOutput, Error, NodeContext>
-    ChainPollParallel<Result<(HeadOutput,), Error>, NodeContext> for (MaybeDone<Head>,)
+    ChainPollParallel<Result<(NodeOutputStruct<HeadOutput>,), Error>, NodeContext>
+    for (MaybeDone<Head>,)
 where
     Error: Send,
     NodeContext: Send,
     HeadOutput: Send,
-    Head: Future<Output = Result<(HeadOutput, NodeContext), Error>> + Send,
+    Head: Future<Output = Result<(NodeOutputStruct<HeadOutput>, NodeContext), Error>> + Send,
 {
-    fn poll(
+    fn drive(self: Pin<&mut Self>, cx: &mut Context<'_>, limits: &mut PollLimits<'_>) -> bool {
+        let head = unsafe { Pin::new_unchecked(&mut self.get_unchecked_mut().0) };
+        poll_node(head, cx, limits)
+    }
+
+    fn collect(
         self: Pin<&mut Self>,
-        cx: &mut Context<'_>,
-        tail_ready: bool,
         context_acc: &mut Vec<NodeContext>,
-    ) -> Poll<Result<(HeadOutput,), Error>> {
-        let mut head = unsafe { Pin::new_unchecked(&mut self.get_unchecked_mut().0) };
-        if head.as_mut().poll(cx).is_ready() && tail_ready {
-            match head.take_output().unwrap() {
-                Ok((output, node_context)) => {
-                    context_acc.push(node_context);
-                    Poll::Ready(Ok((output,)))
-                }
-                Err(e) => Poll::Ready(Err(e)),
-            }
-        } else {
-            Poll::Pending
-        }
+    ) -> Result<(NodeOutputStruct<HeadOutput>,), Error> {
+        let head = unsafe { Pin::new_unchecked(&mut self.get_unchecked_mut().0) };
+        Ok((take_node_output(head, context_acc)?,))
     }
 }
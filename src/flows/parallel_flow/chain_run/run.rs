@@ -2,11 +2,28 @@ use std::{future::poll_fn, pin::pin};
 
 use crate::{
     context::{Fork, Join},
-    flows::parallel_flow::chain_run::{poll::ChainPollParallel, spawn::ChainSpawn},
+    flows::parallel_flow::{
+        chain_run::{
+            poll::{ChainPollParallel, PollLimits},
+            spawn::ChainSpawn,
+        },
+        concurrency::Concurrency,
+    },
 };
 
 pub trait ChainRunParallel<Input, Output, Context, T> {
-    fn run(&self, input: Input, context: &mut Context) -> impl Future<Output = Output> + Send;
+    fn run(&self, input: Input, context: &mut Context) -> impl Future<Output = Output> + Send {
+        static UNBOUNDED: Concurrency = Concurrency::UNBOUNDED;
+        self.run_until(input, context, std::future::pending(), &UNBOUNDED)
+    }
+
+    fn run_until(
+        &self,
+        input: Input,
+        context: &mut Context,
+        deadline: impl Future<Output = ()> + Send,
+        concurrency: &Concurrency,
+    ) -> impl Future<Output = Output> + Send;
 }
 
 impl<Input, Output, Error, Context, T, U> ChainRunParallel<Input, Result<Output, Error>, Context, T>
@@ -16,13 +33,37 @@ where
     Input: Send,
     Context: Fork + Join + Send,
 {
-    async fn run(&self, input: Input, context: &mut Context) -> Result<Output, Error> {
-        let fut_chain = self.spawn(input, context.fork());
+    async fn run_until(
+        &self,
+        input: Input,
+        context: &mut Context,
+        deadline: impl Future<Output = ()> + Send,
+        concurrency: &Concurrency,
+    ) -> Result<Output, Error> {
         let mut context_acc = Vec::with_capacity(U::NUM_FUTURES);
-        let mut fut_chain = pin!(fut_chain);
-        let res =
-            poll_fn(|cx| ChainPollParallel::poll(fut_chain.as_mut(), cx, true, &mut context_acc))
-                .await;
+        let res = {
+            let fut_chain = self.spawn(input, context.fork());
+            let mut fut_chain = pin!(fut_chain);
+            let mut deadline = pin!(deadline);
+            let mut limits = PollLimits::new(concurrency);
+            poll_fn(|cx| {
+                limits.expired = limits.expired || deadline.as_mut().poll(cx).is_ready();
+                loop {
+                    limits.reset();
+                    let res = ChainPollParallel::poll(
+                        fut_chain.as_mut(),
+                        cx,
+                        &mut limits,
+                        &mut context_acc,
+                    );
+                    if res.is_ready() || !limits.should_repoll() {
+                        return res;
+                    }
+                }
+            })
+            .await
+            // unfinished nodes are canceled here
+        };
         context.join(context_acc.into_boxed_slice());
         res
     }
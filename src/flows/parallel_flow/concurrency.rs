@@ -0,0 +1,47 @@
+/// Limits how many nodes (branches) of a parallel flow run at the same time.
+#[derive(Debug, Clone)]
+pub struct Concurrency {
+    /// Maximum number of nodes running at the same time.
+    pub max: usize,
+    /// Names of concurrency groups and maximum number of their nodes running at the same time.
+    pub groups: Vec<(&'static str, usize)>,
+    /// Index of the group of each node, in the order in which the nodes were added.
+    pub node_groups: Vec<Option<usize>>,
+}
+
+impl Concurrency {
+    pub const UNBOUNDED: Self = Self {
+        max: usize::MAX,
+        groups: Vec::new(),
+        node_groups: Vec::new(),
+    };
+
+    fn group_index(&mut self, group: &'static str) -> usize {
+        self.groups
+            .iter()
+            .position(|(name, _)| *name == group)
+            .unwrap_or_else(|| {
+                self.groups.push((group, usize::MAX));
+                self.groups.len() - 1
+            })
+    }
+
+    /// Registers a newly added node which doesn't belong to any group.
+    pub fn add_node(&mut self) {
+        self.node_groups.push(None);
+    }
+
+    /// Moves the last added node into `group`.
+    pub fn set_last_node_group(&mut self, group: &'static str) {
+        let group = self.group_index(group);
+        if let Some(node_group) = self.node_groups.last_mut() {
+            *node_group = Some(group);
+        }
+    }
+
+    /// Sets the maximum number of nodes from `group` running at the same time.
+    pub fn set_group_limit(&mut self, group: &'static str, max: usize) {
+        let group = self.group_index(group);
+        self.groups[group].1 = max;
+    }
+}
@@ -0,0 +1,19 @@
+use std::time::Duration;
+
+use crate::context::Clock;
+
+pub trait DeadlineTimer<Context>: Send + Sync {
+    fn timer(&self) -> impl Future<Output = ()> + Send;
+}
+
+impl<Context> DeadlineTimer<Context> for () {
+    fn timer(&self) -> impl Future<Output = ()> + Send {
+        std::future::pending()
+    }
+}
+
+impl<Context: Clock> DeadlineTimer<Context> for Duration {
+    fn timer(&self) -> impl Future<Output = ()> + Send {
+        Context::sleep(*self)
+    }
+}
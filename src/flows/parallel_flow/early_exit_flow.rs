@@ -0,0 +1,360 @@
+use std::{fmt::Debug, time::Duration};
+
+use super::chain_run::ChainRunEarlyExit;
+use super::concurrency::Concurrency;
+use super::deadline::DeadlineTimer;
+use super::flow::describe_flow;
+use crate::{
+    context::Clock,
+    describe::Description,
+    flows::{
+        NodeResult, chain_debug::ChainDebug, chain_describe::ChainDescribe,
+        parallel_flow::EarlyExitJoiner,
+    },
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// `EarlyExitParallelFlow` executes nodes (branches) **in parallel** and can finish early.
+///
+/// It is created by [`Builder::build_with_early_exit`](super::Builder::build_with_early_exit).
+///
+/// Nodes (branches) are executed concurrently, like in [`ParallelFlow`](super::ParallelFlow),
+/// but the output of each node is passed into an [`EarlyExitJoiner`] as soon as the node finishes.
+/// - If the joiner decides to finish early, its result is returned
+///   and the nodes (branches) that are still running are canceled.
+/// - If a node returns an **error**, then that error is returned.
+/// - Otherwise, the joiner is asked for the final result once all nodes (branches) finish.
+///
+/// Optionally, the flow can be limited by a deadline (see [`EarlyExitParallelFlow::with_deadline`]).
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// For examples, see [`Builder::build_with_early_exit`](super::Builder::build_with_early_exit).
+pub struct EarlyExitParallelFlow<
+    Input,
+    Output,
+    Error,
+    Context,
+    Item = (),
+    Joiner = (),
+    NodeTypes = (),
+    NodeIOETypes = (),
+    Deadline = (),
+> {
+    #[expect(clippy::type_complexity)]
+    pub(super) _ioec: std::marker::PhantomData<fn() -> (Input, Output, Error, Context)>,
+    pub(super) _nodes_io: std::marker::PhantomData<fn() -> NodeIOETypes>,
+    pub(super) nodes: std::sync::Arc<NodeTypes>,
+    pub(super) _item: std::marker::PhantomData<fn() -> Item>,
+    pub(super) joiner: Joiner,
+    pub(super) deadline: Deadline,
+    pub(super) concurrency: std::sync::Arc<Concurrency>,
+}
+
+impl<Input, Output, Error, Context, Item, J, NodeTypes, NodeIOETypes>
+    EarlyExitParallelFlow<Input, Output, Error, Context, Item, J, NodeTypes, NodeIOETypes>
+where
+    Context: Clock,
+{
+    /// Limits the total wall-clock time of this flow.
+    ///
+    /// The flow waits for nodes (branches) until the `deadline` elapses (measured using the [`Clock`] context trait).
+    /// After that, nodes (branches) that have not finished are cancelled
+    /// and [`EarlyExitJoiner::finish`] is called with the outputs of the already finished nodes (branches).
+    ///
+    /// Contexts of the cancelled nodes (branches) are not joined back.
+    pub fn with_deadline(
+        self,
+        deadline: Duration,
+    ) -> EarlyExitParallelFlow<
+        Input,
+        Output,
+        Error,
+        Context,
+        Item,
+        J,
+        NodeTypes,
+        NodeIOETypes,
+        Duration,
+    > {
+        EarlyExitParallelFlow {
+            _ioec: std::marker::PhantomData,
+            _nodes_io: std::marker::PhantomData,
+            nodes: self.nodes,
+            _item: std::marker::PhantomData,
+            joiner: self.joiner,
+            deadline,
+            concurrency: self.concurrency,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, Item, J, NodeTypes, NodeIOETypes, D> Clone
+    for EarlyExitParallelFlow<Input, Output, Error, Context, Item, J, NodeTypes, NodeIOETypes, D>
+where
+    J: Clone,
+    D: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: std::marker::PhantomData,
+            _nodes_io: std::marker::PhantomData,
+            nodes: self.nodes.clone(),
+            _item: std::marker::PhantomData,
+            joiner: self.joiner.clone(),
+            deadline: self.deadline.clone(),
+            concurrency: self.concurrency.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, Item, J, NodeTypes, NodeIOETypes, D> Debug
+    for EarlyExitParallelFlow<Input, Output, Error, Context, Item, J, NodeTypes, NodeIOETypes, D>
+where
+    NodeTypes: ChainDebug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EarlyExitParallelFlow")
+            .field("nodes", &self.nodes.as_list())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, Item, J, NodeTypes, NodeIOETypes, D>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for EarlyExitParallelFlow<Input, Output, Error, Context, Item, J, NodeTypes, NodeIOETypes, D>
+where
+    Input: Send,
+    Context: Send,
+    D: DeadlineTimer<Context>,
+    J: EarlyExitJoiner<Item, Output, Error>,
+    NodeTypes: ChainRunEarlyExit<Input, Item, Output, Error, Context, NodeIOETypes>
+        + ChainDescribe<Context, NodeIOETypes>
+        + Send
+        + Sync,
+{
+    fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = NodeResult<Output, Error>> + Send {
+        self.nodes.run_early_exit(
+            input,
+            context,
+            self.deadline.timer(),
+            &self.concurrency,
+            &self.joiner,
+        )
+    }
+
+    fn describe(&self) -> Description {
+        describe_flow::<
+            _,
+            Input,
+            Output,
+            Error,
+            Context,
+            Vec<NodeOutputStruct<Item>>,
+            _,
+            NodeIOETypes,
+        >(self, self.nodes.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::{Duration, Instant},
+    };
+
+    use super::EarlyExitParallelFlow;
+    use crate::{
+        context::{
+            Clock, Fork, Join,
+            storage::local_storage::{LocalStorage, LocalStorageImpl, tests::MyVal},
+        },
+        flows::{
+            NodeResult, ParallelFlow, parallel_flow::EarlyExitJoiner,
+            tests::InsertIntoStorageAssertWasNotInStorage,
+        },
+        node::{Node, NodeOutput},
+    };
+
+    struct ClockCtx;
+
+    impl Fork for ClockCtx {
+        fn fork(&self) -> Self {
+            Self
+        }
+    }
+
+    impl Join for ClockCtx {
+        fn join(&mut self, _others: Box<[Self]>) {}
+    }
+
+    impl Clock for ClockCtx {
+        async fn sleep(duration: Duration) {
+            tokio::time::sleep(duration).await;
+        }
+    }
+
+    #[derive(Clone)]
+    struct SleepNode {
+        millis: u64,
+        output: Option<u8>,
+        finished: Arc<AtomicUsize>,
+    }
+
+    impl SleepNode {
+        fn new(millis: u64, output: Option<u8>, finished: &Arc<AtomicUsize>) -> Self {
+            Self {
+                millis,
+                output,
+                finished: finished.clone(),
+            }
+        }
+    }
+
+    impl<C: Send> Node<u8, NodeOutput<u8>, (), C> for SleepNode {
+        async fn run(&mut self, _input: u8, _context: &mut C) -> Result<NodeOutput<u8>, ()> {
+            tokio::time::sleep(Duration::from_millis(self.millis)).await;
+            self.finished.fetch_add(1, Ordering::SeqCst);
+            Ok(self.output.map_or(NodeOutput::SoftFail, NodeOutput::Ok))
+        }
+    }
+
+    #[derive(Clone)]
+    struct FailNode(u64);
+
+    impl<C: Send> Node<u8, NodeOutput<u16>, (), C> for FailNode {
+        async fn run(&mut self, _input: u8, _context: &mut C) -> Result<NodeOutput<u16>, ()> {
+            tokio::time::sleep(Duration::from_millis(self.0)).await;
+            Err(())
+        }
+    }
+
+    /// Finishes with the successful outputs once there are `0` of them.
+    struct FirstN(usize);
+
+    impl EarlyExitJoiner<u16, Vec<u16>, ()> for FirstN {
+        fn on_output(
+            &self,
+            outputs: &mut Vec<NodeOutput<u16>>,
+        ) -> Option<NodeResult<Vec<u16>, ()>> {
+            outputs.retain(NodeOutput::is_ok);
+            (outputs.len() == self.0).then(|| self.finish(std::mem::take(outputs)))
+        }
+
+        fn finish(&self, outputs: Vec<NodeOutput<u16>>) -> NodeResult<Vec<u16>, ()> {
+            Ok(NodeOutput::Ok(
+                outputs.into_iter().filter_map(NodeOutput::ok).collect(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow_early_exit() {
+        let finished = Arc::new(AtomicUsize::new(0));
+        let mut flow = ParallelFlow::<u8, Vec<u16>, (), ClockCtx>::builder()
+            .add_node(SleepNode::new(300, Some(1), &finished))
+            .add_node(SleepNode::new(30, Some(2), &finished))
+            .add_node(SleepNode::new(10, None, &finished))
+            .add_node(SleepNode::new(20, Some(4), &finished))
+            .add_node(SleepNode::new(300, Some(5), &finished))
+            .build_with_early_exit(FirstN(2));
+
+        let start = Instant::now();
+        let res = flow.run(0, &mut ClockCtx).await;
+        let took = start.elapsed();
+
+        assert_eq!(res, Ok(NodeOutput::Ok(vec![4, 2])));
+        assert!(took.as_millis() < 200);
+        assert_eq!(finished.load(Ordering::SeqCst), 3);
+
+        // remaining nodes were canceled
+        tokio::time::sleep(Duration::from_millis(350)).await;
+        assert_eq!(finished.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_flow_finish() {
+        let finished = Arc::new(AtomicUsize::new(0));
+        let mut flow = ParallelFlow::<u8, Vec<u16>, (), ClockCtx>::builder()
+            .add_node(SleepNode::new(20, Some(1), &finished))
+            .add_node(SleepNode::new(10, None, &finished))
+            .add_node(SleepNode::new(0, Some(3), &finished))
+            .build_with_early_exit(FirstN(5));
+
+        let res = flow.run(0, &mut ClockCtx).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(vec![3, 1])));
+        assert_eq!(finished.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_flow_error() {
+        let finished = Arc::new(AtomicUsize::new(0));
+        let mut flow = ParallelFlow::<u8, Vec<u16>, (), ClockCtx>::builder()
+            .add_node(SleepNode::new(0, Some(1), &finished))
+            .add_node(FailNode(10))
+            .add_node(SleepNode::new(300, Some(3), &finished))
+            .build_with_early_exit(FirstN(2));
+
+        let start = Instant::now();
+        let res = flow.run(0, &mut ClockCtx).await;
+        assert_eq!(res, Err(()));
+        assert!(start.elapsed().as_millis() < 200);
+        assert_eq!(finished.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_flow_deadline() {
+        let finished = Arc::new(AtomicUsize::new(0));
+        let mut flow = ParallelFlow::<u8, Vec<u16>, (), ClockCtx>::builder()
+            .add_node(SleepNode::new(0, Some(1), &finished))
+            .add_node(SleepNode::new(300, Some(2), &finished))
+            .build_with_early_exit(FirstN(2))
+            .with_deadline(Duration::from_millis(30));
+
+        let start = Instant::now();
+        let res = flow.run(0, &mut ClockCtx).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(vec![1])));
+        assert!(start.elapsed().as_millis() < 200);
+    }
+
+    #[tokio::test]
+    async fn test_flow_storage() {
+        struct Count;
+
+        impl EarlyExitJoiner<u16, usize, ()> for Count {
+            fn on_output(
+                &self,
+                _outputs: &mut Vec<NodeOutput<u16>>,
+            ) -> Option<NodeResult<usize, ()>> {
+                None
+            }
+
+            fn finish(&self, outputs: Vec<NodeOutput<u16>>) -> NodeResult<usize, ()> {
+                Ok(NodeOutput::Ok(outputs.len()))
+            }
+        }
+
+        let mut st = LocalStorageImpl::new();
+        let mut flow: EarlyExitParallelFlow<u8, usize, (), LocalStorageImpl, _, _, _, _> =
+            ParallelFlow::<u8, usize, (), LocalStorageImpl>::builder()
+                .add_node(InsertIntoStorageAssertWasNotInStorage::<u8, u16, (), MyVal>::new())
+                .add_node(InsertIntoStorageAssertWasNotInStorage::<u8, u8, (), MyVal>::new())
+                .build_with_early_exit(Count);
+
+        let res = flow.run(5, &mut st).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(2)));
+        assert_eq!(st.remove::<MyVal>(), Some(MyVal("||".to_owned())));
+    }
+}
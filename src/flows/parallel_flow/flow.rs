@@ -1,9 +1,11 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 use super::Builder;
 use super::chain_run::ChainRunParallel as ChainRun;
+use super::concurrency::Concurrency;
+use super::deadline::DeadlineTimer;
 use crate::{
-    context::{Fork, Join},
+    context::{Clock, Fork, Join},
     describe::{Description, DescriptionBase, Edge, Type, remove_generics_from_name},
     flows::{
         NodeResult, chain_debug::ChainDebug, chain_describe::ChainDescribe, parallel_flow::Joiner,
@@ -19,9 +21,17 @@ use crate::{
 ///   the flow continues waiting for other nodes (branches).
 /// - If a node returns an **error**, then that error is returned.
 ///
+/// When a node returns an error, nodes (branches) that are still running are canceled
+/// (their futures are dropped) before the flow returns, so they don't keep running in the background.
+/// Contexts of the canceled nodes (branches) are not joined back.
+/// Keep this in mind when nodes have side effects, a canceled node can stop at any `.await` point.
+///
 /// The output of all nodes is then passed into a [`Joiner`],
 /// which decides what should happen and what should this flow return.
 ///
+/// Optionally, the flow can be limited by a deadline (see [`ParallelFlow::with_deadline`])
+/// and the number of nodes (branches) running at the same time can be limited (see [`Builder::build_bounded`]).
+///
 /// # Type Parameters
 /// - `Input`: The type of data accepted by this flow.
 /// - `Output`: The type of data produced by this flow.
@@ -89,6 +99,7 @@ pub struct ParallelFlow<
     Joiner = (),
     NodeTypes = (),
     NodeIOETypes = (),
+    Deadline = (),
 > {
     #[expect(clippy::type_complexity)]
     pub(super) _ioec: std::marker::PhantomData<fn() -> (Input, Output, Error, Context)>,
@@ -96,6 +107,8 @@ pub struct ParallelFlow<
     pub(super) nodes: std::sync::Arc<NodeTypes>,
     pub(super) _joiner_input: std::marker::PhantomData<fn() -> ChainOutput>,
     pub(super) joiner: Joiner,
+    pub(super) deadline: Deadline,
+    pub(super) concurrency: std::sync::Arc<Concurrency>,
 }
 
 impl<Input, Output, Error, Context> ParallelFlow<Input, Output, Error, Context>
@@ -126,10 +139,109 @@ where
     }
 }
 
-impl<Input, Output, Error, Context, ChainRunOutput, J, NodeTypes, NodeIOETypes> Clone
-    for ParallelFlow<Input, Output, Error, Context, ChainRunOutput, J, NodeTypes, NodeIOETypes>
+impl<Input, Output, Error, Context, ChainRunOutput, J, NodeTypes, NodeIOETypes>
+    ParallelFlow<Input, Output, Error, Context, ChainRunOutput, J, NodeTypes, NodeIOETypes>
+where
+    Context: Clock,
+{
+    /// Limits the total wall-clock time of this flow.
+    ///
+    /// The flow waits for nodes (branches) until the `deadline` elapses (measured using the [`Clock`] context trait).
+    /// After that, nodes (branches) that have not finished are cancelled
+    /// and passed into the [`Joiner`] as [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail),
+    /// together with the outputs of the already finished nodes (branches).
+    ///
+    /// Contexts of the cancelled nodes (branches) are not joined back.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::{Node, NodeOutput};
+    /// use node_flow::flows::ParallelFlow;
+    /// use node_flow::context::{Clock, Fork, Join};
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Clone)]
+    /// struct Fast;
+    /// #[derive(Clone)]
+    /// struct Slow;
+    ///
+    /// struct ExampleCtx;
+    /// impl Fork for ExampleCtx // ...
+    /// # { fn fork(&self) -> Self { Self } }
+    /// impl Join for ExampleCtx // ...
+    /// # { fn join(&mut self, others: Box<[Self]>) {} }
+    /// impl Clock for ExampleCtx {
+    ///     async fn sleep(duration: Duration) {
+    ///         tokio::time::sleep(duration).await;
+    ///     }
+    /// }
+    ///
+    /// impl<Ctx: Send> Node<(), NodeOutput<i32>, (), Ctx> for Fast {
+    ///     async fn run(&mut self, _: (), _: &mut Ctx) -> Result<NodeOutput<i32>, ()> {
+    ///         Ok(NodeOutput::Ok(5))
+    ///     }
+    /// }
+    ///
+    /// impl<Ctx: Send> Node<(), NodeOutput<i32>, (), Ctx> for Slow {
+    ///     async fn run(&mut self, _: (), _: &mut Ctx) -> Result<NodeOutput<i32>, ()> {
+    ///         tokio::time::sleep(Duration::from_secs(10)).await;
+    ///         Ok(NodeOutput::Ok(7))
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread()
+    /// #     .enable_all()
+    /// #     .build()
+    /// #     .unwrap()
+    /// #     .block_on(async {
+    /// async fn main() {
+    ///     let mut flow = ParallelFlow::<(), i32, (), _>::builder()
+    ///         .add_node(Fast)
+    ///         .add_node(Slow)
+    ///         .build(async |input, _: &mut ExampleCtx| {
+    ///             assert_eq!(input, ((NodeOutput::Ok(5),), NodeOutput::SoftFail));
+    ///             Ok(NodeOutput::Ok(120))
+    ///         })
+    ///         .with_deadline(Duration::from_millis(10));
+    ///
+    ///     let mut ctx = ExampleCtx;
+    ///     let result = flow.run((), &mut ctx).await;
+    ///     assert_eq!(result, Ok(NodeOutput::Ok(120)));
+    /// }
+    /// # main().await;
+    /// # });
+    /// ```
+    pub fn with_deadline(
+        self,
+        deadline: Duration,
+    ) -> ParallelFlow<
+        Input,
+        Output,
+        Error,
+        Context,
+        ChainRunOutput,
+        J,
+        NodeTypes,
+        NodeIOETypes,
+        Duration,
+    > {
+        ParallelFlow {
+            _ioec: std::marker::PhantomData,
+            _nodes_io: std::marker::PhantomData,
+            nodes: self.nodes,
+            _joiner_input: std::marker::PhantomData,
+            joiner: self.joiner,
+            deadline,
+            concurrency: self.concurrency,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, ChainRunOutput, J, NodeTypes, NodeIOETypes, D> Clone
+    for ParallelFlow<Input, Output, Error, Context, ChainRunOutput, J, NodeTypes, NodeIOETypes, D>
 where
     J: Clone,
+    D: Clone,
 {
     fn clone(&self) -> Self {
         Self {
@@ -138,12 +250,14 @@ where
             nodes: self.nodes.clone(),
             _joiner_input: std::marker::PhantomData,
             joiner: self.joiner.clone(),
+            deadline: self.deadline.clone(),
+            concurrency: self.concurrency.clone(),
         }
     }
 }
 
-impl<Input, Output, Error, Context, ChainRunOutput, J, NodeTypes, NodeIOETypes> Debug
-    for ParallelFlow<Input, Output, Error, Context, ChainRunOutput, J, NodeTypes, NodeIOETypes>
+impl<Input, Output, Error, Context, ChainRunOutput, J, NodeTypes, NodeIOETypes, D> Debug
+    for ParallelFlow<Input, Output, Error, Context, ChainRunOutput, J, NodeTypes, NodeIOETypes, D>
 where
     NodeTypes: ChainDebug,
 {
@@ -168,12 +282,13 @@ where
     j.join(i, s)
 }
 
-impl<Input, Output, Error, Context, ChainRunOutput, J, NodeTypes, NodeIOETypes>
+impl<Input, Output, Error, Context, ChainRunOutput, J, NodeTypes, NodeIOETypes, D>
     Node<Input, NodeOutputStruct<Output>, Error, Context>
-    for ParallelFlow<Input, Output, Error, Context, ChainRunOutput, J, NodeTypes, NodeIOETypes>
+    for ParallelFlow<Input, Output, Error, Context, ChainRunOutput, J, NodeTypes, NodeIOETypes, D>
 where
     Input: Send,
     Context: Send,
+    D: DeadlineTimer<Context>,
     for<'a> J: Joiner<'a, ChainRunOutput, Output, Error, Context>,
     NodeTypes: ChainRun<Input, Result<ChainRunOutput, Error>, Context, NodeIOETypes>
         + ChainDescribe<Context, NodeIOETypes>
@@ -187,8 +302,10 @@ where
     ) -> impl Future<Output = NodeResult<Output, Error>> + Send {
         let nodes = self.nodes.as_ref();
         let joiner = &self.joiner;
+        let deadline = self.deadline.timer();
+        let concurrency = self.concurrency.as_ref();
         async move {
-            let fut = nodes.run(input, context);
+            let fut = nodes.run_until(input, context, deadline, concurrency);
             let res = fut.await?;
             // workaround for https://github.com/rust-lang/rust/issues/100013
             call_joiner::<J, ChainRunOutput, Output, Error, Context>(joiner, res, context).await
@@ -196,52 +313,119 @@ where
     }
 
     fn describe(&self) -> Description {
-        let node_count = <NodeTypes as ChainDescribe<Context, NodeIOETypes>>::COUNT;
-        let mut node_descriptions = Vec::with_capacity(node_count + 1);
-        self.nodes.describe(&mut node_descriptions);
-
-        node_descriptions.push(Description::Node {
-            base: DescriptionBase {
-                r#type: Type {
-                    name: "Joiner".to_owned(),
-                },
-                input: Type {
-                    name: String::new(),
-                },
-                output: Type {
-                    name: String::new(),
-                },
-                error: Type {
-                    name: String::new(),
-                },
-                context: Type {
-                    name: String::new(),
-                },
-                description: None,
-                externals: None,
-            },
-        });
+        describe_flow::<_, Input, Output, Error, Context, ChainRunOutput, _, NodeIOETypes>(
+            self,
+            self.nodes.as_ref(),
+        )
+    }
+}
 
-        let mut edges = Vec::with_capacity(node_count * 2 + 1);
-        for i in 0..node_count {
-            edges.push(Edge::flow_to_node(i));
-            edges.push(Edge::node_to_node(i, node_count));
-        }
-        edges.push(Edge::node_to_flow(node_count));
+/// Describes a parallel flow, the joiner is described as the last node.
+///
+/// The joiner takes `JoinerInput` and returns the `Output`, `Error` and `Context` of the flow.
+pub(super) fn describe_flow<
+    Flow,
+    Input,
+    Output,
+    Error,
+    Context,
+    JoinerInput,
+    NodeTypes,
+    NodeIOETypes,
+>(
+    flow: &Flow,
+    nodes: &NodeTypes,
+) -> Description
+where
+    Flow: Node<Input, NodeOutputStruct<Output>, Error, Context>,
+    NodeTypes: ChainDescribe<Context, NodeIOETypes>,
+{
+    let node_count = <NodeTypes as ChainDescribe<Context, NodeIOETypes>>::COUNT;
+    let mut node_descriptions = Vec::with_capacity(node_count + 1);
+    nodes.describe(&mut node_descriptions);
 
-        Description::new_flow(self, node_descriptions, edges).modify_name(remove_generics_from_name)
+    node_descriptions.push(Description::Node {
+        base: DescriptionBase {
+            r#type: Type {
+                name: "Joiner".to_owned(),
+            },
+            input: Type::of::<JoinerInput>(),
+            output: Type::of::<Output>(),
+            error: Type::of::<Error>(),
+            context: Type::of::<Context>(),
+            description: None,
+            externals: None,
+            detached: false,
+        },
+    });
+
+    let mut edges = Vec::with_capacity(node_count * 2 + 1);
+    for i in 0..node_count {
+        edges.push(Edge::flow_to_node(i));
+        edges.push(Edge::node_to_node(i, node_count));
     }
+    edges.push(Edge::node_to_flow(node_count));
+
+    Description::new_flow(flow, node_descriptions, edges).modify_name(remove_generics_from_name)
 }
 
 #[cfg(test)]
 mod test {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::{Duration, Instant},
+    };
+
     use super::{ChainRun, ParallelFlow as Flow};
     use crate::{
-        context::storage::local_storage::{LocalStorage, LocalStorageImpl, tests::MyVal},
+        context::{
+            Clock, Fork, Join,
+            storage::local_storage::{LocalStorage, LocalStorageImpl, tests::MyVal},
+        },
         flows::tests::{InsertIntoStorageAssertWasNotInStorage, Passer, SoftFailNode},
         node::{Node, NodeOutput},
     };
 
+    struct ClockCtx;
+
+    impl Fork for ClockCtx {
+        fn fork(&self) -> Self {
+            Self
+        }
+    }
+
+    impl Join for ClockCtx {
+        fn join(&mut self, _others: Box<[Self]>) {}
+    }
+
+    impl Clock for ClockCtx {
+        async fn sleep(duration: Duration) {
+            tokio::time::sleep(duration).await;
+        }
+    }
+
+    #[derive(Clone)]
+    struct SleepNode(u64);
+
+    impl<C: Send> Node<u8, NodeOutput<u32>, (), C> for SleepNode {
+        async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u32>, ()> {
+            tokio::time::sleep(Duration::from_millis(self.0)).await;
+            Ok(NodeOutput::Ok(input.into()))
+        }
+    }
+
+    #[derive(Clone)]
+    struct FailNode;
+
+    impl<C: Send> Node<u8, NodeOutput<u32>, (), C> for FailNode {
+        async fn run(&mut self, _input: u8, _context: &mut C) -> Result<NodeOutput<u32>, ()> {
+            Err(())
+        }
+    }
+
     #[tokio::test]
     async fn test_flow() {
         let mut st = LocalStorageImpl::new();
@@ -318,4 +502,247 @@ mod test {
 
         assert_eq!(st.remove::<MyVal>(), Some(MyVal::default()));
     }
+
+    #[tokio::test]
+    async fn test_flow_deadline() {
+        let mut ctx = ClockCtx;
+        let mut flow = Flow::<u8, u64, (), _>::builder()
+            .add_node(SleepNode(0))
+            .add_node(SleepNode(200))
+            .build(async |input, _context: &mut ClockCtx| {
+                assert_eq!(input, ((NodeOutput::Ok(3u32),), NodeOutput::SoftFail));
+                Ok(NodeOutput::Ok(120))
+            })
+            .with_deadline(Duration::from_millis(50));
+
+        let start = Instant::now();
+        let res = flow.run(3, &mut ctx).await;
+        let took = start.elapsed();
+
+        assert_eq!(res, Result::Ok(NodeOutput::Ok(120)));
+        assert!(took.as_millis() < 150);
+    }
+
+    #[tokio::test]
+    async fn test_flow_deadline_not_reached() {
+        let mut ctx = ClockCtx;
+        let mut flow = Flow::<u8, u64, (), _>::builder()
+            .add_node(SleepNode(0))
+            .add_node(SleepNode(200))
+            .build(async |input, _context: &mut ClockCtx| {
+                assert_eq!(input, ((NodeOutput::Ok(3u32),), NodeOutput::Ok(3u32)));
+                Ok(NodeOutput::Ok(120))
+            })
+            .with_deadline(Duration::from_secs(5));
+
+        let res = flow.run(3, &mut ctx).await;
+        assert_eq!(res, Result::Ok(NodeOutput::Ok(120)));
+    }
+
+    #[tokio::test]
+    async fn test_flow_bounded() {
+        let mut ctx = ClockCtx;
+        let mut flow = Flow::<u8, u64, (), _>::builder()
+            .add_node(SleepNode(100))
+            .add_node(SleepNode(100))
+            .add_node(SleepNode(100))
+            .add_node(SleepNode(100))
+            .build_bounded(
+                async |input, _context: &mut ClockCtx| {
+                    assert_eq!(
+                        input,
+                        (
+                            (
+                                ((NodeOutput::Ok(3u32),), NodeOutput::Ok(3u32)),
+                                NodeOutput::Ok(3u32)
+                            ),
+                            NodeOutput::Ok(3u32)
+                        )
+                    );
+                    Ok(NodeOutput::Ok(120))
+                },
+                2,
+            );
+
+        let start = Instant::now();
+        let res = flow.run(3, &mut ctx).await;
+        let took = start.elapsed();
+
+        assert_eq!(res, Result::Ok(NodeOutput::Ok(120)));
+        assert!(took.as_millis() >= 190);
+        assert!(took.as_millis() < 290);
+    }
+
+    #[tokio::test]
+    async fn test_flow_bounded_above_node_count() {
+        let mut ctx = ClockCtx;
+        let mut flow = Flow::<u8, u64, (), _>::builder()
+            .add_node(SleepNode(100))
+            .add_node(SleepNode(100))
+            .build_bounded(
+                async |input, _context: &mut ClockCtx| {
+                    assert_eq!(input, ((NodeOutput::Ok(3u32),), NodeOutput::Ok(3u32)));
+                    Ok(NodeOutput::Ok(120))
+                },
+                5,
+            );
+
+        let start = Instant::now();
+        let res = flow.run(3, &mut ctx).await;
+        let took = start.elapsed();
+
+        assert_eq!(res, Result::Ok(NodeOutput::Ok(120)));
+        assert!(took.as_millis() < 190);
+    }
+
+    #[tokio::test]
+    async fn test_flow_bounded_error() {
+        let mut ctx = ClockCtx;
+        let mut flow = Flow::<u8, u64, (), _>::builder()
+            .add_node(FailNode)
+            .add_node(SleepNode(200))
+            .add_node(SleepNode(200))
+            .build_bounded(
+                async |_input, _context: &mut ClockCtx| -> Result<NodeOutput<u64>, ()> {
+                    panic!("joiner should not be called")
+                },
+                2,
+            );
+
+        let start = Instant::now();
+        let res = flow.run(3, &mut ctx).await;
+        let took = start.elapsed();
+
+        assert_eq!(res, Result::Err(()));
+        assert!(took.as_millis() < 150);
+    }
+
+    /// Counts how many times the node was started, finished and dropped.
+    #[derive(Clone, Default)]
+    struct SentinelNode {
+        started: Arc<AtomicUsize>,
+        finished: Arc<AtomicUsize>,
+        dropped: Arc<AtomicUsize>,
+    }
+
+    struct DropGuard(Arc<AtomicUsize>);
+
+    impl Drop for DropGuard {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    impl<C: Send> Node<u8, NodeOutput<u32>, (), C> for SentinelNode {
+        async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u32>, ()> {
+            self.started.fetch_add(1, Ordering::SeqCst);
+            let _guard = DropGuard(self.dropped.clone());
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            self.finished.fetch_add(1, Ordering::SeqCst);
+            Ok(NodeOutput::Ok(input.into()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow_error_cancels_siblings() {
+        let sentinel = SentinelNode::default();
+        let mut flow = Flow::<u8, u64, (), _>::builder()
+            .add_node(sentinel.clone())
+            .add_node(FailNode)
+            .add_node(sentinel.clone())
+            .build(
+                async |_input, _context: &mut ClockCtx| -> Result<NodeOutput<u64>, ()> {
+                    panic!("joiner should not be called")
+                },
+            );
+
+        let res = flow.run(3, &mut ClockCtx).await;
+
+        assert_eq!(res, Result::Err(()));
+        assert_eq!(sentinel.started.load(Ordering::SeqCst), 2);
+        // both siblings were canceled before the flow returned
+        assert_eq!(sentinel.dropped.load(Ordering::SeqCst), 2);
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert_eq!(sentinel.finished.load(Ordering::SeqCst), 0);
+    }
+
+    /// Tracks the number of running nodes of a group.
+    #[derive(Clone)]
+    struct GroupNode {
+        running: Arc<AtomicUsize>,
+        max_running: Arc<AtomicUsize>,
+    }
+
+    impl GroupNode {
+        fn new() -> Self {
+            Self {
+                running: Arc::default(),
+                max_running: Arc::default(),
+            }
+        }
+
+        fn max_running(&self) -> usize {
+            self.max_running.load(Ordering::SeqCst)
+        }
+    }
+
+    impl<C: Send> Node<u8, NodeOutput<u32>, (), C> for GroupNode {
+        async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u32>, ()> {
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_running.fetch_max(running, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(NodeOutput::Ok(input.into()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow_group_limits() {
+        let db = GroupNode::new();
+        let http = GroupNode::new();
+        let mut flow = Flow::<u8, u64, (), _>::builder()
+            .add_node_in_group(db.clone(), "db")
+            .add_node_in_group(db.clone(), "db")
+            .add_node_in_group(db.clone(), "db")
+            .add_node_in_group(http.clone(), "http")
+            .add_node_in_group(http.clone(), "http")
+            .add_node_in_group(http.clone(), "http")
+            .add_node(SleepNode(50))
+            .with_group_limit("db", 1)
+            .with_group_limit("http", 2)
+            .build_bounded(async |_, _: &mut ClockCtx| Ok(NodeOutput::Ok(120)), 10);
+
+        let start = Instant::now();
+        let res = flow.run(3, &mut ClockCtx).await;
+        let took = start.elapsed();
+
+        assert_eq!(res, Result::Ok(NodeOutput::Ok(120)));
+        assert_eq!(db.max_running(), 1);
+        assert_eq!(http.max_running(), 2);
+        // db nodes run one after another
+        assert!(took.as_millis() >= 140);
+        assert!(took.as_millis() < 240);
+    }
+
+    #[test]
+    fn test_describe_joiner() {
+        let flow = Flow::<u8, u64, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u16, u64, ()>::new())
+            .add_node(SoftFailNode::<u16, u32, ()>::new())
+            .build(async |_input, _context: &mut LocalStorageImpl| Ok(NodeOutput::Ok(120)));
+
+        let desc = flow.describe();
+        let joiner = desc.get_by_path(&[2]).unwrap().get_base_ref();
+        assert_eq!(joiner.r#type.name, "Joiner");
+        assert_eq!(
+            joiner.input.name,
+            std::any::type_name::<((NodeOutput<u64>,), NodeOutput<u32>)>()
+        );
+        assert_eq!(joiner.output.name, "u64");
+        assert_eq!(joiner.error.name, "()");
+        assert_eq!(
+            joiner.context.name,
+            std::any::type_name::<LocalStorageImpl>()
+        );
+    }
 }
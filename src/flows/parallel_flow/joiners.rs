@@ -0,0 +1,234 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{
+    flows::{NodeResult, parallel_flow::Joiner},
+    node::NodeOutput,
+};
+
+/// The `FlattenOutputs` trait flattens the nested outputs of [`ParallelFlow`](crate::flows::ParallelFlow) nodes.
+///
+/// Outputs of nodes are passed to a [`Joiner`] as a nested tuple
+/// (`(((NodeOutput<A>,), NodeOutput<B>), NodeOutput<C>)`).
+/// When all outputs can be converted into the same `Item` type,
+/// this trait turns such tuple into a flat [`Vec`] in the order in which the nodes were added.
+///
+/// # Examples
+/// ```
+/// use node_flow::flows::parallel_flow::FlattenOutputs;
+/// use node_flow::node::NodeOutput;
+///
+/// let outputs = (((NodeOutput::Ok(1u8),), NodeOutput::<u16>::SoftFail), NodeOutput::Ok(3u16));
+/// assert_eq!(
+///     outputs.flatten_outputs(),
+///     vec![NodeOutput::Ok(1u16), NodeOutput::SoftFail, NodeOutput::Ok(3)]
+/// );
+/// ```
+pub trait FlattenOutputs<Item> {
+    /// Appends all outputs into `outputs`.
+    fn flatten_outputs_into(self, outputs: &mut Vec<NodeOutput<Item>>);
+
+    /// Flattens all outputs into a [`Vec`].
+    fn flatten_outputs(self) -> Vec<NodeOutput<Item>>
+    where
+        Self: Sized,
+    {
+        let mut outputs = Vec::new();
+        self.flatten_outputs_into(&mut outputs);
+        outputs
+    }
+}
+
+impl<Item, T> FlattenOutputs<Item> for (NodeOutput<T>,)
+where
+    T: Into<Item>,
+{
+    fn flatten_outputs_into(self, outputs: &mut Vec<NodeOutput<Item>>) {
+        outputs.push(convert(self.0));
+    }
+}
+
+impl<Item, Head, T> FlattenOutputs<Item> for (Head, NodeOutput<T>)
+where
+    Head: FlattenOutputs<Item>,
+    T: Into<Item>,
+{
+    fn flatten_outputs_into(self, outputs: &mut Vec<NodeOutput<Item>>) {
+        self.0.flatten_outputs_into(outputs);
+        outputs.push(convert(self.1));
+    }
+}
+
+fn convert<Item, T: Into<Item>>(output: NodeOutput<T>) -> NodeOutput<Item> {
+    match output {
+        NodeOutput::Ok(val) => NodeOutput::Ok(val.into()),
+        NodeOutput::SoftFail => NodeOutput::SoftFail,
+    }
+}
+
+/// `Joiners` provides constructors of commonly used [`Joiner`]s.
+///
+/// These joiners require the outputs of all nodes to be convertible into the same `Item` type.
+///
+/// See also [`FlattenOutputs`].
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::{ParallelFlow, parallel_flow::Joiners};
+/// # use node_flow::context::{Fork, Join};
+/// #
+/// # struct ExampleCtx;
+/// # impl Fork for ExampleCtx { fn fork(&self) -> Self { Self } }
+/// # impl Join for ExampleCtx { fn join(&mut self, others: Box<[Self]>) {} }
+///
+/// #[derive(Clone)]
+/// struct Double;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u16>, (), Ctx> for Double {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u16>, ()> {
+///         Ok(NodeOutput::Ok(u16::from(input) * 2))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = ParallelFlow::<u8, Vec<u16>, (), ExampleCtx>::builder()
+///         .add_node(Double)
+///         .add_node(Double)
+///         .build(Joiners::collect_ok_into_vec());
+///
+///     let result = flow.run(5, &mut ExampleCtx).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(vec![10, 10])));
+/// }
+/// # main().await;
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct Joiners;
+
+impl Joiners {
+    /// Creates a [`Joiner`] that collects outputs of all nodes which didn't soft-fail into a [`Vec`].
+    ///
+    /// Outputs are in the order in which the nodes were added.
+    #[must_use]
+    pub const fn collect_ok_into_vec<Item>() -> CollectOkIntoVec<Item> {
+        CollectOkIntoVec(PhantomData)
+    }
+
+    /// Creates a [`Joiner`] that returns the output of the first added node which didn't soft-fail.
+    ///
+    /// If all nodes soft-failed, the flow soft-fails.
+    #[must_use]
+    pub const fn first_ok<Item>() -> FirstOk<Item> {
+        FirstOk(PhantomData)
+    }
+}
+
+/// [`Joiner`] collecting outputs of all nodes which didn't soft-fail into a [`Vec`].
+///
+/// See [`Joiners::collect_ok_into_vec`].
+pub struct CollectOkIntoVec<Item>(PhantomData<fn() -> Item>);
+
+/// [`Joiner`] returning the output of the first added node which didn't soft-fail.
+///
+/// See [`Joiners::first_ok`].
+pub struct FirstOk<Item>(PhantomData<fn() -> Item>);
+
+impl<'a, Input, Item, Error, Context> Joiner<'a, Input, Vec<Item>, Error, Context>
+    for CollectOkIntoVec<Item>
+where
+    Input: FlattenOutputs<Item> + Send,
+    Context: Send,
+{
+    async fn join(&self, input: Input, _context: &'a mut Context) -> NodeResult<Vec<Item>, Error> {
+        Ok(NodeOutput::Ok(
+            input.flatten_outputs().into_iter().flatten().collect(),
+        ))
+    }
+}
+
+impl<'a, Input, Item, Error, Context> Joiner<'a, Input, Item, Error, Context> for FirstOk<Item>
+where
+    Input: FlattenOutputs<Item> + Send,
+    Context: Send,
+{
+    async fn join(&self, input: Input, _context: &'a mut Context) -> NodeResult<Item, Error> {
+        Ok(input
+            .flatten_outputs()
+            .into_iter()
+            .find(NodeOutput::is_ok)
+            .unwrap_or(NodeOutput::SoftFail))
+    }
+}
+
+impl<Item> Debug for CollectOkIntoVec<Item> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CollectOkIntoVec").finish()
+    }
+}
+
+impl<Item> Clone for CollectOkIntoVec<Item> {
+    fn clone(&self) -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Item> Debug for FirstOk<Item> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FirstOk").finish()
+    }
+}
+
+impl<Item> Clone for FirstOk<Item> {
+    fn clone(&self) -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Joiners;
+    use crate::{
+        context::storage::local_storage::LocalStorageImpl,
+        flows::{
+            ParallelFlow,
+            tests::{Passer, SoftFailNode},
+        },
+        node::{Node, NodeOutput},
+    };
+
+    #[tokio::test]
+    async fn test_collect_ok_into_vec() {
+        let mut flow = ParallelFlow::<u8, Vec<u16>, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(SoftFailNode::<u8, u16, ()>::new())
+            .add_node(Passer::<u8, u16, ()>::new())
+            .build(Joiners::collect_ok_into_vec());
+
+        let res = flow.run(5, &mut LocalStorageImpl::new()).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(vec![5, 5])));
+    }
+
+    #[tokio::test]
+    async fn test_first_ok() {
+        let mut flow = ParallelFlow::<u8, u16, (), LocalStorageImpl>::builder()
+            .add_node(SoftFailNode::<u8, u16, ()>::new())
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(Passer::<u8, u16, ()>::new())
+            .build(Joiners::first_ok());
+        let res = flow.run(5, &mut LocalStorageImpl::new()).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(5)));
+
+        let mut flow = ParallelFlow::<u8, u16, (), LocalStorageImpl>::builder()
+            .add_node(SoftFailNode::<u8, u16, ()>::new())
+            .add_node(SoftFailNode::<u8, u8, ()>::new())
+            .add_node(SoftFailNode::<u8, u16, ()>::new())
+            .build(Joiners::first_ok());
+        let res = flow.run(5, &mut LocalStorageImpl::new()).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+    }
+}
@@ -2,9 +2,17 @@ mod builder;
 pub use builder::*;
 mod flow;
 pub use flow::*;
+mod early_exit_flow;
+pub use early_exit_flow::*;
+mod joiners;
+pub use joiners::*;
+#[cfg(feature = "derive")]
+pub use node_flow_derive::FromParallelOutputs;
 
-use crate::flows::NodeResult;
+use crate::{flows::NodeResult, node::NodeOutput};
 mod chain_run;
+mod concurrency;
+mod deadline;
 
 /// The `Joiner` handles the output of all nodes from [`ParallelFlow`].
 ///
@@ -37,3 +45,34 @@ where
         (self)(input, context)
     }
 }
+
+/// The `EarlyExitJoiner` handles the outputs of nodes from [`ParallelFlow`] as soon as they finish.
+///
+/// Unlike [`Joiner`], which is called once all nodes finish,
+/// `EarlyExitJoiner` receives outputs incrementally and can finish the flow early,
+/// which cancels the nodes that are still running.
+/// Outputs of all nodes must be convertible into the same `Item` type.
+///
+/// See also [`EarlyExitParallelFlow`] and [`Builder::build_with_early_exit`].
+pub trait EarlyExitJoiner<Item, Output, Error>: Send + Sync {
+    /// Handles the output of a node that has just finished.
+    ///
+    /// `outputs` contains the outputs of finished nodes in the order in which they finished,
+    /// the last one being the output of the node that has just finished.
+    ///
+    /// Returning `Some` finishes the flow with the returned result
+    /// and the remaining nodes are canceled.
+    ///
+    /// # Errors
+    /// Returning `Some(Err(err))` fails the flow with `err`.
+    fn on_output(&self, outputs: &mut Vec<NodeOutput<Item>>) -> Option<NodeResult<Output, Error>>;
+
+    /// Handles the outputs of all finished nodes,
+    /// when [`EarlyExitJoiner::on_output`] didn't finish the flow early.
+    ///
+    /// It's called when all nodes finished or when the deadline of the flow elapsed.
+    ///
+    /// # Errors
+    /// The returned error is returned by the flow.
+    fn finish(&self, outputs: Vec<NodeOutput<Item>>) -> NodeResult<Output, Error>;
+}
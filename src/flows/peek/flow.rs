@@ -0,0 +1,224 @@
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use crate::{
+    describe::Description,
+    flows::NodeResult,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+type OnInput<Input> = Arc<dyn Fn(&Input) + Send + Sync>;
+type OnOutput<Output> = Arc<dyn Fn(&NodeOutputStruct<Output>) + Send + Sync>;
+
+/// `PeekFlow` wraps a node and lets you inspect its input and output without altering them.
+///
+/// The closure set by [`PeekFlow::with_on_input`] is called with the input before the node is run
+/// and the closure set by [`PeekFlow::with_on_output`] is called with the output once the node finishes.
+/// The output hook is not called when the node returns an error.
+///
+/// The description of this flow is the description of the wrapped node,
+/// so peeking doesn't show up in visualizations.
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::PeekFlow;
+///
+/// #[derive(Clone)]
+/// struct AddOne;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         Ok(NodeOutput::Ok(input + 1))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = PeekFlow::<u8, u8, (), ()>::new(AddOne)
+///         .with_on_input(|input| println!("input: {input}"))
+///         .with_on_output(|output| println!("output: {output:?}"));
+///
+///     let result = flow.run(5, &mut ()).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(6)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct PeekFlow<Input, Output, Error, Context, NodeType = (), NodeOutput = (), NodeError = ()> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    _node_oe: PhantomData<fn() -> (NodeOutput, NodeError)>,
+    node: NodeType,
+    on_input: Option<OnInput<Input>>,
+    on_output: Option<OnOutput<Output>>,
+}
+
+impl<Input, Output, Error, Context> PeekFlow<Input, Output, Error, Context> {
+    /// Creates a new [`PeekFlow`] wrapping `node` without any hooks.
+    pub fn new<NodeType, NodeOutput, NodeError>(
+        node: NodeType,
+    ) -> PeekFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    where
+        NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeOutput: Into<Output>,
+        NodeError: Into<Error>,
+    {
+        PeekFlow {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node,
+            on_input: None,
+            on_output: None,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    PeekFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+{
+    /// Sets a closure which is called with the input before the wrapped node is run.
+    #[must_use]
+    pub fn with_on_input(mut self, on_input: impl Fn(&Input) + Send + Sync + 'static) -> Self {
+        self.on_input = Some(Arc::new(on_input));
+        self
+    }
+
+    /// Sets a closure which is called with the output after the wrapped node finishes.
+    #[must_use]
+    pub fn with_on_output(
+        mut self,
+        on_output: impl Fn(&NodeOutputStruct<Output>) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_output = Some(Arc::new(on_output));
+        self
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Debug
+    for PeekFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PeekFlow")
+            .field("node", &self.node)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Clone
+    for PeekFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node: self.node.clone(),
+            on_input: self.on_input.clone(),
+            on_output: self.on_output.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for PeekFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context> + Send,
+    NodeOutput: Into<Output>,
+    NodeError: Into<Error>,
+    Input: Send,
+    Context: Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        if let Some(on_input) = &self.on_input {
+            on_input(&input);
+        }
+
+        let output = match self.node.run(input, context).await {
+            Ok(NodeOutputStruct::Ok(output)) => NodeOutputStruct::Ok(output.into()),
+            Ok(NodeOutputStruct::SoftFail) => NodeOutputStruct::SoftFail,
+            Err(err) => return Err(err.into()),
+        };
+
+        if let Some(on_output) = &self.on_output {
+            on_output(&output);
+        }
+        Ok(output)
+    }
+
+    fn describe(&self) -> Description {
+        self.node.describe()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::PeekFlow;
+    use crate::{
+        flows::tests::{Passer, SoftFailNode},
+        node::{Node, NodeOutput},
+    };
+
+    #[tokio::test]
+    async fn test_hooks_fire_once() {
+        let inputs = Arc::new(AtomicUsize::new(0));
+        let outputs = Arc::new(AtomicUsize::new(0));
+
+        let mut flow = PeekFlow::<u8, u16, (), ()>::new(Passer::<u8, u8, ()>::new())
+            .with_on_input({
+                let inputs = inputs.clone();
+                move |input| {
+                    assert_eq!(*input, 5);
+                    inputs.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+            .with_on_output({
+                let outputs = outputs.clone();
+                move |output| {
+                    assert_eq!(*output, NodeOutput::Ok(5));
+                    outputs.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+
+        assert_eq!(flow.run(5, &mut ()).await, Ok(NodeOutput::Ok(5)));
+        assert_eq!(inputs.load(Ordering::Relaxed), 1);
+        assert_eq!(outputs.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_soft_fail_and_no_hooks() {
+        let outputs = Arc::new(AtomicUsize::new(0));
+        let mut flow = PeekFlow::<u8, u16, (), ()>::new(SoftFailNode::<u8, u8, ()>::new())
+            .with_on_output({
+                let outputs = outputs.clone();
+                move |output| {
+                    assert_eq!(*output, NodeOutput::SoftFail);
+                    outputs.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        assert_eq!(flow.run(5, &mut ()).await, Ok(NodeOutput::SoftFail));
+        assert_eq!(outputs.load(Ordering::Relaxed), 1);
+
+        let mut flow = PeekFlow::<u8, u16, (), ()>::new(Passer::<u8, u8, ()>::new());
+        assert_eq!(flow.run(5, &mut ()).await, Ok(NodeOutput::Ok(5)));
+    }
+}
@@ -0,0 +1,192 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use super::QuorumFlow as Flow;
+use crate::{
+    context::{Fork, Join},
+    flows::{ChainLink, FlowNode, NodeIOE, generic_defs::debug::impl_debug_for_builder},
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// Builder for [`QuorumFlow`](Flow).
+///
+/// This builder ensures:
+/// - `Input` into the flow can be converted into the input of all nodes
+/// - output of all nodes can be converted into the `Output` of the flow
+/// - error of all nodes can be converted into the `Error` of the flow
+///
+/// See also [`QuorumFlow`](Flow).
+pub struct Builder<Input, Output, Error, Context, NodeTypes = (), NodeIOETypes = ()>
+where
+    // Trait bounds for better and nicer errors
+    Input: Send + Clone,
+    Output: Send,
+    Error: Send,
+    Context: Fork + Join + Send,
+{
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    _nodes_io: PhantomData<fn() -> NodeIOETypes>,
+    nodes: NodeTypes,
+}
+
+impl_debug_for_builder!(
+    "QuorumFlow",
+    Builder,
+    Input: Send + Clone,
+    Output: Send,
+    Error: Send,
+    Context: Fork + Join + Send
+);
+
+impl<Input, Output, Error, Context> Default for Builder<Input, Output, Error, Context>
+where
+    // Trait bounds for better and nicer errors
+    Input: Send + Clone,
+    Output: Send,
+    Error: Send,
+    Context: Fork + Join + Send,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Input, Output, Error, Context> Builder<Input, Output, Error, Context>
+where
+    // Trait bounds for better and nicer errors
+    Input: Send + Clone,
+    Output: Send,
+    Error: Send,
+    Context: Fork + Join + Send,
+{
+    /// Creates a new empty builder for [`QuorumFlow`](Flow).
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            _ioec: PhantomData,
+            _nodes_io: PhantomData,
+            nodes: (),
+        }
+    }
+
+    /// Adds a new node.
+    ///
+    /// The new node must satisfy:
+    /// - `Self`: `Node<NodeInputType, NodeOutput<NodeOutputType>, NodeErrorType, _>`
+    /// - `Input`: `Into<NodeInputType>`,
+    /// - `NodeOutputType`: `Into<Output>`,
+    /// - `NodeErrorType`: `Into<Error>`,
+    ///
+    /// # Returns
+    /// A new [`Builder`] with the added node.
+    #[expect(clippy::type_complexity, clippy::type_repetition_in_bounds)]
+    pub fn add_node<NodeType, NodeInput, NodeOutput, NodeError>(
+        self,
+        node: NodeType,
+    ) -> Builder<
+        Input,
+        Output,
+        Error,
+        Context,
+        (NodeType,),
+        ChainLink<(), NodeIOE<NodeInput, NodeOutput, NodeError>>,
+    >
+    where
+        Input: Into<NodeInput>,
+        NodeOutput: Into<Output>,
+        NodeError: Into<Error>,
+        NodeType: Node<NodeInput, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeType: FlowNode,
+    {
+        Builder {
+            _ioec: PhantomData,
+            _nodes_io: PhantomData,
+            nodes: (node,),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, OtherNodeIOETypes, LastNodeIOETypes>
+    Builder<
+        Input,
+        Output,
+        Error,
+        Context,
+        NodeTypes,
+        ChainLink<OtherNodeIOETypes, LastNodeIOETypes>,
+    >
+where
+    // Trait bounds for better and nicer errors
+    Input: Send + Clone,
+    Output: Send,
+    Error: Send,
+    Context: Fork + Join + Send,
+{
+    /// Adds a new node.
+    ///
+    /// The new node must satisfy:
+    /// - `Self`: `Node<NodeInputType, NodeOutput<NodeOutputType>, NodeErrorType, _>`
+    /// - `Input`: `Into<NodeInputType>`,
+    /// - `NodeOutputType`: `Into<Output>`,
+    /// - `NodeErrorType`: `Into<Error>`,
+    ///
+    /// # Returns
+    /// A new [`Builder`] with the added node.
+    #[expect(clippy::type_complexity, clippy::type_repetition_in_bounds)]
+    pub fn add_node<NodeType, NodeInput, NodeOutput, NodeError>(
+        self,
+        node: NodeType,
+    ) -> Builder<
+        Input,
+        Output,
+        Error,
+        Context,
+        ChainLink<NodeTypes, NodeType>,
+        ChainLink<
+            ChainLink<OtherNodeIOETypes, LastNodeIOETypes>,
+            NodeIOE<NodeInput, NodeOutput, NodeError>,
+        >,
+    >
+    where
+        Input: Into<NodeInput>,
+        NodeOutput: Into<Output>,
+        NodeError: Into<Error>,
+        NodeType: Node<NodeInput, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeType: FlowNode,
+    {
+        Builder {
+            _ioec: PhantomData,
+            _nodes_io: PhantomData,
+            nodes: (self.nodes, node),
+        }
+    }
+
+    /// Finalizes the builder and produces a [`QuorumFlow`](Flow) instance
+    /// that succeeds once `quorum` nodes (branches) succeed.
+    ///
+    /// When `quorum` is greater than the number of nodes, the flow always soft-fails.
+    ///
+    /// # Panics
+    /// Panics if `quorum` is `0`.
+    pub fn build(
+        self,
+        quorum: usize,
+    ) -> Flow<
+        Input,
+        Output,
+        Error,
+        Context,
+        NodeTypes,
+        ChainLink<OtherNodeIOETypes, LastNodeIOETypes>,
+    > {
+        assert!(quorum > 0, "quorum must be greater than 0");
+        Flow {
+            _ioec: PhantomData,
+            _nodes_io: PhantomData,
+            nodes: Arc::new(self.nodes),
+            quorum,
+        }
+    }
+}
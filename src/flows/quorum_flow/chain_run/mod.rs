@@ -0,0 +1,5 @@
+mod poll;
+mod run;
+mod spawn;
+
+pub use run::ChainRunQuorum;
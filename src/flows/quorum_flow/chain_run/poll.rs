@@ -0,0 +1,106 @@
+use std::{pin::Pin, task::Context};
+
+use futures_util::future::MaybeDone;
+
+use crate::{
+    flows::quorum_flow::FutOutput, future_utils::SoftFailPoll, node::NodeOutput as NodeOutputStruct,
+};
+
+/// Successful outputs together with contexts of the nodes that produced them.
+pub type Successes<Output, NodeContext> = Vec<(Output, NodeContext)>;
+
+pub trait ChainPollQuorum<Output, Error, NodeContext>: Send {
+    /// Polls all unfinished nodes and collects successful outputs into `successes`.
+    ///
+    /// Returns:
+    /// - `Ready(Ok(()))` when `quorum` successes were collected
+    /// - `Ready(Err(_))` when some node failed
+    /// - `SoftFail` when all nodes finished without reaching the `quorum`
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        successes: &mut Successes<Output, NodeContext>,
+        quorum: usize,
+    ) -> SoftFailPoll<Result<(), Error>>;
+}
+
+fn poll_node<Fut, Output, Error, NodeContext>(
+    fut: Pin<&mut MaybeDone<Fut>>,
+    cx: &mut Context<'_>,
+    successes: &mut Successes<Output, NodeContext>,
+    quorum: usize,
+) -> SoftFailPoll<Result<(), Error>>
+where
+    Fut: Future<Output = FutOutput<Output, Error, NodeContext>>,
+{
+    let mut fut = fut;
+    if matches!(*fut, MaybeDone::Gone) {
+        return SoftFailPoll::SoftFail;
+    }
+    if fut.as_mut().poll(cx).is_pending() {
+        return SoftFailPoll::Pending;
+    }
+    match fut.take_output().unwrap() {
+        Ok((NodeOutputStruct::Ok(output), context)) => {
+            successes.push((output, context));
+            if successes.len() >= quorum {
+                SoftFailPoll::Ready(Ok(()))
+            } else {
+                SoftFailPoll::SoftFail
+            }
+        }
+        Ok((NodeOutputStruct::SoftFail, _)) => SoftFailPoll::SoftFail,
+        Err(err) => SoftFailPoll::Ready(Err(err)),
+    }
+}
+
+impl<Head, Tail, Output, Error, NodeContext> ChainPollQuorum<Output, Error, NodeContext>
+    for (Head, MaybeDone<Tail>)
+where
+    Error: Send,
+    Output: Send,
+    NodeContext: Send,
+    Head: ChainPollQuorum<Output, Error, NodeContext>,
+    Tail: Future<Output = FutOutput<Output, Error, NodeContext>> + Send,
+{
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        successes: &mut Successes<Output, NodeContext>,
+        quorum: usize,
+    ) -> SoftFailPoll<Result<(), Error>> {
+        let (head, tail) = unsafe { self.get_unchecked_mut() };
+        let head = unsafe { Pin::new_unchecked(head) };
+        let head_pending = match ChainPollQuorum::poll(head, cx, successes, quorum) {
+            SoftFailPoll::Pending => true,
+            SoftFailPoll::Ready(res) => return SoftFailPoll::Ready(res),
+            SoftFailPoll::SoftFail => false,
+        };
+
+        let tail = unsafe { Pin::new_unchecked(tail) };
+        match poll_node(tail, cx, successes, quorum) {
+            SoftFailPoll::Ready(res) => SoftFailPoll::Ready(res),
+            SoftFailPoll::SoftFail if !head_pending => SoftFailPoll::SoftFail,
+            _ => SoftFailPoll::Pending,
+        }
+    }
+}
+
+impl<Head, Output, Error, NodeContext> ChainPollQuorum<Output, Error, NodeContext>
+    for (MaybeDone<Head>,)
+where
+    Error: Send,
+    Output: Send,
+    NodeContext: Send,
+    Head: Future<Output = FutOutput<Output, Error, NodeContext>> + Send,
+{
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        successes: &mut Successes<Output, NodeContext>,
+        quorum: usize,
+    ) -> SoftFailPoll<Result<(), Error>> {
+        let head = unsafe { Pin::new_unchecked(&mut self.get_unchecked_mut().0) };
+        poll_node(head, cx, successes, quorum)
+    }
+}
@@ -0,0 +1,59 @@
+use std::{future::poll_fn, pin::pin, task::Poll};
+
+use crate::{
+    context::{Fork, Join},
+    flows::{
+        NodeResult,
+        quorum_flow::chain_run::{poll::ChainPollQuorum, spawn::ChainSpawn},
+    },
+    future_utils::SoftFailPoll,
+    node::NodeOutput as NodeOutputStruct,
+};
+
+pub trait ChainRunQuorum<Input, Output, Context, T> {
+    fn run(
+        &self,
+        input: Input,
+        context: &mut Context,
+        quorum: usize,
+    ) -> impl Future<Output = Output> + Send;
+}
+
+impl<Input, Output, Error, Context, T, U>
+    ChainRunQuorum<Input, NodeResult<Vec<Output>, Error>, Context, T> for U
+where
+    U: ChainSpawn<Input, Output, Error, Context, T> + Sync,
+    Input: Send,
+    Output: Send,
+    Error: Send,
+    Context: Fork + Join + Send,
+{
+    async fn run(
+        &self,
+        input: Input,
+        context: &mut Context,
+        quorum: usize,
+    ) -> NodeResult<Vec<Output>, Error> {
+        let mut successes = Vec::with_capacity(quorum);
+        let res = {
+            let fut_chain = self.run(input, context.fork());
+            let mut fut_chain = pin!(fut_chain);
+            // unfinished nodes are cancelled when `fut_chain` is dropped
+            poll_fn(|cx| {
+                match ChainPollQuorum::poll(fut_chain.as_mut(), cx, &mut successes, quorum) {
+                    SoftFailPoll::Pending => Poll::Pending,
+                    SoftFailPoll::Ready(res) => Poll::Ready(res.map(|()| true)),
+                    SoftFailPoll::SoftFail => Poll::Ready(Ok(false)),
+                }
+            })
+            .await
+        };
+
+        if !res? {
+            return Ok(NodeOutputStruct::SoftFail);
+        }
+        let (outputs, contexts): (Vec<_>, Vec<_>) = successes.into_iter().unzip();
+        context.join(contexts.into_boxed_slice());
+        Ok(NodeOutputStruct::Ok(outputs))
+    }
+}
@@ -0,0 +1,267 @@
+use std::fmt::Debug;
+
+use super::Builder;
+use super::chain_run::ChainRunQuorum as ChainRun;
+use crate::{
+    context::{Fork, Join},
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::{NodeResult, chain_debug::ChainDebug, chain_describe::ChainDescribe},
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// `QuorumFlow` executes nodes (branches) **in parallel**, returning when a quorum of them succeeds.
+///
+/// Nodes (branches) are executed concurrently.
+/// The flow completes when `quorum` nodes succeed or **any** node "hard" fails.
+/// - If a node returns [`NodeOutput::Ok`](crate::node::NodeOutput::Ok), that value is collected.
+/// - If a node returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail),
+///   that result is ignored and the flow continues waiting for other nodes (branches).
+/// - If a node returns an **error**, then that error is returned.
+///
+/// Once `quorum` values are collected, nodes (branches) that have not finished are cancelled
+/// and the collected values are returned in the order in which the nodes finished.
+/// Contexts of the successful nodes (branches) are joined back, contexts of the others are discarded.
+///
+/// If too many nodes (branches) soft-fail for the quorum to be reached,
+/// the flow itself returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail).
+///
+/// This flow is a middle ground between [`OneOfParallelFlow`](crate::flows::OneOfParallelFlow),
+/// which returns on the first success, and [`ParallelFlow`](crate::flows::ParallelFlow),
+/// which waits for all nodes (branches).
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by each node (branch), this flow produces `Vec<Output>`.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::QuorumFlow;
+/// use node_flow::context::{Fork, Join};
+///
+/// // Example nodes
+/// #[derive(Clone)]
+/// struct A;
+/// #[derive(Clone)]
+/// struct B;
+///
+/// struct ExampleCtx;
+/// impl Fork for ExampleCtx // ...
+/// # { fn fork(&self) -> Self { Self } }
+/// impl Join for ExampleCtx // ...
+/// # { fn join(&mut self, others: Box<[Self]>) {} }
+///
+/// impl<Ctx: Send> Node<(), NodeOutput<i32>, (), Ctx> for A {
+///     async fn run(&mut self, _: (), _: &mut Ctx) -> Result<NodeOutput<i32>, ()> {
+///         Ok(NodeOutput::SoftFail) // Ignored
+///     }
+/// }
+///
+/// impl<Ctx: Send> Node<(), NodeOutput<i32>, (), Ctx> for B {
+///     async fn run(&mut self, _: (), _: &mut Ctx) -> Result<NodeOutput<i32>, ()> {
+///         Ok(NodeOutput::Ok(5))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = QuorumFlow::<(), i32, (), _>::builder()
+///         .add_node(A)
+///         .add_node(B)
+///         .add_node(B)
+///         .build(2);
+///
+///     let mut ctx = ExampleCtx;
+///     let result = flow.run((), &mut ctx).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(vec![5, 5])));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct QuorumFlow<Input, Output, Error, Context, NodeTypes = (), NodeIOETypes = ()> {
+    #[expect(clippy::type_complexity)]
+    pub(super) _ioec: std::marker::PhantomData<fn() -> (Input, Output, Error, Context)>,
+    pub(super) _nodes_io: std::marker::PhantomData<fn() -> NodeIOETypes>,
+    pub(super) nodes: std::sync::Arc<NodeTypes>,
+    pub(super) quorum: usize,
+}
+
+impl<Input, Output, Error, Context> QuorumFlow<Input, Output, Error, Context>
+where
+    // Trait bounds for better and nicer errors
+    Input: Send + Clone,
+    Output: Send,
+    Error: Send,
+    Context: Fork + Join + Send,
+{
+    /// Creates a new [`Builder`] for constructing [`QuorumFlow`].
+    ///
+    /// See also [`QuorumFlow`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use node_flow::context::{Fork, Join};
+    /// # struct Ctx;
+    /// # impl Fork for Ctx { fn fork(&self) -> Self { Self } }
+    /// # impl Join for Ctx { fn join(&mut self, other: Box<[Self]>) {} }
+    /// #
+    /// use node_flow::flows::QuorumFlow;
+    ///
+    /// let builder = QuorumFlow::<u8, u16, (), Ctx>::builder();
+    /// ```
+    #[must_use]
+    pub fn builder() -> Builder<Input, Output, Error, Context> {
+        Builder::new()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes> Clone
+    for QuorumFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: std::marker::PhantomData,
+            _nodes_io: std::marker::PhantomData,
+            nodes: self.nodes.clone(),
+            quorum: self.quorum,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes> Debug
+    for QuorumFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+where
+    NodeTypes: ChainDebug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QuorumFlow")
+            .field("nodes", &self.nodes.as_list())
+            .field("quorum", &self.quorum)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+    Node<Input, NodeOutputStruct<Vec<Output>>, Error, Context>
+    for QuorumFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+where
+    NodeTypes: ChainRun<Input, NodeResult<Vec<Output>, Error>, Context, NodeIOETypes>
+        + ChainDescribe<Context, NodeIOETypes>,
+{
+    fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = NodeResult<Vec<Output>, Error>> + Send {
+        ChainRun::run(self.nodes.as_ref(), input, context, self.quorum)
+    }
+
+    fn describe(&self) -> Description {
+        let node_count = <NodeTypes as ChainDescribe<Context, NodeIOETypes>>::COUNT;
+        let mut node_descriptions = Vec::with_capacity(node_count);
+        self.nodes.describe(&mut node_descriptions);
+        let edges = (0..node_count)
+            .flat_map(|i| [Edge::flow_to_node(i), Edge::node_to_flow(i)])
+            .collect::<Vec<_>>();
+
+        Description::new_flow(self, node_descriptions, edges)
+            .modify_name(remove_generics_from_name)
+            .with_description(format!("Succeeds when {} nodes succeed", self.quorum))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::QuorumFlow as Flow;
+    use crate::{
+        context::storage::local_storage::{LocalStorage, LocalStorageImpl, tests::MyVal},
+        flows::tests::{Passer, SoftFailNode},
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct SleepInsertNode(u64, &'static str);
+
+    impl<C: LocalStorage + Send> Node<u8, NodeOutput<u64>, (), C> for SleepInsertNode {
+        async fn run(&mut self, _input: u8, context: &mut C) -> Result<NodeOutput<u64>, ()> {
+            tokio::time::sleep(Duration::from_millis(self.0)).await;
+            context.insert(MyVal(self.1.to_owned()));
+            Ok(NodeOutput::Ok(self.0))
+        }
+    }
+
+    #[derive(Clone)]
+    struct FailNode;
+
+    impl<C: Send> Node<u8, NodeOutput<u64>, (), C> for FailNode {
+        async fn run(&mut self, _input: u8, _context: &mut C) -> Result<NodeOutput<u64>, ()> {
+            Err(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow() {
+        let mut st = LocalStorageImpl::new();
+        let mut flow = Flow::<u8, u64, (), _>::builder()
+            .add_node(SleepInsertNode(10, "a"))
+            .add_node(SleepInsertNode(300, "c"))
+            .add_node(SoftFailNode::<u8, u64, ()>::new())
+            .add_node(SleepInsertNode(100, "b"))
+            .build(2);
+
+        let start = Instant::now();
+        let res = flow.run(5, &mut st).await;
+        let took = start.elapsed();
+
+        assert_eq!(res, Ok(NodeOutput::Ok(vec![10, 100])));
+        assert!(took.as_millis() < 250);
+        assert_eq!(st.remove::<MyVal>(), Some(MyVal("ab".to_owned())));
+    }
+
+    #[tokio::test]
+    async fn test_flow_soft_fail() {
+        let mut st = LocalStorageImpl::new();
+        let mut flow = Flow::<u8, u64, (), _>::builder()
+            .add_node(SoftFailNode::<u16, u32, ()>::new())
+            .add_node(Passer::<u16, u32, ()>::new())
+            .add_node(SoftFailNode::<u8, u16, ()>::new())
+            .build(2);
+        let res = flow.run(5, &mut st).await;
+
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+    }
+
+    #[tokio::test]
+    async fn test_flow_error() {
+        let mut st = LocalStorageImpl::new();
+        let mut flow = Flow::<u8, u64, (), _>::builder()
+            .add_node(SleepInsertNode(10, "a"))
+            .add_node(SleepInsertNode(300, "b"))
+            .add_node(FailNode)
+            .build(2);
+
+        let start = Instant::now();
+        let res = flow.run(5, &mut st).await;
+        let took = start.elapsed();
+
+        assert_eq!(res, Err(()));
+        assert!(took.as_millis() < 250);
+        assert_eq!(st.remove::<MyVal>(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "quorum must be greater than 0")]
+    fn test_zero_quorum() {
+        let _ = Flow::<u8, u64, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u8, u64, ()>::new())
+            .build(0);
+    }
+}
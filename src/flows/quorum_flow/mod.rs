@@ -0,0 +1,10 @@
+mod builder;
+pub use builder::*;
+mod flow;
+pub use flow::*;
+
+mod chain_run;
+
+use crate::node::NodeOutput as NodeOutputStruct;
+
+type FutOutput<Output, Error, Context> = Result<(NodeOutputStruct<Output>, Context), Error>;
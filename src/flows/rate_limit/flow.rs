@@ -0,0 +1,251 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    context::Clock,
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::NodeResult,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// `RateLimitFlow` enforces a minimum interval between the starts of node runs.
+///
+/// When the flow is run sooner than `min_interval` after the previous run started,
+/// it sleeps (using the [`Clock`] context trait) until the interval elapses and only then runs the node.
+/// Each run reserves its start time before sleeping,
+/// so concurrent runs are spaced out by `min_interval` as well.
+///
+/// The limiter state is shared between all clones of the flow,
+/// so the limit applies even when the flow is cloned (for example by other flows).
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::context::Clock;
+/// use node_flow::flows::RateLimitFlow;
+/// use std::time::{Duration, Instant};
+///
+/// #[derive(Clone)]
+/// struct CallApi;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, String, Ctx> for CallApi {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, String> {
+///         Ok(NodeOutput::Ok(input))
+///     }
+/// }
+///
+/// struct ExampleCtx;
+/// impl Clock for ExampleCtx // ...
+/// # {
+/// #     fn sleep(duration: Duration) -> impl Future<Output = ()> + Send {
+/// #         tokio::time::sleep(duration)
+/// #     }
+/// # }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = RateLimitFlow::<u8, u8, String, ExampleCtx>::new(
+///         CallApi,
+///         Duration::from_millis(10),
+///     );
+///
+///     let mut ctx = ExampleCtx;
+///     let start = Instant::now();
+///     assert_eq!(flow.run(1, &mut ctx).await, Ok(NodeOutput::Ok(1)));
+///     assert_eq!(flow.run(2, &mut ctx).await, Ok(NodeOutput::Ok(2)));
+///     assert!(start.elapsed() >= Duration::from_millis(10));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct RateLimitFlow<
+    Input,
+    Output,
+    Error,
+    Context,
+    NodeType = (),
+    NodeOutput = (),
+    NodeError = (),
+> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    _node_oe: PhantomData<fn() -> (NodeOutput, NodeError)>,
+    node: NodeType,
+    /// Earliest start of the next run, `None` if the flow was not run yet.
+    next_start: Arc<Mutex<Option<Instant>>>,
+    min_interval: Duration,
+}
+
+impl<Input, Output, Error, Context> RateLimitFlow<Input, Output, Error, Context> {
+    /// Creates a new [`RateLimitFlow`] that starts `node` at most once per `min_interval`.
+    pub fn new<NodeType, NodeOutput, NodeError>(
+        node: NodeType,
+        min_interval: Duration,
+    ) -> RateLimitFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    where
+        NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeOutput: Into<Output>,
+        NodeError: Into<Error>,
+    {
+        RateLimitFlow {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node,
+            next_start: Arc::new(Mutex::new(None)),
+            min_interval,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Debug
+    for RateLimitFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateLimitFlow")
+            .field("node", &self.node)
+            .field("next_start", &self.next_start)
+            .field("min_interval", &self.min_interval)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Clone
+    for RateLimitFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node: self.node.clone(),
+            next_start: self.next_start.clone(),
+            min_interval: self.min_interval,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for RateLimitFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context> + Send,
+    NodeOutput: Into<Output>,
+    NodeError: Into<Error>,
+    Input: Send,
+    Context: Clock + Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        let now = Context::now();
+        // reserve the start of this run
+        let start = {
+            let mut next_start = self.next_start.lock().unwrap();
+            let start = next_start.map_or(now, |next_start| next_start.max(now));
+            *next_start = Some(start + self.min_interval);
+            start
+        };
+        if start > now {
+            Context::sleep(start - now).await;
+        }
+
+        match self.node.run(input, context).await {
+            Ok(NodeOutputStruct::Ok(output)) => Ok(NodeOutputStruct::Ok(output.into())),
+            Ok(NodeOutputStruct::SoftFail) => Ok(NodeOutputStruct::SoftFail),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_flow(
+            self,
+            vec![self.node.describe()],
+            vec![Edge::flow_to_node(0), Edge::node_to_flow(0)],
+        )
+        .modify_name(remove_generics_from_name)
+        .with_description(format!("Min interval: {:?}", self.min_interval))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    };
+
+    use super::RateLimitFlow;
+    use crate::{
+        context::test::TokioSpawner,
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone, Default)]
+    struct RecordStart(Arc<Mutex<Vec<Instant>>>);
+
+    impl<C: Send> Node<u8, NodeOutput<u8>, (), C> for RecordStart {
+        async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u8>, ()> {
+            self.0.lock().unwrap().push(Instant::now());
+            Ok(NodeOutput::Ok(input))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow() {
+        let node = RecordStart::default();
+        let mut flow =
+            RateLimitFlow::<u8, u64, (), _>::new(node.clone(), Duration::from_millis(20));
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            assert_eq!(flow.run(5, &mut TokioSpawner).await, Ok(NodeOutput::Ok(5)));
+        }
+        let took = start.elapsed();
+        println!("took: {took:?}");
+        assert!(took.as_millis() >= 38);
+
+        let starts = node.0.lock().unwrap().clone();
+        assert_eq!(starts.len(), 3);
+        for pair in starts.windows(2) {
+            assert!(pair[1].duration_since(pair[0]).as_millis() >= 19);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow_shared_state() {
+        let mut flow =
+            RateLimitFlow::<u8, u64, (), _>::new(RecordStart::default(), Duration::from_millis(30));
+        let mut cloned = flow.clone();
+
+        let start = Instant::now();
+        assert_eq!(flow.run(5, &mut TokioSpawner).await, Ok(NodeOutput::Ok(5)));
+        assert!(start.elapsed().as_millis() < 10);
+        assert_eq!(
+            cloned.run(5, &mut TokioSpawner).await,
+            Ok(NodeOutput::Ok(5))
+        );
+        assert!(start.elapsed().as_millis() >= 28);
+
+        // no waiting after the interval elapsed
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        let start = Instant::now();
+        assert_eq!(flow.run(5, &mut TokioSpawner).await, Ok(NodeOutput::Ok(5)));
+        assert!(start.elapsed().as_millis() < 10);
+    }
+}
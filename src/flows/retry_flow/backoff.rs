@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+/// Specifies how long [`RetryFlow`](super::RetryFlow) waits between attempts.
+///
+/// # Examples
+/// ```
+/// use node_flow::flows::retry_flow::Backoff;
+/// use std::time::Duration;
+///
+/// let backoff = Backoff::Exponential {
+///     base: Duration::from_millis(100),
+///     factor: 2,
+///     max: Duration::from_millis(500),
+/// };
+/// assert_eq!(backoff.delay(1), Duration::from_millis(100));
+/// assert_eq!(backoff.delay(2), Duration::from_millis(200));
+/// assert_eq!(backoff.delay(3), Duration::from_millis(400));
+/// assert_eq!(backoff.delay(4), Duration::from_millis(500));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backoff {
+    /// Waits the same amount of time after every attempt.
+    Fixed(Duration),
+    /// Waits `base * factor^(attempt - 1)`, but at most `max`.
+    Exponential {
+        /// The delay after the first attempt.
+        base: Duration,
+        /// The multiplier applied to the delay after each attempt.
+        factor: u32,
+        /// The maximum delay.
+        max: Duration,
+    },
+}
+
+impl Backoff {
+    /// Returns the delay after the given (failed) attempt.
+    ///
+    /// Attempts are numbered from `1`.
+    #[must_use]
+    pub fn delay(&self, attempt: u32) -> Duration {
+        match *self {
+            Self::Fixed(delay) => delay,
+            Self::Exponential { base, factor, max } => factor
+                .checked_pow(attempt.saturating_sub(1))
+                .and_then(|multiplier| base.checked_mul(multiplier))
+                .map_or(max, |delay| delay.min(max)),
+        }
+    }
+}
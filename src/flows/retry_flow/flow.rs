@@ -0,0 +1,276 @@
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use super::Backoff;
+use crate::{
+    context::Clock,
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::NodeResult,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// `RetryFlow` re-runs a node until it succeeds or the maximum number of attempts is reached.
+///
+/// The node is re-run when it returns an **error** or [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail).
+/// Between attempts, the flow waits as specified by [`Backoff`], using the [`Clock`] context trait.
+/// When all attempts are exhausted, the result of the last attempt is returned.
+///
+/// Since the input is replayed for every attempt, it must implement [`Clone`].
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::context::Clock;
+/// use node_flow::flows::RetryFlow;
+/// use node_flow::flows::retry_flow::Backoff;
+/// use std::time::Duration;
+///
+/// #[derive(Clone)]
+/// struct Flaky;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, String, Ctx> for Flaky {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, String> {
+///         // call some flaky external service
+/// #       let service_response = Ok::<_, String>(input);
+///         Ok(NodeOutput::Ok(service_response?))
+///     }
+/// }
+///
+/// struct ExampleCtx;
+/// impl Clock for ExampleCtx // ...
+/// # {
+/// #     async fn sleep(duration: Duration) {}
+/// # }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = RetryFlow::<u8, u8, String, ExampleCtx>::new(
+///         Flaky,
+///         3,
+///         Backoff::Fixed(Duration::from_millis(100)),
+///     );
+///
+///     let mut ctx = ExampleCtx;
+///     let result = flow.run(5, &mut ctx).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(5)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct RetryFlow<Input, Output, Error, Context, NodeType = (), NodeOutput = (), NodeError = ()>
+{
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    _node_oe: PhantomData<fn() -> (NodeOutput, NodeError)>,
+    node: Arc<NodeType>,
+    max_attempts: u32,
+    backoff: Backoff,
+}
+
+impl<Input, Output, Error, Context> RetryFlow<Input, Output, Error, Context> {
+    /// Creates a new [`RetryFlow`] that runs `node` at most `max_attempts` times.
+    ///
+    /// # Parameters
+    /// - `node`: The node to retry.
+    /// - `max_attempts`: The maximum number of attempts (including the first one).
+    /// - `backoff`: Specifies how long to wait between attempts.
+    ///
+    /// # Panics
+    /// Panics if `max_attempts` is `0`.
+    #[expect(clippy::type_repetition_in_bounds)]
+    pub fn new<NodeType, NodeOutput, NodeError>(
+        node: NodeType,
+        max_attempts: u32,
+        backoff: Backoff,
+    ) -> RetryFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    where
+        NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeType: Clone + Send + Sync,
+        NodeOutput: Into<Output>,
+        NodeError: Into<Error>,
+        Input: Clone + Send,
+    {
+        assert!(max_attempts > 0, "max_attempts must be greater than 0");
+        RetryFlow {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node: Arc::new(node),
+            max_attempts,
+            backoff,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Debug
+    for RetryFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryFlow")
+            .field("node", &self.node)
+            .field("max_attempts", &self.max_attempts)
+            .field("backoff", &self.backoff)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Clone
+    for RetryFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node: self.node.clone(),
+            max_attempts: self.max_attempts,
+            backoff: self.backoff,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for RetryFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context> + Clone + Send + Sync,
+    NodeOutput: Into<Output>,
+    NodeError: Into<Error>,
+    Input: Clone + Send,
+    Context: Clock + Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        let mut attempt = 1;
+        loop {
+            let mut node = self.node.as_ref().clone();
+            match node.run(input.clone(), context).await {
+                Ok(NodeOutputStruct::Ok(output)) => return Ok(NodeOutputStruct::Ok(output.into())),
+                Ok(NodeOutputStruct::SoftFail) if attempt >= self.max_attempts => {
+                    return Ok(NodeOutputStruct::SoftFail);
+                }
+                Err(err) if attempt >= self.max_attempts => return Err(err.into()),
+                _ => {}
+            }
+            Context::sleep(self.backoff.delay(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_flow(
+            self,
+            vec![self.node.describe()],
+            vec![Edge::flow_to_node(0), Edge::node_to_flow(0)],
+        )
+        .modify_name(remove_generics_from_name)
+        .with_description(format!("Max attempts: {}", self.max_attempts))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicU32, Ordering},
+        },
+        time::{Duration, Instant},
+    };
+
+    use super::{Backoff, RetryFlow};
+    use crate::{
+        context::test::TokioSpawner,
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct FailTimes {
+        times: u32,
+        soft_fail: bool,
+        attempts: Arc<AtomicU32>,
+    }
+
+    impl FailTimes {
+        fn new(times: u32, soft_fail: bool) -> Self {
+            Self {
+                times,
+                soft_fail,
+                attempts: Arc::default(),
+            }
+        }
+    }
+
+    impl<C: Send> Node<u8, NodeOutput<u8>, u32, C> for FailTimes {
+        async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u8>, u32> {
+            let attempt = self.attempts.fetch_add(1, Ordering::SeqCst) + 1;
+            if attempt > self.times {
+                return Ok(NodeOutput::Ok(input));
+            }
+            if self.soft_fail {
+                return Ok(NodeOutput::SoftFail);
+            }
+            Err(attempt)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow() {
+        let node = FailTimes::new(2, false);
+        let mut flow = RetryFlow::<u8, u64, u32, TokioSpawner>::new(
+            node.clone(),
+            3,
+            Backoff::Fixed(Duration::from_millis(20)),
+        );
+        let start = Instant::now();
+        let res = flow.run(5, &mut TokioSpawner).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(5)));
+        assert_eq!(node.attempts.load(Ordering::SeqCst), 3);
+        assert!(start.elapsed().as_millis() >= 40);
+    }
+
+    #[tokio::test]
+    async fn test_flow_exhausted() {
+        let node = FailTimes::new(5, false);
+        let mut flow = RetryFlow::<u8, u64, u32, TokioSpawner>::new(
+            node.clone(),
+            3,
+            Backoff::Fixed(Duration::ZERO),
+        );
+        let res = flow.run(5, &mut TokioSpawner).await;
+        assert_eq!(res, Err(3));
+        assert_eq!(node.attempts.load(Ordering::SeqCst), 3);
+
+        let node = FailTimes::new(5, true);
+        let mut flow = RetryFlow::<u8, u64, u32, TokioSpawner>::new(
+            node.clone(),
+            2,
+            Backoff::Fixed(Duration::ZERO),
+        );
+        let res = flow.run(5, &mut TokioSpawner).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+        assert_eq!(node.attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_backoff() {
+        let backoff = Backoff::Exponential {
+            base: Duration::from_millis(10),
+            factor: 3,
+            max: Duration::from_secs(1),
+        };
+        assert_eq!(backoff.delay(1), Duration::from_millis(10));
+        assert_eq!(backoff.delay(3), Duration::from_millis(90));
+        assert_eq!(backoff.delay(10), Duration::from_secs(1));
+        assert_eq!(backoff.delay(u32::MAX), Duration::from_secs(1));
+    }
+}
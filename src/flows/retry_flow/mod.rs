@@ -0,0 +1,4 @@
+mod backoff;
+pub use backoff::Backoff;
+mod flow;
+pub use flow::RetryFlow;
@@ -1,11 +1,31 @@
-use std::{marker::PhantomData, sync::Arc};
+use std::{marker::PhantomData, sync::Arc, time::Duration};
 
-use super::SequentialFlow as Flow;
+use super::{
+    DeadlineSequentialFlow, SequentialFlow as Flow, SoftFailPolicySequentialFlow,
+    chain_prepend::ChainPrepend,
+};
 use crate::{
-    flows::{ChainLink, NodeIOE, generic_defs::debug::impl_debug_for_builder},
+    context::Clock,
+    flows::{ChainLink, FlowNode, NodeIOE, generic_defs::debug::impl_debug_for_builder},
     node::{Node, NodeOutput as NodeOutputStruct},
 };
 
+/// Marker trait checking that the output of a node can be converted into the input of the next node.
+///
+/// It is implemented for every type implementing [`Into<NodeInput>`]
+/// and it's used by [`Builder::add_node`] in place of a plain [`Into`] bound,
+/// so that connecting incompatible nodes produces a short error naming both types
+/// instead of a wall of tuple types.
+#[diagnostic::on_unimplemented(
+    message = "output of the previous node (`{Self}`) does not convert into input of the added node (`{NodeInput}`)",
+    label = "input of the added node cannot be created from the output of the previous node",
+    note = "consider implementing `From<{Self}>` for `{NodeInput}` or adding a node that converts between them"
+)]
+pub trait NodeOutputInto<NodeInput>: Into<NodeInput> {}
+
+#[diagnostic::do_not_recommend]
+impl<T, NodeInput> NodeOutputInto<NodeInput> for T where T: Into<NodeInput> {}
+
 /// Builder for [`SequentialFlow`](Flow).
 ///
 /// This builder ensures:
@@ -91,7 +111,7 @@ where
         NodeError: Into<Error>,
         NodeType: Node<NodeInput, NodeOutputStruct<NodeOutput>, NodeError, Context>,
         // Trait bounds for better and nicer errors
-        NodeType: Clone + Send + Sync,
+        NodeType: FlowNode,
         NodeInput: Send,
     {
         Builder {
@@ -132,7 +152,7 @@ where
     /// The new node must satisfy:
     /// - `Self`: `Node<NodeInputType, NodeOutput<NodeOutputType>, NodeErrorType, _>`
     /// - `NodeErrorType`: `Into<Error>`,
-    /// - `LastNodeOutputType`: `Into<NodeInputType>`,
+    /// - `LastNodeOutputType`: `Into<NodeInputType>` (checked by [`NodeOutputInto`]),
     ///
     /// # Returns
     /// A new [`Builder`] with the added node.
@@ -152,11 +172,11 @@ where
         >,
     >
     where
-        LastNodeOutType: Into<NodeInput>,
+        LastNodeOutType: NodeOutputInto<NodeInput>,
         NodeError: Into<Error>,
         NodeType: Node<NodeInput, NodeOutputStruct<NodeOutput>, NodeError, Context>,
         // Trait bounds for better and nicer errors
-        NodeType: Clone + Send + Sync,
+        NodeType: FlowNode,
         NodeInput: Send,
     {
         Builder {
@@ -166,6 +186,100 @@ where
         }
     }
 
+    /// Adds a new node before the first node.
+    ///
+    /// This allows wrapping an already assembled chain of nodes with a preprocessor.
+    ///
+    /// The new node must satisfy:
+    /// - `Self`: `Node<NodeInputType, NodeOutput<NodeOutputType>, NodeErrorType, _>`
+    /// - `Input`: `Into<NodeInputType>`,
+    /// - `NodeErrorType`: `Into<Error>`,
+    /// - `NodeOutputType`: `Into<FirstNodeInputType>` (checked by [`NodeOutputInto`]),
+    ///
+    /// # Returns
+    /// A new [`Builder`] with the added node.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::{Node, NodeOutput};
+    /// use node_flow::flows::SequentialFlow;
+    ///
+    /// #[derive(Clone)]
+    /// struct AddOne;
+    /// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+    ///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+    ///         Ok(NodeOutput::Ok(input + 1))
+    ///     }
+    /// }
+    ///
+    /// #[derive(Clone)]
+    /// struct Double;
+    /// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for Double {
+    ///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+    ///         Ok(NodeOutput::Ok(input * 2))
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread()
+    /// #     .enable_all()
+    /// #     .build()
+    /// #     .unwrap()
+    /// #     .block_on(async {
+    /// async fn main() {
+    ///     let mut flow = SequentialFlow::<u8, u8, (), ()>::builder()
+    ///         .add_node(AddOne)
+    ///         .prepend_node(Double)
+    ///         .build();
+    ///
+    ///     let result = flow.run(5, &mut ()).await;
+    ///     assert_eq!(result, Ok(NodeOutput::Ok(11)));
+    /// }
+    /// # main().await;
+    /// # });
+    /// ```
+    #[expect(clippy::type_complexity, clippy::type_repetition_in_bounds)]
+    pub fn prepend_node<NodeType, NodeInput, NodeOutput, NodeError>(
+        self,
+        node: NodeType,
+    ) -> Builder<
+        Input,
+        Output,
+        Error,
+        Context,
+        <NodeTypes as ChainPrepend<
+            NodeType,
+            ChainLink<OtherNodeIOETypes, NodeIOE<LastNodeInType, LastNodeOutType, LastNodeErrType>>,
+            NodeIOE<NodeInput, NodeOutput, NodeError>,
+        >>::Nodes,
+        <NodeTypes as ChainPrepend<
+            NodeType,
+            ChainLink<OtherNodeIOETypes, NodeIOE<LastNodeInType, LastNodeOutType, LastNodeErrType>>,
+            NodeIOE<NodeInput, NodeOutput, NodeError>,
+        >>::NodeIOETypes,
+    >
+    where
+        Input: Into<NodeInput>,
+        NodeError: Into<Error>,
+        NodeType: Node<NodeInput, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        NodeTypes: ChainPrepend<
+                NodeType,
+                ChainLink<
+                    OtherNodeIOETypes,
+                    NodeIOE<LastNodeInType, LastNodeOutType, LastNodeErrType>,
+                >,
+                NodeIOE<NodeInput, NodeOutput, NodeError>,
+            >,
+        // Trait bounds for better and nicer errors
+        NodeType: FlowNode,
+        NodeInput: Send,
+    {
+        Builder {
+            _ioec: PhantomData,
+            _nodes_io: PhantomData,
+            nodes: self.nodes.prepend(node),
+        }
+    }
+
     /// Finalizes the builder and produces a [`SequentialFlow`](Flow) instance.
     #[expect(clippy::type_complexity)]
     pub fn build(
@@ -185,6 +299,150 @@ where
             _ioec: PhantomData,
             _nodes_io: PhantomData,
             nodes: Arc::new(self.nodes),
+            description: None,
+        }
+    }
+
+    /// Finalizes the builder and produces a [`DeadlineSequentialFlow`] instance,
+    /// which stops once the whole pipeline runs longer than `total`.
+    ///
+    /// The elapsed time is checked (using the [`Clock`] context trait) before each node is started.
+    /// When the budget is exceeded, the flow returns [`NodeOutput::SoftFail`](NodeOutputStruct::SoftFail).
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::{Node, NodeOutput};
+    /// use node_flow::flows::SequentialFlow;
+    /// use node_flow::context::Clock;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Clone)]
+    /// struct Slow;
+    /// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for Slow {
+    ///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+    ///         tokio::time::sleep(Duration::from_millis(20)).await;
+    ///         Ok(NodeOutput::Ok(input + 1))
+    ///     }
+    /// }
+    ///
+    /// struct ExampleCtx;
+    /// impl Clock for ExampleCtx // ...
+    /// # {
+    /// #     fn sleep(duration: Duration) -> impl Future<Output = ()> + Send {
+    /// #         tokio::time::sleep(duration)
+    /// #     }
+    /// # }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread()
+    /// #     .enable_all()
+    /// #     .build()
+    /// #     .unwrap()
+    /// #     .block_on(async {
+    /// async fn main() {
+    ///     let mut flow = SequentialFlow::<u8, u8, (), ExampleCtx>::builder()
+    ///         .add_node(Slow)
+    ///         .add_node(Slow)
+    ///         .add_node(Slow)
+    ///         .build_with_deadline(Duration::from_millis(30));
+    ///
+    ///     let result = flow.run(5, &mut ExampleCtx).await;
+    ///     assert_eq!(result, Ok(NodeOutput::SoftFail));
+    /// }
+    /// # main().await;
+    /// # });
+    /// ```
+    #[expect(clippy::type_complexity)]
+    pub fn build_with_deadline(
+        self,
+        total: Duration,
+    ) -> DeadlineSequentialFlow<
+        Input,
+        Output,
+        Error,
+        Context,
+        NodeTypes,
+        ChainLink<OtherNodeIOETypes, NodeIOE<LastNodeInType, LastNodeOutType, LastNodeErrType>>,
+    >
+    where
+        LastNodeOutType: Into<Output>,
+        // Trait bounds for better and nicer errors
+        Context: Clock,
+    {
+        DeadlineSequentialFlow {
+            flow: self.build(),
+            budget: total,
+        }
+    }
+
+    /// Finalizes the builder and produces a [`SoftFailPolicySequentialFlow`] instance,
+    /// which follows `policy` when a node soft-fails.
+    ///
+    /// With [`Stop`](super::Stop), the flow soft-fails like [`SequentialFlow`](Flow).
+    /// With [`SkipKeepingInput`](super::SkipKeepingInput), soft-failing nodes are skipped
+    /// and their input is passed to the next node.
+    /// Only the latter requires the input of every node to be [`Clone`]
+    /// and convertible into the input of the next node.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::{Node, NodeOutput};
+    /// use node_flow::flows::{SequentialFlow, sequential_flow::SkipKeepingInput};
+    ///
+    /// #[derive(Clone)]
+    /// struct AddOne;
+    /// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+    ///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+    ///         Ok(NodeOutput::Ok(input + 1))
+    ///     }
+    /// }
+    ///
+    /// #[derive(Clone)]
+    /// struct SkipOdd;
+    /// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for SkipOdd {
+    ///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+    ///         if input % 2 == 1 {
+    ///             return Ok(NodeOutput::SoftFail);
+    ///         }
+    ///         Ok(NodeOutput::Ok(input * 10))
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread()
+    /// #     .enable_all()
+    /// #     .build()
+    /// #     .unwrap()
+    /// #     .block_on(async {
+    /// async fn main() {
+    ///     let mut flow = SequentialFlow::<u8, u8, (), ()>::builder()
+    ///         .add_node(SkipOdd)
+    ///         .add_node(AddOne)
+    ///         .build_with_soft_fail_policy(SkipKeepingInput);
+    ///
+    ///     assert_eq!(flow.run(2, &mut ()).await, Ok(NodeOutput::Ok(21)));
+    ///     assert_eq!(flow.run(3, &mut ()).await, Ok(NodeOutput::Ok(4)));
+    /// }
+    /// # main().await;
+    /// # });
+    /// ```
+    #[expect(clippy::type_complexity)]
+    pub fn build_with_soft_fail_policy<Policy>(
+        self,
+        policy: Policy,
+    ) -> SoftFailPolicySequentialFlow<
+        Input,
+        Output,
+        Error,
+        Context,
+        NodeTypes,
+        ChainLink<OtherNodeIOETypes, NodeIOE<LastNodeInType, LastNodeOutType, LastNodeErrType>>,
+        Policy,
+    >
+    where
+        LastNodeOutType: Into<Output>,
+    {
+        SoftFailPolicySequentialFlow {
+            flow: self.build(),
+            policy,
         }
     }
 }
@@ -0,0 +1,53 @@
+use crate::flows::{ChainLink, NodeIOE};
+
+use super::NodeOutputInto;
+
+/// Adds a node before the first node of a chain.
+pub trait ChainPrepend<NodeType, NodeIOETypes, PrependedIOE> {
+    type Nodes;
+    type NodeIOETypes;
+
+    fn prepend(self, node: NodeType) -> Self::Nodes;
+}
+
+impl<
+    NodeType,
+    NodeInType,
+    NodeOutType,
+    NodeErrType,
+    Head,
+    HeadNodeInType,
+    HeadNodeOutType,
+    HeadNodeErrType,
+>
+    ChainPrepend<
+        NodeType,
+        ChainLink<(), NodeIOE<HeadNodeInType, HeadNodeOutType, HeadNodeErrType>>,
+        NodeIOE<NodeInType, NodeOutType, NodeErrType>,
+    > for (Head,)
+where
+    NodeOutType: NodeOutputInto<HeadNodeInType>,
+{
+    type Nodes = ((NodeType,), Head);
+    type NodeIOETypes = ChainLink<
+        ChainLink<(), NodeIOE<NodeInType, NodeOutType, NodeErrType>>,
+        NodeIOE<HeadNodeInType, HeadNodeOutType, HeadNodeErrType>,
+    >;
+
+    fn prepend(self, node: NodeType) -> Self::Nodes {
+        ((node,), self.0)
+    }
+}
+
+impl<NodeType, PrependedIOE, Head, Tail, HeadIOETypes, TailIOE>
+    ChainPrepend<NodeType, ChainLink<HeadIOETypes, TailIOE>, PrependedIOE> for (Head, Tail)
+where
+    Head: ChainPrepend<NodeType, HeadIOETypes, PrependedIOE>,
+{
+    type Nodes = (Head::Nodes, Tail);
+    type NodeIOETypes = ChainLink<Head::NodeIOETypes, TailIOE>;
+
+    fn prepend(self, node: NodeType) -> Self::Nodes {
+        (self.0.prepend(node), self.1)
+    }
+}
@@ -1,10 +1,24 @@
+use std::time::Instant;
+
 use crate::{
+    context::Clock,
     flows::{ChainLink, NodeIOE, NodeResult},
     node::{Node, NodeOutput as NodeOutputStruct},
 };
 
 pub trait ChainRunSequential<Input, Output, Context, T> {
     fn run(&self, input: Input, context: &mut Context) -> impl Future<Output = Output> + Send;
+
+    /// Runs nodes like [`ChainRunSequential::run`], but soft-fails
+    /// when the `deadline` passed before a node is started.
+    fn run_until(
+        &self,
+        input: Input,
+        context: &mut Context,
+        deadline: Instant,
+    ) -> impl Future<Output = Output> + Send
+    where
+        Context: Clock;
 }
 
 impl<
@@ -50,6 +64,28 @@ where
         }
         Ok(NodeOutputStruct::SoftFail)
     }
+
+    async fn run_until(
+        &self,
+        input: Input,
+        context: &mut Context,
+        deadline: Instant,
+    ) -> NodeResult<Output, Error>
+    where
+        Context: Clock,
+    {
+        let (head, tail) = self;
+        if let NodeOutputStruct::Ok(input) = head.run_until(input, context, deadline).await?
+            && Context::now() < deadline
+        {
+            let output = tail.clone().run(input, context).await.map_err(Into::into)?;
+            return Ok(match output {
+                NodeOutputStruct::SoftFail => NodeOutputStruct::SoftFail,
+                NodeOutputStruct::Ok(output) => NodeOutputStruct::Ok(output.into()),
+            });
+        }
+        Ok(NodeOutputStruct::SoftFail)
+    }
 }
 
 impl<Input, Output, Error, Context, HeadNodeInType, HeadNodeOutType, HeadNodeErrType, Head>
@@ -81,4 +117,110 @@ where
             NodeOutputStruct::Ok(output) => NodeOutputStruct::Ok(output.into()),
         })
     }
+
+    async fn run_until(
+        &self,
+        input: Input,
+        context: &mut Context,
+        deadline: Instant,
+    ) -> NodeResult<Output, Error>
+    where
+        Context: Clock,
+    {
+        if Context::now() >= deadline {
+            return Ok(NodeOutputStruct::SoftFail);
+        }
+        self.run(input, context).await
+    }
+}
+
+/// Runs nodes like [`ChainRunSequential::run`], but nodes which soft-fail are skipped
+/// and their input is passed to the next node instead.
+pub trait ChainRunSkipping<Input, Output, Context, T> {
+    fn run_skipping(
+        &self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = Output> + Send;
+}
+
+impl<
+    Input,
+    Output,
+    Error,
+    Context,
+    HeadIOETypes,
+    TailNodeInType,
+    TailNodeOutType,
+    TailNodeErrType,
+    Head,
+    Tail,
+>
+    ChainRunSkipping<
+        Input,
+        NodeResult<Output, Error>,
+        Context,
+        ChainLink<HeadIOETypes, NodeIOE<TailNodeInType, TailNodeOutType, TailNodeErrType>>,
+    > for (Head, Tail)
+where
+    Head: ChainRunSkipping<Input, NodeResult<TailNodeInType, Error>, Context, HeadIOETypes> + Sync,
+    Tail: Node<TailNodeInType, NodeOutputStruct<TailNodeOutType>, TailNodeErrType, Context>
+        + Clone
+        + Send
+        + Sync,
+    TailNodeInType: Clone + Into<Output> + Send,
+    TailNodeErrType: Into<Error>,
+    TailNodeOutType: Into<Output>,
+    Input: Send,
+    Error: Send,
+    Context: Send,
+{
+    async fn run_skipping(&self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        let (head, tail) = self;
+        let NodeOutputStruct::Ok(input) = head.run_skipping(input, context).await? else {
+            return Ok(NodeOutputStruct::SoftFail);
+        };
+        let output = tail
+            .clone()
+            .run(input.clone(), context)
+            .await
+            .map_err(Into::into)?;
+        Ok(NodeOutputStruct::Ok(match output {
+            NodeOutputStruct::SoftFail => input.into(),
+            NodeOutputStruct::Ok(output) => output.into(),
+        }))
+    }
+}
+
+impl<Input, Output, Error, Context, HeadNodeInType, HeadNodeOutType, HeadNodeErrType, Head>
+    ChainRunSkipping<
+        Input,
+        NodeResult<Output, Error>,
+        Context,
+        ChainLink<(), NodeIOE<HeadNodeInType, HeadNodeOutType, HeadNodeErrType>>,
+    > for (Head,)
+where
+    Input: Into<HeadNodeInType> + Send,
+    Head: Node<HeadNodeInType, NodeOutputStruct<HeadNodeOutType>, HeadNodeErrType, Context>
+        + Clone
+        + Send
+        + Sync,
+    HeadNodeInType: Clone + Into<Output> + Send,
+    HeadNodeErrType: Into<Error>,
+    HeadNodeOutType: Into<Output>,
+    Context: Send,
+{
+    async fn run_skipping(&self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        let input = input.into();
+        let output = self
+            .0
+            .clone()
+            .run(input.clone(), context)
+            .await
+            .map_err(Into::into)?;
+        Ok(NodeOutputStruct::Ok(match output {
+            NodeOutputStruct::SoftFail => input.into(),
+            NodeOutputStruct::Ok(output) => output.into(),
+        }))
+    }
 }
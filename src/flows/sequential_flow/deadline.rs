@@ -0,0 +1,162 @@
+use std::{fmt::Debug, time::Duration};
+
+use super::{SequentialFlow, chain_run::ChainRunSequential as ChainRun};
+use crate::{
+    context::Clock,
+    describe::{Description, DescriptionBase, remove_generics_from_name},
+    flows::{NodeResult, chain_describe::ChainDescribe},
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// `DeadlineSequentialFlow` is a [`SequentialFlow`] with a time budget for the whole pipeline.
+///
+/// The elapsed time is measured (using the [`Clock`] context trait) from the start of the flow
+/// and checked between nodes.
+/// When the budget is exceeded before the next node is started,
+/// the flow stops and returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail).
+/// A node that is already running is not interrupted,
+/// wrap it in a [`TimeoutFlow`](crate::flows::TimeoutFlow) if that is needed.
+///
+/// Otherwise it behaves the same as [`SequentialFlow`].
+///
+/// Created by [`Builder::build_with_deadline`](super::Builder::build_with_deadline).
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+pub struct DeadlineSequentialFlow<Input, Output, Error, Context, NodeTypes = (), NodeIOETypes = ()>
+{
+    pub(super) flow: SequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>,
+    pub(super) budget: Duration,
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+    DeadlineSequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+{
+    /// Returns the time budget of this flow.
+    #[must_use]
+    pub const fn budget(&self) -> Duration {
+        self.budget
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes> Debug
+    for DeadlineSequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+where
+    SequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeadlineSequentialFlow")
+            .field("flow", &self.flow)
+            .field("budget", &self.budget)
+            .finish()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes> Clone
+    for DeadlineSequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+{
+    fn clone(&self) -> Self {
+        Self {
+            flow: self.flow.clone(),
+            budget: self.budget,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for DeadlineSequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+where
+    NodeTypes: ChainRun<Input, NodeResult<Output, Error>, Context, NodeIOETypes>
+        + ChainDescribe<Context, NodeIOETypes>,
+    Context: Clock,
+{
+    fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = NodeResult<Output, Error>> + Send {
+        let deadline = Context::now() + self.budget;
+        ChainRun::run_until(self.flow.nodes.as_ref(), input, context, deadline)
+    }
+
+    fn describe(&self) -> Description {
+        let mut description = self.flow.describe();
+        let base = description.get_base_mut();
+        base.r#type = DescriptionBase::from_node(self).r#type;
+        if base.description.is_none() {
+            base.description = Some(format!("Deadline: {:?}", self.budget));
+        }
+        description.modify_name(remove_generics_from_name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
+
+    use super::super::SequentialFlow as Flow;
+    use crate::{
+        context::test::TokioSpawner,
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct SlowNode(Arc<AtomicUsize>);
+
+    impl<C: Send> Node<u8, NodeOutput<u8>, (), C> for SlowNode {
+        async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u8>, ()> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(40)).await;
+            Ok(NodeOutput::Ok(input + 1))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_budget_exceeded() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let mut flow = Flow::<u8, u8, (), TokioSpawner>::builder()
+            .add_node(SlowNode(runs.clone()))
+            .add_node(SlowNode(runs.clone()))
+            .add_node(SlowNode(runs.clone()))
+            .build_with_deadline(Duration::from_millis(60));
+
+        let res = flow.run(0, &mut TokioSpawner).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+        // the budget was exceeded while the second node was running
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_budget_not_exceeded() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let mut flow = Flow::<u8, u8, (), TokioSpawner>::builder()
+            .add_node(SlowNode(runs.clone()))
+            .add_node(SlowNode(runs.clone()))
+            .build_with_deadline(Duration::from_secs(5));
+
+        let res = flow.run(0, &mut TokioSpawner).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(2)));
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+
+        let desc = flow.describe();
+        assert_eq!(
+            desc.get_base_ref().r#type.name,
+            "node_flow::flows::sequential_flow::deadline::DeadlineSequentialFlow"
+        );
+        assert_eq!(
+            desc.get_base_ref().description.as_deref(),
+            Some("Deadline: 5s")
+        );
+        assert_eq!(desc.node_count(), 2);
+    }
+}
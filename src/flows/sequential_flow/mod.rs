@@ -1,6 +1,11 @@
 mod builder;
 pub use builder::*;
+mod chain_prepend;
 mod chain_run;
+mod deadline;
+pub use deadline::DeadlineSequentialFlow;
+mod soft_fail;
+pub use soft_fail::{SkipKeepingInput, SoftFailPolicySequentialFlow, Stop};
 
 use crate::{
     describe::{Description, Edge, remove_generics_from_name},
@@ -35,7 +40,8 @@ define_flow!(
     /// Nodes are executed in order of insertion until **all** succeed or **any** node "hard" fails.
     ///
     /// - If a node returns [`NodeOutput::Ok`](crate::node::NodeOutput::Ok), that value is then fed into the next node.
-    /// - If a node returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail), the flow soft-fails.
+    /// - If a node returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail), the flow soft-fails
+    ///   (see [`Builder::build_with_soft_fail_policy`] for skipping soft-failing nodes instead).
     /// - If a node returns an **error**, then that error is returned.
     ///
     /// # Type Parameters
@@ -114,4 +120,71 @@ mod test {
             ChainRun::<_, Result<NodeOutput<u128>, ()>, (), _>::run(&node, true, &mut ()).await;
         assert_eq!(res, Ok(NodeOutput::Ok(1)));
     }
+
+    #[tokio::test]
+    async fn test_prepend_node() {
+        #[derive(Clone)]
+        struct Push(u8);
+
+        impl<C: Send> Node<Vec<u8>, NodeOutput<Vec<u8>>, (), C> for Push {
+            async fn run(
+                &mut self,
+                mut input: Vec<u8>,
+                _context: &mut C,
+            ) -> Result<NodeOutput<Vec<u8>>, ()> {
+                input.push(self.0);
+                Ok(NodeOutput::Ok(input))
+            }
+        }
+
+        let mut flow = Flow::<Vec<u8>, Vec<u8>, (), ()>::builder()
+            .add_node(Push(1))
+            .add_node(Push(2))
+            .prepend_node(Push(0))
+            .add_node(Push(3))
+            .build();
+        let res = flow.run(Vec::new(), &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(vec![0, 1, 2, 3])));
+
+        let mut flow = Flow::<bool, u128, (), ()>::builder()
+            .add_node(Passer::<u16, u64, ()>::new())
+            .prepend_node(Passer::<bool, u8, ()>::new())
+            .build();
+        let res = flow.run(true, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(1)));
+    }
+
+    #[tokio::test]
+    async fn test_macro() {
+        let mut flow = crate::sequential_flow![<bool, u128, (), ()>
+            Passer::<u8, u16, ()>::new(),
+            Passer::<u32, u64, ()>::new(),
+        ];
+        assert_eq!(flow.run(true, &mut ()).await, Ok(NodeOutput::Ok(1)));
+
+        let mut flow: Flow<u8, u64, (), (), _, _> =
+            crate::sequential_flow![Passer::<u8, u16, ()>::new(), Passer::<u32, u64, ()>::new()];
+        assert_eq!(flow.run(3, &mut ()).await, Ok(NodeOutput::Ok(3)));
+    }
+
+    #[test]
+    fn test_with_description() {
+        let flow = Flow::<u8, u8, (), ()>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .build();
+        assert_eq!(flow.describe().get_base_ref().description, None);
+
+        let flow = flow.with_description("Labeled flow");
+        let cloned = flow.clone();
+        let desc = cloned.describe();
+        assert_eq!(
+            desc.get_base_ref().description.as_deref(),
+            Some("Labeled flow")
+        );
+        assert!(desc.get_base_ref().r#type.name.contains("SequentialFlow"));
+        assert_eq!(
+            desc.get_base_ref().description,
+            flow.describe().get_base_ref().description
+        );
+    }
 }
@@ -0,0 +1,232 @@
+use std::fmt::Debug;
+
+use super::{
+    SequentialFlow,
+    chain_run::{ChainRunSequential as ChainRun, ChainRunSkipping},
+};
+use crate::{
+    describe::{Description, Type, remove_generics_from_name},
+    flows::{NodeResult, chain_describe::ChainDescribe},
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// Marker for [`SoftFailPolicySequentialFlow`] that stops the flow when a node soft-fails.
+///
+/// The flow then returns [`NodeOutput::SoftFail`](NodeOutputStruct::SoftFail),
+/// same as [`SequentialFlow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stop;
+
+/// Marker for [`SoftFailPolicySequentialFlow`] that skips nodes which soft-fail.
+///
+/// The input of the skipped node is passed to the next node instead of its output.
+/// This requires the input of every node to be [`Clone`]
+/// and convertible into the input of the next node (or the output of the flow).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SkipKeepingInput;
+
+/// `SoftFailPolicySequentialFlow` is a [`SequentialFlow`] with a configurable behavior on soft-fail.
+///
+/// When a node returns [`NodeOutput::SoftFail`](NodeOutputStruct::SoftFail),
+/// the flow follows its policy ([`Stop`] or [`SkipKeepingInput`]).
+/// Otherwise it behaves the same as [`SequentialFlow`].
+///
+/// Created by [`Builder::build_with_soft_fail_policy`](super::Builder::build_with_soft_fail_policy).
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+/// - `Policy`: The soft-fail policy, either [`Stop`] or [`SkipKeepingInput`].
+pub struct SoftFailPolicySequentialFlow<
+    Input,
+    Output,
+    Error,
+    Context,
+    NodeTypes = (),
+    NodeIOETypes = (),
+    Policy = Stop,
+> {
+    pub(super) flow: SequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>,
+    pub(super) policy: Policy,
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes, Policy>
+    SoftFailPolicySequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes, Policy>
+{
+    /// Returns the soft-fail policy of this flow.
+    #[must_use]
+    pub const fn policy(&self) -> &Policy {
+        &self.policy
+    }
+
+    fn describe_with_policy(&self) -> Description
+    where
+        NodeTypes: ChainDescribe<Context, NodeIOETypes>,
+        Policy: Debug,
+        SequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>:
+            Node<Input, NodeOutputStruct<Output>, Error, Context>,
+    {
+        let mut description = self.flow.describe();
+        let base = description.get_base_mut();
+        base.r#type = Type::of::<Self>();
+        if base.description.is_none() {
+            base.description = Some(format!("Soft fail policy: {:?}", self.policy));
+        }
+        description.modify_name(remove_generics_from_name)
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes, Policy> Debug
+    for SoftFailPolicySequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes, Policy>
+where
+    SequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes>: Debug,
+    Policy: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SoftFailPolicySequentialFlow")
+            .field("flow", &self.flow)
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes, Policy> Clone
+    for SoftFailPolicySequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes, Policy>
+where
+    Policy: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            flow: self.flow.clone(),
+            policy: self.policy.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for SoftFailPolicySequentialFlow<Input, Output, Error, Context, NodeTypes, NodeIOETypes, Stop>
+where
+    NodeTypes: ChainRun<Input, NodeResult<Output, Error>, Context, NodeIOETypes>
+        + ChainDescribe<Context, NodeIOETypes>,
+{
+    fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = NodeResult<Output, Error>> + Send {
+        ChainRun::run(self.flow.nodes.as_ref(), input, context)
+    }
+
+    fn describe(&self) -> Description {
+        self.describe_with_policy()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeTypes, NodeIOETypes>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for SoftFailPolicySequentialFlow<
+        Input,
+        Output,
+        Error,
+        Context,
+        NodeTypes,
+        NodeIOETypes,
+        SkipKeepingInput,
+    >
+where
+    NodeTypes: ChainRunSkipping<Input, NodeResult<Output, Error>, Context, NodeIOETypes>
+        + ChainRun<Input, NodeResult<Output, Error>, Context, NodeIOETypes>
+        + ChainDescribe<Context, NodeIOETypes>,
+{
+    fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = NodeResult<Output, Error>> + Send {
+        ChainRunSkipping::run_skipping(self.flow.nodes.as_ref(), input, context)
+    }
+
+    fn describe(&self) -> Description {
+        self.describe_with_policy()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{super::SequentialFlow as Flow, SkipKeepingInput, Stop};
+    use crate::{
+        flows::tests::{Passer, SoftFailNode},
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct AddOne;
+
+    impl<C: Send> Node<u16, NodeOutput<u16>, (), C> for AddOne {
+        async fn run(&mut self, input: u16, _context: &mut C) -> Result<NodeOutput<u16>, ()> {
+            Ok(NodeOutput::Ok(input + 1))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stop() {
+        let mut flow = Flow::<u8, u32, (), ()>::builder()
+            .add_node(Passer::<u8, u16, ()>::new())
+            .add_node(SoftFailNode::<u16, u16, ()>::new())
+            .add_node(AddOne)
+            .build_with_soft_fail_policy(Stop);
+
+        let res = flow.run(5, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+        assert_eq!(flow.policy(), &Stop);
+    }
+
+    #[tokio::test]
+    async fn test_stop_non_clone_input() {
+        struct NotClone(u16);
+
+        #[derive(Clone)]
+        struct Unwrap;
+
+        impl<C: Send> Node<NotClone, NodeOutput<u16>, (), C> for Unwrap {
+            async fn run(
+                &mut self,
+                input: NotClone,
+                _context: &mut C,
+            ) -> Result<NodeOutput<u16>, ()> {
+                Ok(NodeOutput::Ok(input.0))
+            }
+        }
+
+        let mut flow = Flow::<NotClone, u16, (), ()>::builder()
+            .add_node(Unwrap)
+            .add_node(AddOne)
+            .build_with_soft_fail_policy(Stop);
+
+        let res = flow.run(NotClone(5), &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(6)));
+    }
+
+    #[tokio::test]
+    async fn test_skip_keeping_input() {
+        let mut flow = Flow::<u8, u32, (), ()>::builder()
+            .add_node(SoftFailNode::<u8, u16, ()>::new())
+            .add_node(AddOne)
+            .add_node(SoftFailNode::<u16, u16, ()>::new())
+            .add_node(AddOne)
+            .build_with_soft_fail_policy(SkipKeepingInput);
+
+        let res = flow.run(5, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(7)));
+
+        let desc = flow.describe();
+        assert_eq!(
+            desc.get_base_ref().description.as_deref(),
+            Some("Soft fail policy: SkipKeepingInput")
+        );
+        assert_eq!(desc.node_count(), 4);
+    }
+}
@@ -0,0 +1,166 @@
+use std::{ops::Deref, sync::Arc};
+
+/// `Shared` is a cheaply clonable wrapper for large inputs.
+///
+/// Flows that execute nodes (branches) in parallel, like [`ParallelFlow`](crate::flows::ParallelFlow)
+/// and [`OneOfParallelFlow`](crate::flows::OneOfParallelFlow),
+/// clone the input for each node (branch).
+/// For large payloads, this deep clone can be expensive.
+///
+/// Wrapping the input in `Shared` makes the clone just an [`Arc`] reference count increment,
+/// so all nodes (branches) share the same payload.
+/// The payload is accessed through [`Deref`].
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::{ParallelFlow, Shared};
+/// use node_flow::context::{Fork, Join};
+///
+/// #[derive(Clone)]
+/// struct Len;
+/// #[derive(Clone)]
+/// struct Sum;
+///
+/// struct ExampleCtx;
+/// impl Fork for ExampleCtx // ...
+/// # { fn fork(&self) -> Self { Self } }
+/// impl Join for ExampleCtx // ...
+/// # { fn join(&mut self, others: Box<[Self]>) {} }
+///
+/// impl<Ctx: Send> Node<Shared<Vec<u64>>, NodeOutput<u64>, (), Ctx> for Len {
+///     async fn run(&mut self, input: Shared<Vec<u64>>, _: &mut Ctx) -> Result<NodeOutput<u64>, ()> {
+///         Ok(NodeOutput::Ok(input.len() as u64))
+///     }
+/// }
+///
+/// impl<Ctx: Send> Node<Shared<Vec<u64>>, NodeOutput<u64>, (), Ctx> for Sum {
+///     async fn run(&mut self, input: Shared<Vec<u64>>, _: &mut Ctx) -> Result<NodeOutput<u64>, ()> {
+///         Ok(NodeOutput::Ok(input.iter().sum()))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = ParallelFlow::<Shared<Vec<u64>>, u64, (), _>::builder()
+///         .add_node(Len)
+///         .add_node(Sum)
+///         .build(async |input: ((NodeOutput<u64>,), NodeOutput<u64>), _: &mut ExampleCtx| {
+///             let ((len,), sum) = input;
+///             Ok(NodeOutput::Ok(len.ok().unwrap_or(0) + sum.ok().unwrap_or(0)))
+///         });
+///
+///     // the vector is not cloned for each branch
+///     let payload = Shared::new(vec![1; 1_000_000]);
+///     let result = flow.run(payload, &mut ExampleCtx).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(2_000_000)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Shared<T: ?Sized>(Arc<T>);
+
+impl<T> Shared<T> {
+    /// Creates a new [`Shared`] value.
+    #[must_use]
+    pub fn new(val: T) -> Self {
+        Self(Arc::new(val))
+    }
+}
+
+impl<T: ?Sized> Shared<T> {
+    /// Returns the underlying [`Arc`].
+    #[must_use]
+    pub fn into_arc(self) -> Arc<T> {
+        self.0
+    }
+
+    /// Returns `true` if both values point to the same allocation.
+    ///
+    /// See also [`Arc::ptr_eq`].
+    #[must_use]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+}
+
+impl<T: ?Sized> Clone for Shared<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ?Sized> Deref for Shared<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> AsRef<T> for Shared<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> From<T> for Shared<T> {
+    fn from(val: T) -> Self {
+        Self::new(val)
+    }
+}
+
+impl<T: ?Sized> From<Arc<T>> for Shared<T> {
+    fn from(val: Arc<T>) -> Self {
+        Self(val)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Shared;
+    use crate::{
+        context::storage::local_storage::LocalStorageImpl,
+        flows::ParallelFlow,
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct ReturnInput;
+
+    impl<C: Send> Node<Shared<String>, NodeOutput<Shared<String>>, (), C> for ReturnInput {
+        async fn run(
+            &mut self,
+            input: Shared<String>,
+            _context: &mut C,
+        ) -> Result<NodeOutput<Shared<String>>, ()> {
+            Ok(NodeOutput::Ok(input))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow_shares_input() {
+        let input = Shared::new("payload".to_owned());
+        let mut st = LocalStorageImpl::new();
+        let mut flow =
+            ParallelFlow::<Shared<String>, (Shared<String>, Shared<String>), (), _>::builder()
+                .add_node(ReturnInput)
+                .add_node(ReturnInput)
+                .build(async |output, _context: &mut LocalStorageImpl| {
+                    let ((NodeOutput::Ok(a),), NodeOutput::Ok(b)) = output else {
+                        return Ok(NodeOutput::SoftFail);
+                    };
+                    Ok(NodeOutput::Ok((a, b)))
+                });
+        let Ok(NodeOutput::Ok((a, b))) = flow.run(input.clone(), &mut st).await else {
+            panic!("flow failed");
+        };
+        assert!(Shared::ptr_eq(&a, &input));
+        assert!(Shared::ptr_eq(&b, &input));
+    }
+}
@@ -0,0 +1,239 @@
+use std::{fmt::Debug, marker::PhantomData, sync::Arc, time::Duration};
+
+use super::race::race;
+use crate::{
+    context::Clock,
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::NodeResult,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+type TimeoutError<Error> = Arc<dyn Fn() -> Error + Send + Sync>;
+
+/// `TimeoutFlow` limits how long a node can run.
+///
+/// The node is raced against a timer (using the [`Clock`] context trait).
+/// - If the node finishes in time, its result is returned.
+/// - If the timer fires first, the node is dropped (canceled) and the flow returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail),
+///   or an error when [`TimeoutFlow::with_timeout_error`] was used.
+///
+/// The node is raced in place instead of being spawned as a separate task.
+/// A spawned task has to be `'static`, which would require the node, its input and a forked context
+/// to be moved into it, so `TimeoutFlow` would only accept `'static` nodes and forkable contexts.
+/// Dropping the node future on timeout cancels it the same way [`Task::cancel`](crate::context::Task::cancel) would,
+/// but only at its next `.await` point, so a node that blocks the thread cannot be interrupted.
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::context::Clock;
+/// use node_flow::flows::TimeoutFlow;
+/// use std::time::Duration;
+///
+/// #[derive(Clone)]
+/// struct Stalling;
+///
+/// impl<Ctx: Clock + Send> Node<u8, NodeOutput<u8>, String, Ctx> for Stalling {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, String> {
+///         Ctx::sleep(Duration::from_secs(60)).await;
+///         Ok(NodeOutput::Ok(input))
+///     }
+/// }
+///
+/// struct ExampleCtx;
+/// impl Clock for ExampleCtx // ...
+/// # {
+/// #     fn sleep(duration: Duration) -> impl Future<Output = ()> + Send {
+/// #         tokio::time::sleep(duration)
+/// #     }
+/// # }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = TimeoutFlow::<u8, u8, String, ExampleCtx>::new(
+///         Stalling,
+///         Duration::from_millis(10),
+///     )
+///     .with_timeout_error(|| "timed out".to_owned());
+///
+///     let mut ctx = ExampleCtx;
+///     let result = flow.run(5, &mut ctx).await;
+///     assert_eq!(result, Err("timed out".to_owned()));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct TimeoutFlow<
+    Input,
+    Output,
+    Error,
+    Context,
+    NodeType = (),
+    NodeOutput = (),
+    NodeError = (),
+> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    _node_oe: PhantomData<fn() -> (NodeOutput, NodeError)>,
+    node: NodeType,
+    timeout: Duration,
+    timeout_error: Option<TimeoutError<Error>>,
+}
+
+impl<Input, Output, Error, Context> TimeoutFlow<Input, Output, Error, Context> {
+    /// Creates a new [`TimeoutFlow`] that lets `node` run for at most `timeout`.
+    ///
+    /// By default, the flow returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail) on timeout.
+    pub fn new<NodeType, NodeOutput, NodeError>(
+        node: NodeType,
+        timeout: Duration,
+    ) -> TimeoutFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    where
+        NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeOutput: Into<Output>,
+        NodeError: Into<Error>,
+    {
+        TimeoutFlow {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node,
+            timeout,
+            timeout_error: None,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    TimeoutFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+{
+    /// Makes the flow return an error created by `timeout_error` on timeout
+    /// instead of [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail).
+    #[must_use]
+    pub fn with_timeout_error<F>(mut self, timeout_error: F) -> Self
+    where
+        F: Fn() -> Error + Send + Sync + 'static,
+    {
+        self.timeout_error = Some(Arc::new(timeout_error));
+        self
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Debug
+    for TimeoutFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TimeoutFlow")
+            .field("node", &self.node)
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Clone
+    for TimeoutFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node: self.node.clone(),
+            timeout: self.timeout,
+            timeout_error: self.timeout_error.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for TimeoutFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context> + Send,
+    NodeOutput: Into<Output>,
+    NodeError: Into<Error>,
+    Input: Send,
+    Context: Clock + Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        let timer = Context::sleep(self.timeout);
+        match race(self.node.run(input, context), timer).await {
+            Some(Ok(NodeOutputStruct::Ok(output))) => Ok(NodeOutputStruct::Ok(output.into())),
+            Some(Ok(NodeOutputStruct::SoftFail)) => Ok(NodeOutputStruct::SoftFail),
+            Some(Err(err)) => Err(err.into()),
+            None => self
+                .timeout_error
+                .as_ref()
+                .map_or(Ok(NodeOutputStruct::SoftFail), |timeout_error| {
+                    Err(timeout_error())
+                }),
+        }
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_flow(
+            self,
+            vec![self.node.describe()],
+            vec![Edge::flow_to_node(0), Edge::node_to_flow(0)],
+        )
+        .modify_name(remove_generics_from_name)
+        .with_description(format!("Timeout: {:?}", self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::TimeoutFlow;
+    use crate::{
+        context::test::TokioSpawner,
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct SleepNode(u64);
+
+    impl<C: Send> Node<u8, NodeOutput<u8>, String, C> for SleepNode {
+        async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u8>, String> {
+            tokio::time::sleep(Duration::from_millis(self.0)).await;
+            Ok(NodeOutput::Ok(input))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow() {
+        let mut flow =
+            TimeoutFlow::<u8, u64, String, _>::new(SleepNode(10), Duration::from_millis(100));
+        let res = flow.run(5, &mut TokioSpawner).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(5)));
+    }
+
+    #[tokio::test]
+    async fn test_flow_timeout() {
+        let mut flow =
+            TimeoutFlow::<u8, u64, String, _>::new(SleepNode(1000), Duration::from_millis(20));
+        let start = Instant::now();
+        let res = flow.run(5, &mut TokioSpawner).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+        assert!(start.elapsed().as_millis() < 500);
+
+        let timeout = Duration::from_millis(20);
+        let mut flow = flow.with_timeout_error(move || format!("timed out after {timeout:?}"));
+        let res = flow.run(5, &mut TokioSpawner).await;
+        assert_eq!(res, Err("timed out after 20ms".to_owned()));
+    }
+}
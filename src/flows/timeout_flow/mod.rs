@@ -0,0 +1,3 @@
+mod flow;
+pub use flow::TimeoutFlow;
+mod race;
@@ -0,0 +1,19 @@
+use std::{future::poll_fn, pin::pin, task::Poll};
+
+/// Polls `fut` until it completes or `timer` fires.
+///
+/// Returns `None` when `timer` fired first, `fut` is dropped in that case.
+pub async fn race<F>(fut: F, timer: impl Future<Output = ()>) -> Option<F::Output>
+where
+    F: Future,
+{
+    let mut fut = pin!(fut);
+    let mut timer = pin!(timer);
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = fut.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+        timer.as_mut().poll(cx).map(|()| None)
+    })
+    .await
+}
@@ -0,0 +1,301 @@
+use std::{fmt::Debug, marker::PhantomData, time::Instant};
+
+use tracing::{Instrument, field};
+
+use crate::{
+    describe::Description,
+    flows::NodeResult,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// `TracedFlow` wraps a node and runs it inside of a [`tracing`] span.
+///
+/// Every run creates an `INFO` span named `node_run` with the following fields:
+/// - `node`: The type name of the wrapped node taken from its [`Description`].
+/// - `status`: The outcome of the run, one of `ok`, `soft_fail` or `err`.
+/// - `elapsed`: How long the run took.
+///
+/// The span is entered whenever the wrapped node is polled,
+/// so spans and events emitted by the node are nested under it.
+///
+/// The description of this flow is the description of the wrapped node,
+/// so tracing doesn't show up in visualizations.
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::TracedFlow;
+///
+/// #[derive(Clone)]
+/// struct AddOne;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         Ok(NodeOutput::Ok(input + 1))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = TracedFlow::<u8, u8, (), ()>::new(AddOne);
+///
+///     // with a subscriber installed, the run is recorded in a `node_run` span
+///     let result = flow.run(5, &mut ()).await;
+///     assert_eq!(result, Ok(NodeOutput::Ok(6)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct TracedFlow<Input, Output, Error, Context, NodeType = (), NodeOutput = (), NodeError = ()>
+{
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    _node_oe: PhantomData<fn() -> (NodeOutput, NodeError)>,
+    node: NodeType,
+    /// Type name of the wrapped node.
+    name: String,
+}
+
+impl<Input, Output, Error, Context> TracedFlow<Input, Output, Error, Context> {
+    /// Creates a new [`TracedFlow`] that traces runs of `node`.
+    pub fn new<NodeType, NodeOutput, NodeError>(
+        node: NodeType,
+    ) -> TracedFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    where
+        NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeOutput: Into<Output>,
+        NodeError: Into<Error>,
+    {
+        let name = node.describe().get_base_ref().r#type.name.clone();
+        TracedFlow {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node,
+            name,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Debug
+    for TracedFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TracedFlow")
+            .field("node", &self.node)
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Clone
+    for TracedFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node: self.node.clone(),
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for TracedFlow<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context> + Send,
+    NodeOutput: Into<Output>,
+    NodeError: Into<Error>,
+    Input: Send,
+    Context: Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        let span = tracing::info_span!(
+            "node_run",
+            node = self.name.as_str(),
+            status = field::Empty,
+            elapsed = field::Empty,
+        );
+        let start = Instant::now();
+        let res = self.node.run(input, context).instrument(span.clone()).await;
+        let status = match &res {
+            Ok(NodeOutputStruct::Ok(_)) => "ok",
+            Ok(NodeOutputStruct::SoftFail) => "soft_fail",
+            Err(_) => "err",
+        };
+        span.record("status", status);
+        span.record("elapsed", field::debug(start.elapsed()));
+
+        match res {
+            Ok(NodeOutputStruct::Ok(output)) => Ok(NodeOutputStruct::Ok(output.into())),
+            Ok(NodeOutputStruct::SoftFail) => Ok(NodeOutputStruct::SoftFail),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn describe(&self) -> Description {
+        self.node.describe()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        collections::HashMap,
+        fmt::Debug,
+        sync::{
+            Arc, Mutex,
+            atomic::{AtomicU64, Ordering},
+        },
+    };
+
+    use tracing::{
+        Event, Metadata, Subscriber,
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+    };
+
+    use super::TracedFlow;
+    use crate::{
+        describe::Description,
+        flows::tests::{Passer, SoftFailNode},
+        node::{Node, NodeOutput},
+    };
+
+    type Fields = HashMap<&'static str, String>;
+
+    /// Records names and fields of all created spans.
+    #[derive(Clone, Default)]
+    struct TestSubscriber {
+        next_id: Arc<AtomicU64>,
+        spans: Arc<Mutex<HashMap<u64, (&'static str, Fields)>>>,
+    }
+
+    impl TestSubscriber {
+        fn spans(&self) -> Vec<(&'static str, Fields)> {
+            let mut spans = self
+                .spans
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(id, span)| (*id, span.clone()))
+                .collect::<Vec<_>>();
+            spans.sort_by_key(|(id, _)| *id);
+            spans.into_iter().map(|(_, span)| span).collect()
+        }
+    }
+
+    struct FieldVisitor<'a>(&'a mut Fields);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name(), value.to_owned());
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0.insert(field.name(), format!("{value:?}"));
+        }
+    }
+
+    impl Subscriber for TestSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let id = self.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+            let mut fields = Fields::new();
+            span.record(&mut FieldVisitor(&mut fields));
+            self.spans
+                .lock()
+                .unwrap()
+                .insert(id, (span.metadata().name(), fields));
+            Id::from_u64(id)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let mut spans = self.spans.lock().unwrap();
+            let (_, fields) = spans.get_mut(&span.into_u64()).unwrap();
+            values.record(&mut FieldVisitor(fields));
+            drop(spans);
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[derive(Clone)]
+    struct FailNode;
+
+    impl<C: Send> Node<u8, NodeOutput<u8>, (), C> for FailNode {
+        async fn run(&mut self, _input: u8, _context: &mut C) -> Result<NodeOutput<u8>, ()> {
+            Err(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flow() {
+        let subscriber = TestSubscriber::default();
+        let _guard = tracing::subscriber::set_default(subscriber.clone());
+
+        let mut flow = TracedFlow::<u8, u64, (), ()>::new(Passer::<u8, u16, ()>::new());
+        assert_eq!(flow.run(5, &mut ()).await, Ok(NodeOutput::Ok(5)));
+        let mut flow = TracedFlow::<u8, u64, (), ()>::new(SoftFailNode::<u8, u16, ()>::new());
+        assert_eq!(flow.run(5, &mut ()).await, Ok(NodeOutput::SoftFail));
+        let mut flow = TracedFlow::<u8, u64, (), ()>::new(FailNode);
+        assert_eq!(flow.run(5, &mut ()).await, Err(()));
+
+        let spans = subscriber.spans();
+        assert_eq!(spans.len(), 3);
+        let expected = [
+            (std::any::type_name::<Passer<u8, u16, ()>>(), "ok"),
+            (
+                std::any::type_name::<SoftFailNode<u8, u16, ()>>(),
+                "soft_fail",
+            ),
+            (std::any::type_name::<FailNode>(), "err"),
+        ];
+        for ((name, fields), (node, status)) in spans.iter().zip(expected) {
+            assert_eq!(*name, "node_run");
+            assert_eq!(fields["node"], node);
+            assert_eq!(fields["status"], status);
+            assert!(fields.contains_key("elapsed"));
+        }
+        // `Passer` sleeps for 150ms
+        assert!(spans[0].1["elapsed"].ends_with("ms"));
+    }
+
+    #[test]
+    fn test_describe() {
+        let flow = TracedFlow::<u8, u64, (), ()>::new(Passer::<u8, u16, ()>::new());
+        let Description::Node { base } = flow.describe() else {
+            panic!("expected node description");
+        };
+        assert_eq!(
+            base.r#type.name,
+            std::any::type_name::<Passer<u8, u16, ()>>()
+        );
+        assert_eq!(base.output.name, "u16");
+    }
+}
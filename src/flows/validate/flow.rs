@@ -0,0 +1,201 @@
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use crate::{
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::NodeResult,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+type Validator<Input, Error> = Arc<dyn Fn(&Input) -> Result<(), Error> + Send + Sync>;
+
+/// `ValidateNode` checks the input with a predicate before running a node.
+///
+/// If the predicate returns an error, the error is returned and the node is not run.
+/// Otherwise the node is run with the untouched input and its result is returned.
+/// This is useful for rejecting oversized or invalid inputs before they reach an expensive node,
+/// for example at the front of a [`SequentialFlow`](crate::flows::SequentialFlow).
+///
+/// # Type Parameters
+/// - `Input`: The type of data accepted by this flow.
+/// - `Output`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::ValidateNode;
+///
+/// #[derive(Clone)]
+/// struct Len;
+///
+/// impl<Ctx: Send> Node<String, NodeOutput<usize>, &'static str, Ctx> for Len {
+///     async fn run(&mut self, input: String, _: &mut Ctx) -> Result<NodeOutput<usize>, &'static str> {
+///         Ok(NodeOutput::Ok(input.len()))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = ValidateNode::<String, usize, &'static str, ()>::new(Len, |input: &String| {
+///         if input.len() > 4 { Err("too long") } else { Ok(()) }
+///     });
+///
+///     assert_eq!(flow.run("abc".to_owned(), &mut ()).await, Ok(NodeOutput::Ok(3)));
+///     assert_eq!(flow.run("abcdef".to_owned(), &mut ()).await, Err("too long"));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct ValidateNode<
+    Input,
+    Output,
+    Error,
+    Context,
+    NodeType = (),
+    NodeOutput = (),
+    NodeError = (),
+> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    _node_oe: PhantomData<fn() -> (NodeOutput, NodeError)>,
+    node: NodeType,
+    validator: Validator<Input, Error>,
+}
+
+impl<Input, Output, Error, Context> ValidateNode<Input, Output, Error, Context> {
+    /// Creates a new [`ValidateNode`] which runs `node` only if `validator` accepts the input.
+    pub fn new<NodeType, NodeOutput, NodeError>(
+        node: NodeType,
+        validator: impl Fn(&Input) -> Result<(), Error> + Send + Sync + 'static,
+    ) -> ValidateNode<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    where
+        NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context>,
+        // Trait bounds for better and nicer errors
+        NodeOutput: Into<Output>,
+        NodeError: Into<Error>,
+    {
+        ValidateNode {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node,
+            validator: Arc::new(validator),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Debug
+    for ValidateNode<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValidateNode")
+            .field("node", &self.node)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError> Clone
+    for ValidateNode<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _node_oe: PhantomData,
+            node: self.node.clone(),
+            validator: self.validator.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+    Node<Input, NodeOutputStruct<Output>, Error, Context>
+    for ValidateNode<Input, Output, Error, Context, NodeType, NodeOutput, NodeError>
+where
+    NodeType: Node<Input, NodeOutputStruct<NodeOutput>, NodeError, Context> + Send,
+    NodeOutput: Into<Output>,
+    NodeError: Into<Error>,
+    Input: Send,
+    Context: Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> NodeResult<Output, Error> {
+        (self.validator)(&input)?;
+        match self.node.run(input, context).await {
+            Ok(NodeOutputStruct::Ok(output)) => Ok(NodeOutputStruct::Ok(output.into())),
+            Ok(NodeOutputStruct::SoftFail) => Ok(NodeOutputStruct::SoftFail),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_flow(
+            self,
+            vec![self.node.describe()],
+            vec![Edge::flow_to_node(0), Edge::node_to_flow(0)],
+        )
+        .modify_name(remove_generics_from_name)
+        .with_description("Validates input before running the node")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::ValidateNode;
+    use crate::{
+        flows::{SequentialFlow, tests::Passer},
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct CountRuns(Arc<AtomicUsize>);
+
+    impl<C: Send> Node<u8, NodeOutput<u8>, &'static str, C> for CountRuns {
+        async fn run(
+            &mut self,
+            input: u8,
+            _context: &mut C,
+        ) -> Result<NodeOutput<u8>, &'static str> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            Ok(NodeOutput::Ok(input))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pass_through() {
+        let mut flow = SequentialFlow::<u8, u64, &'static str, ()>::builder()
+            .add_node(ValidateNode::<u8, u16, &'static str, ()>::new(
+                Passer::<u8, u8, &'static str>::new(),
+                |_: &u8| Ok(()),
+            ))
+            .add_node(Passer::<u16, u32, &'static str>::new())
+            .build();
+        assert_eq!(flow.run(5, &mut ()).await, Ok(NodeOutput::Ok(5)));
+    }
+
+    #[tokio::test]
+    async fn test_rejection() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let mut flow = ValidateNode::<u8, u16, &'static str, ()>::new(
+            CountRuns(runs.clone()),
+            |input: &u8| if *input > 10 { Err("too big") } else { Ok(()) },
+        );
+
+        assert_eq!(flow.run(11, &mut ()).await, Err("too big"));
+        assert_eq!(runs.load(Ordering::Relaxed), 0);
+
+        assert_eq!(flow.run(10, &mut ()).await, Ok(NodeOutput::Ok(10)));
+        assert_eq!(runs.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,567 @@
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    context::Clock,
+    describe::{Description, Edge, remove_generics_from_name},
+    flows::NodeResult,
+    node::{Node, NodeOutput as NodeOutputStruct},
+};
+
+/// The `Window` trait defines when the inputs buffered by [`WindowFlow`] form a closed window.
+///
+/// Implementors receive a mutable reference to a buffer (stored in the [`WindowFlow`])
+/// and an item that should be pushed into it.
+/// When the pushed item closes a window, the items of that window are returned.
+///
+/// This crate provides [`CountWindow`] and [`TimeWindow`].
+pub trait Window<Item, Context> {
+    /// The buffer holding items of the currently open window(s).
+    type Buffer: Default + Send;
+
+    /// Pushes `item` into the `buffer`.
+    ///
+    /// # Returns
+    /// - `Some(items)` when a window was closed, `items` are the items of the closed window.
+    /// - `None` when no window was closed.
+    fn push(&self, buffer: &mut Self::Buffer, item: Item) -> Option<Vec<Item>>;
+}
+
+/// Windowing by the **number of items**.
+///
+/// - Tumbling windows ([`CountWindow::tumbling`]) do not overlap, every item belongs to exactly one window.
+/// - Sliding windows ([`CountWindow::sliding`]) close every `slide` items and contain the last `size` items.
+///   When `slide` is greater than `size`, items between windows are skipped.
+///
+/// See also [`WindowFlow`], [`Window`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountWindow {
+    size: usize,
+    slide: usize,
+}
+
+impl CountWindow {
+    /// Creates non-overlapping windows of `size` items.
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    #[must_use]
+    pub fn tumbling(size: usize) -> Self {
+        Self::sliding(size, size)
+    }
+
+    /// Creates windows of `size` items, a new window is closed every `slide` items.
+    ///
+    /// # Panics
+    /// Panics if `size` or `slide` is zero.
+    #[must_use]
+    pub fn sliding(size: usize, slide: usize) -> Self {
+        assert!(size > 0, "window size must be greater than zero");
+        assert!(slide > 0, "window slide must be greater than zero");
+        Self { size, slide }
+    }
+}
+
+/// Buffer used by [`CountWindow`].
+#[derive(Debug, Clone)]
+pub struct CountBuffer<Item> {
+    items: VecDeque<Item>,
+    skip: usize,
+}
+
+impl<Item> Default for CountBuffer<Item> {
+    fn default() -> Self {
+        Self {
+            items: VecDeque::new(),
+            skip: 0,
+        }
+    }
+}
+
+impl<Item, Context> Window<Item, Context> for CountWindow
+where
+    Item: Clone + Send + 'static,
+{
+    type Buffer = CountBuffer<Item>;
+
+    fn push(&self, buffer: &mut Self::Buffer, item: Item) -> Option<Vec<Item>> {
+        if buffer.skip > 0 {
+            buffer.skip -= 1;
+            return None;
+        }
+        buffer.items.push_back(item);
+        if buffer.items.len() < self.size {
+            return None;
+        }
+
+        let window = buffer.items.iter().cloned().collect();
+        buffer.items.drain(..self.slide.min(self.size));
+        buffer.skip = self.slide.saturating_sub(self.size);
+        Some(window)
+    }
+}
+
+/// Windowing by **time** measured with the [`Clock`] context trait.
+///
+/// The first window starts when the first item arrives.
+/// Windows are checked only when a new item arrives,
+/// so a window is closed by the first item that falls after its end.
+/// That item is not part of the closed window.
+/// At most one window is closed per item, empty windows are skipped.
+///
+/// - Tumbling windows ([`TimeWindow::tumbling`]) do not overlap.
+/// - Sliding windows ([`TimeWindow::sliding`]) start every `slide` and span `size`.
+///
+/// See also [`WindowFlow`], [`Window`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeWindow {
+    size: Duration,
+    slide: Duration,
+}
+
+impl TimeWindow {
+    /// Creates non-overlapping windows spanning `size`.
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    #[must_use]
+    pub fn tumbling(size: Duration) -> Self {
+        Self::sliding(size, size)
+    }
+
+    /// Creates windows spanning `size`, a new window starts every `slide`.
+    ///
+    /// # Panics
+    /// Panics if `size` or `slide` is zero.
+    #[must_use]
+    pub fn sliding(size: Duration, slide: Duration) -> Self {
+        assert!(!size.is_zero(), "window size must be greater than zero");
+        assert!(!slide.is_zero(), "window slide must be greater than zero");
+        Self { size, slide }
+    }
+}
+
+/// Buffer used by [`TimeWindow`].
+#[derive(Debug, Clone)]
+pub struct TimeBuffer<Item> {
+    start: Option<Instant>,
+    items: VecDeque<(Instant, Item)>,
+}
+
+impl<Item> Default for TimeBuffer<Item> {
+    fn default() -> Self {
+        Self {
+            start: None,
+            items: VecDeque::new(),
+        }
+    }
+}
+
+impl<Item, Context> Window<Item, Context> for TimeWindow
+where
+    Item: Clone + Send + 'static,
+    Context: Clock,
+{
+    type Buffer = TimeBuffer<Item>;
+
+    fn push(&self, buffer: &mut Self::Buffer, item: Item) -> Option<Vec<Item>> {
+        let now = Context::now();
+        let mut start = *buffer.start.get_or_insert(now);
+        let mut closed = None;
+
+        let end = start + self.size;
+        if now >= end {
+            let window = buffer
+                .items
+                .iter()
+                .take_while(|(time, _)| *time < end)
+                .map(|(_, item)| item.clone())
+                .collect::<Vec<_>>();
+            while start + self.size <= now {
+                start += self.slide;
+            }
+            buffer.start = Some(start);
+            while buffer.items.front().is_some_and(|(time, _)| *time < start) {
+                buffer.items.pop_front();
+            }
+            if !window.is_empty() {
+                closed = Some(window);
+            }
+        }
+
+        buffer.items.push_back((now, item));
+        closed
+    }
+}
+
+/// `WindowFlow` buffers inputs into **windows** and aggregates each closed window.
+///
+/// Inputs are pushed into a buffer stored in the flow.
+/// When an input closes a window (see [`Window`]), the aggregate node is executed
+/// with all items of the closed window and its output is returned.
+/// Inputs that don't close a window return [`NodeOutput::SoftFail`](NodeOutputStruct::SoftFail).
+///
+/// Windows can be defined by count ([`CountWindow`]) or by time ([`TimeWindow`]).
+///
+/// Every flow created by [`WindowFlow::new`] has its own buffer.
+/// The buffer is shared between all clones of the flow,
+/// so items pushed by concurrent runs of cloned flows (for example in parallel branches) fall into the same windows.
+///
+/// # Type Parameters
+/// - `Item`: The type of data accepted by this flow.
+/// - `Out`: The type of data produced by this flow.
+/// - `Error`: The type of error emitted by this flow.
+/// - `Context`: The type of context used during execution.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeOutput};
+/// use node_flow::flows::WindowFlow;
+/// use node_flow::flows::window_flow::CountWindow;
+///
+/// struct Sum;
+///
+/// impl<Ctx: Send> Node<Vec<u32>, NodeOutput<u32>, (), Ctx> for Sum {
+///     async fn run(&mut self, input: Vec<u32>, _: &mut Ctx) -> Result<NodeOutput<u32>, ()> {
+///         Ok(NodeOutput::Ok(input.into_iter().sum()))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = WindowFlow::<u32, u32, (), ()>::new(CountWindow::tumbling(2), Sum);
+///     let mut ctx = ();
+///
+///     assert_eq!(flow.run(1, &mut ctx).await, Ok(NodeOutput::SoftFail));
+///     assert_eq!(flow.run(2, &mut ctx).await, Ok(NodeOutput::Ok(3)));
+///     assert_eq!(flow.run(3, &mut ctx).await, Ok(NodeOutput::SoftFail));
+///     assert_eq!(flow.run(4, &mut ctx).await, Ok(NodeOutput::Ok(7)));
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub struct WindowFlow<
+    Item,
+    Out,
+    Error,
+    Context,
+    WindowType = (),
+    Aggregate = (),
+    AggregateOutput = (),
+    AggregateError = (),
+    Buffer = (),
+> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Item, Out, Error, Context)>,
+    _aggregate_oe: PhantomData<fn() -> (AggregateOutput, AggregateError)>,
+    window: WindowType,
+    aggregate: Aggregate,
+    buffer: Arc<Mutex<Buffer>>,
+}
+
+impl<Item, Out, Error, Context> WindowFlow<Item, Out, Error, Context> {
+    /// Creates a new [`WindowFlow`] from a window definition and an aggregate node.
+    ///
+    /// See also [`WindowFlow`], [`CountWindow`], [`TimeWindow`].
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::flows::WindowFlow;
+    /// use node_flow::flows::window_flow::TimeWindow;
+    /// use node_flow::node::{Node, NodeOutput};
+    /// use std::time::Duration;
+    ///
+    /// struct Count;
+    /// impl<Ctx: Send> Node<Vec<u8>, NodeOutput<usize>, (), Ctx> for Count // ...
+    /// # {
+    /// #     async fn run(&mut self, input: Vec<u8>, _: &mut Ctx) -> Result<NodeOutput<usize>, ()> {
+    /// #         Ok(NodeOutput::Ok(input.len()))
+    /// #     }
+    /// # }
+    /// # struct Ctx;
+    /// # impl node_flow::context::Clock for Ctx {
+    /// #     async fn sleep(_: Duration) {}
+    /// # }
+    ///
+    /// let flow = WindowFlow::<u8, usize, (), Ctx>::new(
+    ///     TimeWindow::tumbling(Duration::from_secs(60)),
+    ///     Count,
+    /// );
+    /// ```
+    pub fn new<WindowType, Aggregate, AggregateOutput, AggregateError>(
+        window: WindowType,
+        aggregate: Aggregate,
+    ) -> WindowFlow<
+        Item,
+        Out,
+        Error,
+        Context,
+        WindowType,
+        Aggregate,
+        AggregateOutput,
+        AggregateError,
+        WindowType::Buffer,
+    >
+    where
+        WindowType: Window<Item, Context>,
+        Aggregate: Node<Vec<Item>, NodeOutputStruct<AggregateOutput>, AggregateError, Context>,
+        // Trait bounds for better and nicer errors
+        AggregateOutput: Into<Out>,
+        AggregateError: Into<Error>,
+    {
+        WindowFlow {
+            _ioec: PhantomData,
+            _aggregate_oe: PhantomData,
+            window,
+            aggregate,
+            buffer: Arc::new(Mutex::new(Default::default())),
+        }
+    }
+}
+
+impl<Item, Out, Error, Context, WindowType, Aggregate, AggregateOutput, AggregateError, Buffer>
+    Debug
+    for WindowFlow<
+        Item,
+        Out,
+        Error,
+        Context,
+        WindowType,
+        Aggregate,
+        AggregateOutput,
+        AggregateError,
+        Buffer,
+    >
+where
+    WindowType: Debug,
+    Aggregate: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WindowFlow")
+            .field("window", &self.window)
+            .field("aggregate", &self.aggregate)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Item, Out, Error, Context, WindowType, Aggregate, AggregateOutput, AggregateError, Buffer>
+    Clone
+    for WindowFlow<
+        Item,
+        Out,
+        Error,
+        Context,
+        WindowType,
+        Aggregate,
+        AggregateOutput,
+        AggregateError,
+        Buffer,
+    >
+where
+    WindowType: Clone,
+    Aggregate: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            _aggregate_oe: PhantomData,
+            window: self.window.clone(),
+            aggregate: self.aggregate.clone(),
+            buffer: self.buffer.clone(),
+        }
+    }
+}
+
+impl<Item, Out, Error, Context, WindowType, Aggregate, AggregateOutput, AggregateError, Buffer>
+    Node<Item, NodeOutputStruct<Out>, Error, Context>
+    for WindowFlow<
+        Item,
+        Out,
+        Error,
+        Context,
+        WindowType,
+        Aggregate,
+        AggregateOutput,
+        AggregateError,
+        Buffer,
+    >
+where
+    WindowType: Window<Item, Context, Buffer = Buffer> + Send,
+    Aggregate: Node<Vec<Item>, NodeOutputStruct<AggregateOutput>, AggregateError, Context> + Send,
+    AggregateOutput: Into<Out>,
+    AggregateError: Into<Error>,
+    Buffer: Send,
+    Context: Send,
+    Item: Send,
+{
+    async fn run(&mut self, input: Item, context: &mut Context) -> NodeResult<Out, Error> {
+        let items = {
+            let mut buffer = self.buffer.lock().unwrap();
+            self.window.push(&mut buffer, input)
+        };
+        let Some(items) = items else {
+            return Ok(NodeOutputStruct::SoftFail);
+        };
+
+        let output = self
+            .aggregate
+            .run(items, context)
+            .await
+            .map_err(Into::into)?;
+        Ok(match output {
+            NodeOutputStruct::SoftFail => NodeOutputStruct::SoftFail,
+            NodeOutputStruct::Ok(output) => NodeOutputStruct::Ok(output.into()),
+        })
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_flow(
+            self,
+            vec![self.aggregate.describe()],
+            vec![Edge::flow_to_node(0), Edge::node_to_flow(0)],
+        )
+        .modify_name(remove_generics_from_name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, time::Duration, time::Instant};
+
+    use super::{CountWindow, TimeWindow, WindowFlow as Flow};
+    use crate::{
+        context::Clock,
+        node::{Node, NodeOutput},
+    };
+
+    thread_local! {
+        static START: Instant = Instant::now();
+        static ELAPSED: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+    }
+
+    fn advance(millis: u64) {
+        ELAPSED.with(|e| e.set(e.get() + Duration::from_millis(millis)));
+    }
+
+    /// Context with a manually advanced clock.
+    struct ClockCtx;
+
+    impl Clock for ClockCtx {
+        fn now() -> Instant {
+            START.with(|start| *start + ELAPSED.with(Cell::get))
+        }
+
+        async fn sleep(duration: Duration) {
+            advance(u64::try_from(duration.as_millis()).unwrap());
+        }
+    }
+
+    #[derive(Clone)]
+    struct Collect;
+
+    impl<C: Send> Node<Vec<u8>, NodeOutput<Vec<u8>>, (), C> for Collect {
+        async fn run(&mut self, input: Vec<u8>, _: &mut C) -> Result<NodeOutput<Vec<u8>>, ()> {
+            Ok(NodeOutput::Ok(input))
+        }
+    }
+
+    async fn run_all<F>(flow: &mut F, inputs: &[u8], ctx: &mut ClockCtx) -> Vec<Vec<u8>>
+    where
+        F: Node<u8, NodeOutput<Vec<u8>>, (), ClockCtx>,
+    {
+        let mut windows = Vec::new();
+        for input in inputs {
+            if let Ok(NodeOutput::Ok(window)) = flow.run(*input, ctx).await {
+                windows.push(window);
+            }
+        }
+        windows
+    }
+
+    #[tokio::test]
+    async fn test_count_tumbling() {
+        let mut st = ClockCtx;
+        let mut flow = Flow::<u8, Vec<u8>, (), _>::new(CountWindow::tumbling(3), Collect);
+        let res = run_all(&mut flow, &[1, 2, 3, 4, 5, 6, 7], &mut st).await;
+        assert_eq!(res, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[tokio::test]
+    async fn test_count_sliding() {
+        let mut st = ClockCtx;
+        let mut flow = Flow::<u8, Vec<u8>, (), _>::new(CountWindow::sliding(3, 1), Collect);
+        let res = run_all(&mut flow, &[1, 2, 3, 4, 5], &mut st).await;
+        assert_eq!(res, vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]]);
+
+        let mut st = ClockCtx;
+        let mut flow = Flow::<u8, Vec<u8>, (), _>::new(CountWindow::sliding(2, 3), Collect);
+        let res = run_all(&mut flow, &[1, 2, 3, 4, 5, 6, 7, 8], &mut st).await;
+        assert_eq!(res, vec![vec![1, 2], vec![4, 5], vec![7, 8]]);
+    }
+
+    #[tokio::test]
+    async fn test_time_tumbling() {
+        let mut st = ClockCtx;
+        let mut flow = Flow::<u8, Vec<u8>, (), _>::new(
+            TimeWindow::tumbling(Duration::from_millis(10)),
+            Collect,
+        );
+
+        assert_eq!(flow.run(1, &mut st).await, Ok(NodeOutput::SoftFail));
+        advance(5);
+        assert_eq!(flow.run(2, &mut st).await, Ok(NodeOutput::SoftFail));
+        advance(5);
+        assert_eq!(flow.run(3, &mut st).await, Ok(NodeOutput::Ok(vec![1, 2])));
+        advance(25);
+        assert_eq!(flow.run(4, &mut st).await, Ok(NodeOutput::Ok(vec![3])));
+        advance(10);
+        assert_eq!(flow.run(5, &mut st).await, Ok(NodeOutput::Ok(vec![4])));
+    }
+
+    #[tokio::test]
+    async fn test_time_sliding() {
+        let mut st = ClockCtx;
+        let mut flow = Flow::<u8, Vec<u8>, (), _>::new(
+            TimeWindow::sliding(Duration::from_millis(10), Duration::from_millis(5)),
+            Collect,
+        );
+
+        assert_eq!(flow.run(1, &mut st).await, Ok(NodeOutput::SoftFail));
+        advance(5);
+        assert_eq!(flow.run(2, &mut st).await, Ok(NodeOutput::SoftFail));
+        advance(5);
+        assert_eq!(flow.run(3, &mut st).await, Ok(NodeOutput::Ok(vec![1, 2])));
+        advance(5);
+        assert_eq!(flow.run(4, &mut st).await, Ok(NodeOutput::Ok(vec![2, 3])));
+    }
+
+    #[tokio::test]
+    async fn test_separate_buffers() {
+        let mut st = ClockCtx;
+        let mut small = Flow::<u8, Vec<u8>, (), _>::new(CountWindow::tumbling(2), Collect);
+        let mut large = Flow::<u8, Vec<u8>, (), _>::new(CountWindow::tumbling(3), Collect);
+        let mut small_clone = small.clone();
+
+        assert_eq!(small.run(1, &mut st).await, Ok(NodeOutput::SoftFail));
+        assert_eq!(large.run(2, &mut st).await, Ok(NodeOutput::SoftFail));
+        assert_eq!(large.run(3, &mut st).await, Ok(NodeOutput::SoftFail));
+        // clones share the buffer
+        assert_eq!(
+            small_clone.run(4, &mut st).await,
+            Ok(NodeOutput::Ok(vec![1, 4]))
+        );
+        assert_eq!(
+            large.run(5, &mut st).await,
+            Ok(NodeOutput::Ok(vec![2, 3, 5]))
+        );
+    }
+}
@@ -4,3 +4,30 @@ pub enum SoftFailPoll<T> {
     Ready(T),
     SoftFail,
 }
+
+/// Polls all futures concurrently and returns their outputs in the same order.
+pub async fn join_all<F>(futures: Vec<F>) -> Vec<F::Output>
+where
+    F: Future + Unpin,
+{
+    let mut futures = futures
+        .into_iter()
+        .map(futures_util::future::MaybeDone::Future)
+        .collect::<Vec<_>>();
+    std::future::poll_fn(|cx| {
+        let mut all_done = true;
+        for fut in &mut futures {
+            all_done &= std::pin::Pin::new(fut).poll(cx).is_ready();
+        }
+        if all_done {
+            std::task::Poll::Ready(())
+        } else {
+            std::task::Poll::Pending
+        }
+    })
+    .await;
+    futures
+        .iter_mut()
+        .map(|fut| std::pin::Pin::new(fut).take_output().unwrap())
+        .collect()
+}
@@ -29,12 +29,14 @@
 //!
 //! - **[`Node`](crate::node::Node)** - the core building block, representing an async unit of work.
 //! - **[`NodeOutput`](crate::node::NodeOutput)** - output type used by nodes to signal success or soft failure.
+//! - **[`BoxError`](crate::node::BoxError)** - recommended error type for flows whose nodes return different error types.
 //! - **[`Flows`](crate::flows)** - structures that determine execution order and behavior.
 //! - **[`Context system`](crate::context)** - flows and nodes can restrict the context to ensure that it can perform functions such as:
 //!     - sharing a mutable state
 //!     - context branching/joining
 //!     - task spawning
 //! - **[`Description`](crate::describe::Description)** - describes the structure of a flow, which can then be used for visualization.
+//! - **[`Observer`](crate::observe::Observer)** - gets notified about runs of observed nodes, which can be used for recording metrics.
 //!
 //! ## Examples
 //! ```
@@ -85,3 +87,4 @@ pub mod describe;
 pub mod flows;
 mod future_utils;
 pub mod node;
+pub mod observe;
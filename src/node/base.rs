@@ -99,17 +99,111 @@ pub trait Node<Input, Output, Error, Context> {
     {
         let mut base = DescriptionBase::from::<Self, Input, Output, Error, Context>();
 
-        // remove NodeOutput<> from output name
-        let output_name = &mut base.output.name;
-        if let Some(b_pos) = output_name.find('<')
-            && output_name[..b_pos].contains("NodeOutput")
-        {
-            // remove `..::NodeOutput<`
-            output_name.replace_range(0..=b_pos, "");
-            // remove ending `>`
-            output_name.pop();
-        }
+        strip_node_output(&mut base.output.name);
 
         Description::Node { base }
     }
 }
+
+/// Removes `NodeOutput<>` from the type name, leaving the inner type intact.
+///
+/// The name is modified only when it is exactly `NodeOutput<..>` (optionally with a path),
+/// so names like `Vec<NodeOutput<u8>>` or `MyNodeOutput<u8>` are kept as they are.
+pub(super) fn strip_node_output(name: &mut String) {
+    let Some(b_pos) = name.find('<') else {
+        return;
+    };
+    let path = &name[..b_pos];
+    if path != "NodeOutput" && !path.ends_with("::NodeOutput") {
+        return;
+    }
+    // the bracket after `NodeOutput` has to be closed by the last character
+    let mut depth = 0usize;
+    for (idx, ch) in name.char_indices().skip(b_pos) {
+        match ch {
+            '<' => depth += 1,
+            '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    if idx != name.len() - 1 {
+                        return;
+                    }
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return;
+    }
+
+    // remove ending `>`
+    name.pop();
+    // remove `..::NodeOutput<`
+    name.replace_range(0..=b_pos, "");
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Node, strip_node_output};
+    use crate::node::NodeOutput;
+
+    fn stripped(name: &str) -> String {
+        let mut name = name.to_owned();
+        strip_node_output(&mut name);
+        name
+    }
+
+    #[test]
+    fn test_strip_node_output() {
+        assert_eq!(stripped("node_flow::node::NodeOutput<u8>"), "u8");
+        assert_eq!(
+            stripped("node_flow::node::NodeOutput<alloc::vec::Vec<u8>>"),
+            "alloc::vec::Vec<u8>"
+        );
+        assert_eq!(
+            stripped(
+                "node_flow::node::NodeOutput<alloc::vec::Vec<node_flow::node::NodeOutput<u8>>>"
+            ),
+            "alloc::vec::Vec<node_flow::node::NodeOutput<u8>>"
+        );
+        assert_eq!(stripped("NodeOutput<(u8, u16)>"), "(u8, u16)");
+        // not a NodeOutput
+        assert_eq!(stripped("u8"), "u8");
+        assert_eq!(
+            stripped("alloc::vec::Vec<node_flow::node::NodeOutput<u8>>"),
+            "alloc::vec::Vec<node_flow::node::NodeOutput<u8>>"
+        );
+        assert_eq!(stripped("my::MyNodeOutput<u8>"), "my::MyNodeOutput<u8>");
+        assert_eq!(
+            stripped("(node_flow::node::NodeOutput<u8>, alloc::vec::Vec<u8>)"),
+            "(node_flow::node::NodeOutput<u8>, alloc::vec::Vec<u8>)"
+        );
+    }
+
+    #[test]
+    fn test_describe() {
+        #[derive(Clone)]
+        struct TestNode;
+
+        impl<O: Send, C: Send> Node<u8, O, (), C> for TestNode {
+            async fn run(&mut self, _input: u8, _context: &mut C) -> Result<O, ()> {
+                Err(())
+            }
+        }
+
+        let desc = Node::<u8, NodeOutput<Vec<u8>>, (), ()>::describe(&TestNode);
+        assert_eq!(desc.get_base_ref().output.name, "alloc::vec::Vec<u8>");
+        let desc = Node::<u8, NodeOutput<Vec<NodeOutput<u8>>>, (), ()>::describe(&TestNode);
+        assert_eq!(
+            desc.get_base_ref().output.name,
+            std::any::type_name::<Vec<NodeOutput<u8>>>()
+        );
+        let desc = Node::<u8, Vec<NodeOutput<u8>>, (), ()>::describe(&TestNode);
+        assert_eq!(
+            desc.get_base_ref().output.name,
+            std::any::type_name::<Vec<NodeOutput<u8>>>()
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{describe::Description, node::Node};
+use crate::{
+    describe::Description,
+    node::{LocalNode, Node},
+};
 
 /// The `BoxedNode` trait is a dyn compatible wrapper around the [`Node`] trait.
 ///
@@ -79,11 +82,97 @@ where
     }
 }
 
+/// The `LocalBoxedNode` trait is a dyn compatible wrapper around the [`LocalNode`] trait.
+///
+/// It is the same as [`BoxedNode`], but the returned future doesn't have to be [`Send`].
+/// This allows dynamic dispatch of non-[`Send`] nodes on single-threaded executors.
+///
+/// Blanket implementation of [`LocalBoxedNode`] is implemented
+/// for all types that implement [`LocalNode`] trait.
+/// Regular nodes can be used through the [`AsLocal`](crate::node::AsLocal) adapter.
+///
+/// See also [`LocalNode`], [`BoxedNode`].
+///
+/// # Examples
+/// ```
+/// use node_flow::node::LocalBoxedNode;
+///
+/// async fn run_node(
+///     node: &mut dyn LocalBoxedNode<String, String, String, ()>,
+/// ) {
+///     let result = node.run_boxed_local("hello".into(), &mut ()).await;
+///     println!("{:?}", result);
+/// }
+/// ```
+#[async_trait::async_trait(?Send)]
+pub trait LocalBoxedNode<Input, Output, Error, Context> {
+    /// Runs the node.
+    ///
+    /// This method is equivalent to [`LocalNode::run`], but allows calling it
+    /// via a `Box<dyn LocalBoxedNode<...>>` trait object.
+    ///
+    /// # Returns
+    /// Boxed dyn [`Future`] (`Pin<Box<dyn Future<...> + '_>>`) that resolves to a `Result<Output, Error>`.
+    ///
+    /// See also [`LocalNode::run`].
+    async fn run_boxed_local(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> Result<Output, Error>
+    where
+        Input: 'async_trait,
+        Output: 'async_trait,
+        Error: 'async_trait;
+
+    /// Describes this node, its type signature and other specifics.
+    ///
+    /// See [`Description`] for more details.
+    /// See also [`LocalNode::describe`].
+    fn describe(&self) -> Description;
+}
+
+impl<Input, Output, Error, Context, T> LocalBoxedNode<Input, Output, Error, Context> for T
+where
+    T: LocalNode<Input, Output, Error, Context>,
+{
+    fn run_boxed_local<'life0, 'life1, 'async_trait>(
+        &'life0 mut self,
+        input: Input,
+        context: &'life1 mut Context,
+    ) -> ::core::pin::Pin<
+        Box<dyn ::core::future::Future<Output = Result<Output, Error>> + 'async_trait>,
+    >
+    where
+        Input: 'async_trait,
+        Output: 'async_trait,
+        Error: 'async_trait,
+        'life0: 'async_trait,
+        'life1: 'async_trait,
+        Self: 'async_trait,
+    {
+        Box::pin(<Self as LocalNode<Input, Output, Error, Context>>::run(
+            self, input, context,
+        ))
+    }
+
+    fn describe(&self) -> Description {
+        <Self as LocalNode<Input, Output, Error, Context>>::describe(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::node::NodeOutput;
+    use crate::{
+        context::storage::local_storage::LocalStorageImpl,
+        flows::{ParallelFlow, SequentialFlow, tests::Passer},
+        node::{AsLocal, LocalNode, NodeExt, NodeOutput},
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    use super::{BoxedNode, LocalBoxedNode};
 
-    use super::BoxedNode;
+    type ChainRunOutput = ((NodeOutput<u8>,), NodeOutput<u16>);
 
     #[tokio::test]
     async fn works() {
@@ -92,4 +181,59 @@ mod tests {
         let res = b.run_boxed(5u8, &mut ()).await;
         assert_eq!(res, Ok(NodeOutput::Ok(5u16)));
     }
+
+    #[tokio::test]
+    async fn boxed_flows_in_vec() {
+        let sequential = SequentialFlow::<u8, u16, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(Passer::<u8, u16, ()>::new())
+            .build();
+        let parallel = ParallelFlow::<u8, u16, (), LocalStorageImpl>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(Passer::<u8, u16, ()>::new())
+            .build(
+                async |((a,), b): ChainRunOutput, _: &mut LocalStorageImpl| {
+                    let (NodeOutput::Ok(a), NodeOutput::Ok(b)) = (a, b) else {
+                        return Ok(NodeOutput::SoftFail);
+                    };
+                    Ok(NodeOutput::Ok(u16::from(a) + b))
+                },
+            );
+
+        let mut nodes: Vec<Box<dyn BoxedNode<u8, NodeOutput<u16>, (), LocalStorageImpl>>> =
+            vec![sequential.boxed(), parallel.boxed()];
+
+        let mut results = Vec::new();
+        for node in &mut nodes {
+            results.push(node.run_boxed(5, &mut LocalStorageImpl::new()).await);
+        }
+        assert_eq!(results, [Ok(NodeOutput::Ok(5)), Ok(NodeOutput::Ok(10))]);
+    }
+
+    struct RcCounter(Rc<Cell<u16>>);
+
+    impl LocalNode<u8, NodeOutput<u16>, (), ()> for RcCounter {
+        #[expect(clippy::future_not_send)]
+        async fn run(&mut self, input: u8, _context: &mut ()) -> Result<NodeOutput<u16>, ()> {
+            tokio::task::yield_now().await;
+            self.0.set(self.0.get() + u16::from(input));
+            Ok(NodeOutput::Ok(self.0.get()))
+        }
+    }
+
+    #[tokio::test]
+    async fn local_works() {
+        let counter = Rc::new(Cell::new(0));
+        let mut nodes: Vec<Box<dyn LocalBoxedNode<u8, NodeOutput<u16>, (), ()>>> = vec![
+            Box::new(RcCounter(counter.clone())),
+            Box::new(AsLocal(Passer::<u8, u16, ()>::new())),
+        ];
+
+        let mut results = Vec::new();
+        for node in &mut nodes {
+            results.push(node.run_boxed_local(5, &mut ()).await);
+        }
+        assert_eq!(results, [Ok(NodeOutput::Ok(5)), Ok(NodeOutput::Ok(5))]);
+        assert_eq!(counter.get(), 5);
+    }
 }
@@ -0,0 +1,177 @@
+use std::{collections::HashMap, fmt::Debug, marker::PhantomData, sync::Arc};
+
+use crate::{context::storage::SharedStorage, describe::Description, node::Node};
+
+#[derive(Default)]
+struct CriticalSections(std::sync::Mutex<HashMap<&'static str, Arc<async_lock::Mutex<()>>>>);
+
+/// Node that runs the wrapped node inside a named critical section.
+///
+/// Before the wrapped node is run, an async mutex with the given name is acquired.
+/// It is released after the wrapped node finishes.
+/// Mutexes are stored in the [`SharedStorage`] of the context,
+/// so nodes running concurrently (for example in different branches of a [`ParallelFlow`](crate::flows::ParallelFlow))
+/// with the same section name are serialized, while different sections don't block each other.
+///
+/// It is usually created using [`NodeExt::critical_section`](crate::node::NodeExt::critical_section).
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeExt, NodeOutput};
+/// use node_flow::context::storage::shared_storage::SharedStorageImpl;
+///
+/// struct WriteFile;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for WriteFile {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         // access some shared external resource
+///         Ok(NodeOutput::Ok(input))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// let mut node = WriteFile.critical_section("file");
+/// let mut ctx = SharedStorageImpl::new();
+/// let result = node.run(5, &mut ctx).await;
+/// assert_eq!(result, Ok(NodeOutput::Ok(5)));
+/// # });
+/// ```
+pub struct CriticalSection<Input, Output, Error, Context, NodeType> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    node: NodeType,
+    name: &'static str,
+}
+
+impl<Input, Output, Error, Context, NodeType>
+    CriticalSection<Input, Output, Error, Context, NodeType>
+{
+    /// Creates a new [`CriticalSection`] with the given name.
+    pub const fn new(node: NodeType, name: &'static str) -> Self {
+        Self {
+            _ioec: PhantomData,
+            node,
+            name,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType> Debug
+    for CriticalSection<Input, Output, Error, Context, NodeType>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CriticalSection")
+            .field("node", &self.node)
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType> Clone
+    for CriticalSection<Input, Output, Error, Context, NodeType>
+where
+    NodeType: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            node: self.node.clone(),
+            name: self.name,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType> Node<Input, Output, Error, Context>
+    for CriticalSection<Input, Output, Error, Context, NodeType>
+where
+    NodeType: Node<Input, Output, Error, Context> + Send,
+    Input: Send,
+    Context: SharedStorage + Send + Sync,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> Result<Output, Error> {
+        let mutex = {
+            let _ = context
+                .insert_with_if_absent(async { Ok::<_, ()>(CriticalSections::default()) })
+                .await;
+            let sections = context.get::<CriticalSections>().await.unwrap();
+            let mut sections = sections.0.lock().unwrap();
+            sections.entry(self.name).or_default().clone()
+        };
+        let _guard = mutex.lock_arc().await;
+        self.node.run(input, context).await
+    }
+
+    fn describe(&self) -> Description {
+        self.node.describe()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::{Duration, Instant},
+    };
+
+    use crate::{
+        context::storage::shared_storage::SharedStorageImpl,
+        flows::ParallelFlow,
+        node::{Node, NodeExt, NodeOutput},
+    };
+
+    #[derive(Clone, Default)]
+    struct Counter {
+        active: Arc<AtomicUsize>,
+        max_active: Arc<AtomicUsize>,
+    }
+
+    impl<C: Send> Node<(), NodeOutput<()>, (), C> for Counter {
+        async fn run(&mut self, _input: (), _context: &mut C) -> Result<NodeOutput<()>, ()> {
+            let active = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_active.fetch_max(active, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.active.fetch_sub(1, Ordering::SeqCst);
+            Ok(NodeOutput::Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mutual_exclusion() {
+        let counter = Counter::default();
+        let mut st = SharedStorageImpl::new();
+        let mut flow = ParallelFlow::<(), (), (), _>::builder()
+            .add_node(counter.clone().critical_section("a"))
+            .add_node(counter.clone().critical_section("a"))
+            .add_node(counter.clone().critical_section("a"))
+            .build(async |_, _: &mut SharedStorageImpl| Ok(NodeOutput::Ok(())));
+
+        let start = Instant::now();
+        let res = flow.run((), &mut st).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(())));
+        assert_eq!(counter.max_active.load(Ordering::SeqCst), 1);
+        assert!(start.elapsed().as_millis() >= 150);
+    }
+
+    #[tokio::test]
+    async fn test_different_sections() {
+        let counter = Counter::default();
+        let mut st = SharedStorageImpl::new();
+        let mut flow = ParallelFlow::<(), (), (), _>::builder()
+            .add_node(counter.clone().critical_section("a"))
+            .add_node(counter.clone().critical_section("b"))
+            .build(async |_, _: &mut SharedStorageImpl| Ok(NodeOutput::Ok(())));
+
+        let res = flow.run((), &mut st).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(())));
+        assert_eq!(counter.max_active.load(Ordering::SeqCst), 2);
+    }
+}
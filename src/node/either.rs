@@ -0,0 +1,111 @@
+use crate::{describe::Description, node::Node};
+
+/// Node that holds one of two node types.
+///
+/// `Either` implements [`Node`] when both `L` and `R` do,
+/// dispatching [`Node::run`] and [`Node::describe`] to the variant it holds.
+/// This allows selecting between two node types at construction time
+/// without boxing them (unlike [`BoxedNode`](crate::node::BoxedNode)).
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Either, Node, NodeOutput};
+///
+/// #[derive(Clone)]
+/// struct AddOne;
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         Ok(NodeOutput::Ok(input + 1))
+///     }
+/// }
+///
+/// #[derive(Clone)]
+/// struct Double;
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for Double {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         Ok(NodeOutput::Ok(input * 2))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// let double = true;
+/// let mut node = if double {
+///     Either::Right(Double)
+/// } else {
+///     Either::Left(AddOne)
+/// };
+/// let result = node.run(5, &mut ()).await;
+/// assert_eq!(result, Ok(NodeOutput::Ok(10)));
+/// # });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Either<L, R> {
+    /// The first node type.
+    Left(L),
+    /// The second node type.
+    Right(R),
+}
+
+impl<Input, Output, Error, Context, L, R> Node<Input, Output, Error, Context> for Either<L, R>
+where
+    L: Node<Input, Output, Error, Context>,
+    R: Node<Input, Output, Error, Context>,
+{
+    fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = Result<Output, Error>> + Send {
+        match self {
+            Self::Left(node) => futures_util::future::Either::Left(node.run(input, context)),
+            Self::Right(node) => futures_util::future::Either::Right(node.run(input, context)),
+        }
+    }
+
+    fn describe(&self) -> Description {
+        match self {
+            Self::Left(node) => node.describe(),
+            Self::Right(node) => node.describe(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Either;
+    use crate::{
+        flows::tests::{Passer, SoftFailNode},
+        node::{Node, NodeOutput},
+    };
+
+    #[tokio::test]
+    async fn test_both_variants() {
+        let mut nodes = [
+            Either::Left(Passer::<u8, u16, ()>::new()),
+            Either::Right(SoftFailNode::<u8, u16, ()>::new()),
+        ];
+
+        let mut results = Vec::new();
+        for node in &mut nodes {
+            results.push(node.run(5, &mut ()).await);
+        }
+        assert_eq!(results, [Ok(NodeOutput::Ok(5)), Ok(NodeOutput::SoftFail)]);
+
+        let left_name = Node::<u8, NodeOutput<u16>, (), ()>::describe(&nodes[0])
+            .get_base_ref()
+            .r#type
+            .name
+            .clone();
+        assert!(left_name.contains("Passer"));
+        let right_name = Node::<u8, NodeOutput<u16>, (), ()>::describe(&nodes[1])
+            .get_base_ref()
+            .r#type
+            .name
+            .clone();
+        assert!(right_name.contains("SoftFailNode"));
+    }
+}
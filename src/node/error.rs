@@ -0,0 +1,60 @@
+/// A type-erased error type.
+///
+/// Flows require that errors of all their nodes can be converted into the error type of the flow (`NodeError: Into<Error>`).
+/// When nodes in a flow return different error types, this usually means writing a lot of
+/// `impl From<NodeError> for FlowError` boilerplate.
+///
+/// Using `BoxError` as the error type of a flow avoids that,
+/// because any type implementing [`std::error::Error`] + [`Send`] + [`Sync`] can be converted into it.
+/// This is the recommended error type for pipelines with heterogeneous errors.
+/// If the caller needs to react to a specific error, it can use [`downcast_ref`](https://doc.rust-lang.org/std/error/trait.Error.html#method.downcast_ref).
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{BoxError, Node, NodeOutput};
+/// use node_flow::flows::SequentialFlow;
+/// use std::num::ParseIntError;
+///
+/// #[derive(Clone)]
+/// struct Parse;
+/// impl<Ctx: Send> Node<String, NodeOutput<u8>, ParseIntError, Ctx> for Parse {
+///     async fn run(&mut self, input: String, _: &mut Ctx) -> Result<NodeOutput<u8>, ParseIntError> {
+///         Ok(NodeOutput::Ok(input.parse()?))
+///     }
+/// }
+///
+/// #[derive(Clone)]
+/// struct Check;
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, std::io::Error, Ctx> for Check {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, std::io::Error> {
+///         if input > 100 {
+///             return Err(std::io::Error::other("too big"));
+///         }
+///         Ok(NodeOutput::Ok(input))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut flow = SequentialFlow::<String, u8, BoxError, _>::builder()
+///         .add_node(Parse)
+///         .add_node(Check)
+///         .build();
+///
+///     let mut ctx = ();
+///     assert!(matches!(flow.run("5".to_owned(), &mut ctx).await, Ok(NodeOutput::Ok(5))));
+///
+///     let err = flow.run("x".to_owned(), &mut ctx).await.unwrap_err();
+///     assert!(err.downcast_ref::<ParseIntError>().is_some());
+///
+///     let err = flow.run("200".to_owned(), &mut ctx).await.unwrap_err();
+///     assert!(err.downcast_ref::<std::io::Error>().is_some());
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
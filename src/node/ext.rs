@@ -0,0 +1,156 @@
+use std::convert::Infallible;
+
+use crate::node::{
+    AssertInfallible, MapErr, MapOutput, Node, NodeOutput, RecoverNode, ThenNode, UnwrapErrors,
+};
+
+/// The `NodeExt` trait provides combinators for [`Node`]s.
+///
+/// It is implemented for every type implementing [`Node`].
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeExt, NodeOutput};
+/// use std::convert::Infallible;
+///
+/// struct MayFail;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, String, Ctx> for MayFail {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, String> {
+///         Ok(NodeOutput::Ok(input))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// let mut node = MayFail.unwrap_errors(|err| panic!("unexpected error: {err}"));
+/// let result: Result<_, Infallible> = node.run(5, &mut ()).await;
+/// assert_eq!(result, Ok(NodeOutput::Ok(5)));
+/// # });
+/// ```
+pub trait NodeExt<Input, Output, Error, Context>:
+    Node<Input, Output, Error, Context> + Sized
+{
+    /// Converts this node into a node with [`Infallible`] error.
+    ///
+    /// When this node returns an error, `on_err` is called with it.
+    /// It can either panic or just log the error,
+    /// in which case the returned node returns [`NodeOutput::SoftFail`](crate::node::NodeOutput::SoftFail).
+    ///
+    /// See also [`UnwrapErrors`].
+    fn unwrap_errors<OnErr>(
+        self,
+        on_err: OnErr,
+    ) -> UnwrapErrors<Input, Output, Context, Self, OnErr, Error>
+    where
+        OnErr: Fn(Error) + Send + Sync,
+    {
+        UnwrapErrors::new(self, on_err)
+    }
+
+    /// Converts this node that can never fail (its error is [`Infallible`])
+    /// into a node with the error type `NewError`.
+    ///
+    /// This is useful when composing genuinely infallible nodes with nodes that can fail.
+    ///
+    /// See also [`AssertInfallible`].
+    fn assert_infallible<NewError>(self) -> AssertInfallible<Input, Output, NewError, Context, Self>
+    where
+        Self: Node<Input, Output, Infallible, Context>,
+    {
+        AssertInfallible::new(self)
+    }
+
+    /// Maps the output of this node using `func`.
+    ///
+    /// Only [`NodeOutput::Ok`] values are mapped,
+    /// [`NodeOutput::SoftFail`] and errors are passed through untouched.
+    ///
+    /// See also [`MapOutput`].
+    fn map_output<F, ItemOutput, NewOutput>(
+        self,
+        func: F,
+    ) -> MapOutput<Input, Output, Error, Context, Self, F>
+    where
+        Self: Node<Input, NodeOutput<ItemOutput>, Error, Context>,
+        F: Fn(ItemOutput) -> NewOutput + Send + Sync,
+    {
+        MapOutput::new(self, func)
+    }
+
+    /// Maps the error of this node using `func`.
+    ///
+    /// This is useful when composing nodes with different error types
+    /// without implementing [`From`] for the error type of a flow.
+    ///
+    /// See also [`MapErr`].
+    fn map_err<F, NewError>(self, func: F) -> MapErr<Input, Output, Error, Context, Self, F>
+    where
+        F: Fn(Error) -> NewError + Send + Sync,
+    {
+        MapErr::new(self, func)
+    }
+
+    /// Recovers from errors of this node using `func`.
+    ///
+    /// Errors are converted into outputs (a fallback value or [`NodeOutput::SoftFail`])
+    /// instead of being propagated.
+    ///
+    /// See also [`RecoverNode`].
+    fn recover<F, ItemOutput>(self, func: F) -> RecoverNode<Input, Output, Error, Context, Self, F>
+    where
+        Self: Node<Input, NodeOutput<ItemOutput>, Error, Context>,
+        F: Fn(Error) -> NodeOutput<ItemOutput> + Send + Sync,
+    {
+        RecoverNode::new(self, func)
+    }
+
+    /// Runs `next` after this node, feeding the output of this node into `next`.
+    ///
+    /// When this node soft-fails or returns an error, `next` is not executed.
+    ///
+    /// See also [`ThenNode`].
+    fn then<Next, NextInput>(
+        self,
+        next: Next,
+    ) -> ThenNode<Input, Output, Error, Context, Self, Next, NextInput> {
+        ThenNode::new(self, next)
+    }
+
+    /// Runs this node inside a critical section with the given name.
+    ///
+    /// Nodes using the same critical section name never run concurrently,
+    /// as long as they share the context's [`SharedStorage`](crate::context::storage::SharedStorage).
+    ///
+    /// See also [`CriticalSection`](crate::node::CriticalSection).
+    #[cfg(feature = "shared_storage_impl")]
+    fn critical_section(
+        self,
+        name: &'static str,
+    ) -> crate::node::CriticalSection<Input, Output, Error, Context, Self>
+    where
+        Context: crate::context::storage::SharedStorage,
+    {
+        crate::node::CriticalSection::new(self, name)
+    }
+
+    /// Erases the type of this node by boxing it into a [`BoxedNode`](crate::node::BoxedNode) trait object.
+    ///
+    /// This allows storing different nodes and flows (like [`SequentialFlow`](crate::flows::SequentialFlow)
+    /// and [`ParallelFlow`](crate::flows::ParallelFlow)) in a single collection.
+    #[cfg(feature = "boxed_node")]
+    fn boxed(self) -> Box<dyn crate::node::BoxedNode<Input, Output, Error, Context>>
+    where
+        Self: 'static,
+    {
+        Box::new(self)
+    }
+}
+
+impl<Input, Output, Error, Context, T> NodeExt<Input, Output, Error, Context> for T where
+    T: Node<Input, Output, Error, Context>
+{
+}
@@ -0,0 +1,62 @@
+use crate::node::{Node, NodeOutput};
+
+/// Marker trait for nodes that pass their input through unchanged.
+///
+/// A node implementing `IdentityLike` promises that its output is always equal to its input
+/// (`Input == Output`) and that it has no side effects.
+/// Such nodes are safe to remove from a flow without changing its behavior,
+/// which is useful when flows are generated and may contain redundant passthrough nodes.
+///
+/// Note that flows built from the typed builders (like [`SequentialFlow`](crate::flows::SequentialFlow))
+/// store their nodes as part of their type, so nodes cannot be removed from them after they are built.
+/// Only flows that store their nodes dynamically can make use of this marker for optimization,
+/// like `DynSequentialFlow::optimize` (available with the `boxed_node` feature).
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{IdentityLike, Node, NodeOutput};
+///
+/// #[derive(Clone)]
+/// struct Passthrough;
+///
+/// impl<T: Send, Ctx: Send> Node<T, NodeOutput<T>, (), Ctx> for Passthrough {
+///     async fn run(&mut self, input: T, _: &mut Ctx) -> Result<NodeOutput<T>, ()> {
+///         Ok(NodeOutput::Ok(input))
+///     }
+/// }
+///
+/// impl IdentityLike for Passthrough {}
+/// ```
+pub trait IdentityLike {}
+
+/// Node that returns its input unchanged.
+///
+/// See also [`IdentityLike`].
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Identity, Node, NodeOutput};
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// let result: Result<_, ()> = Identity.run(5u8, &mut ()).await;
+/// assert_eq!(result, Ok(NodeOutput::Ok(5u8)));
+/// # });
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Identity;
+
+impl IdentityLike for Identity {}
+
+impl<T, Error, Context> Node<T, NodeOutput<T>, Error, Context> for Identity
+where
+    T: Send,
+    Context: Send,
+{
+    async fn run(&mut self, input: T, _context: &mut Context) -> Result<NodeOutput<T>, Error> {
+        Ok(NodeOutput::Ok(input))
+    }
+}
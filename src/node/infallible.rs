@@ -0,0 +1,256 @@
+use std::{convert::Infallible, fmt::Debug, marker::PhantomData};
+
+use crate::{
+    describe::{Description, Type},
+    node::{Node, NodeOutput},
+};
+
+/// Node that converts errors of the wrapped node using a callback and never fails.
+///
+/// When the wrapped node returns an error, the callback is called with it.
+/// The callback can either panic or just log the error,
+/// in which case this node returns [`NodeOutput::SoftFail`].
+///
+/// It is usually created using [`NodeExt::unwrap_errors`](crate::node::NodeExt::unwrap_errors).
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeExt, NodeOutput};
+/// use std::convert::Infallible;
+///
+/// struct Fail;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, String, Ctx> for Fail {
+///     async fn run(&mut self, _: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, String> {
+///         Err("failed".to_owned())
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// let mut node = Fail.unwrap_errors(|err| eprintln!("ignored error: {err}"));
+/// let result: Result<_, Infallible> = node.run(5, &mut ()).await;
+/// assert_eq!(result, Ok(NodeOutput::SoftFail));
+/// # });
+/// ```
+pub struct UnwrapErrors<Input, Output, Context, NodeType, OnErr, NodeError> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, NodeError, Context)>,
+    node: NodeType,
+    on_err: OnErr,
+}
+
+impl<Input, Output, Context, NodeType, OnErr, NodeError>
+    UnwrapErrors<Input, Output, Context, NodeType, OnErr, NodeError>
+{
+    /// Creates a new [`UnwrapErrors`] node by wrapping the given node and error callback.
+    ///
+    /// See also [`UnwrapErrors`].
+    pub const fn new(node: NodeType, on_err: OnErr) -> Self {
+        Self {
+            _ioec: PhantomData,
+            node,
+            on_err,
+        }
+    }
+}
+
+impl<Input, Output, Context, NodeType, OnErr, NodeError> Debug
+    for UnwrapErrors<Input, Output, Context, NodeType, OnErr, NodeError>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnwrapErrors")
+            .field("node", &self.node)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Context, NodeType, OnErr, NodeError> Clone
+    for UnwrapErrors<Input, Output, Context, NodeType, OnErr, NodeError>
+where
+    NodeType: Clone,
+    OnErr: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            node: self.node.clone(),
+            on_err: self.on_err.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Context, NodeType, OnErr, NodeError>
+    Node<Input, NodeOutput<Output>, Infallible, Context>
+    for UnwrapErrors<Input, NodeOutput<Output>, Context, NodeType, OnErr, NodeError>
+where
+    NodeType: Node<Input, NodeOutput<Output>, NodeError, Context> + Send,
+    OnErr: Fn(NodeError) + Send + Sync,
+    Input: Send,
+    Context: Send,
+{
+    async fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> Result<NodeOutput<Output>, Infallible> {
+        match self.node.run(input, context).await {
+            Ok(output) => Ok(output),
+            Err(err) => {
+                (self.on_err)(err);
+                Ok(NodeOutput::SoftFail)
+            }
+        }
+    }
+
+    fn describe(&self) -> Description {
+        let mut description = self.node.describe();
+        description.get_base_mut().error = Type::of::<Infallible>();
+        description
+    }
+}
+
+/// Node that wraps a node which can never fail and allows it to have any error type.
+///
+/// The error type of the wrapped node must be [`Infallible`].
+///
+/// It is usually created using [`NodeExt::assert_infallible`](crate::node::NodeExt::assert_infallible).
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeExt, NodeOutput};
+/// use std::convert::Infallible;
+///
+/// struct Double;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u16>, Infallible, Ctx> for Double {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u16>, Infallible> {
+///         Ok(NodeOutput::Ok(u16::from(input) * 2))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// let mut node = Double.assert_infallible::<String>();
+/// let result = node.run(5, &mut ()).await;
+/// assert_eq!(result, Ok(NodeOutput::Ok(10)));
+/// # });
+/// ```
+pub struct AssertInfallible<Input, Output, Error, Context, NodeType> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    node: NodeType,
+}
+
+impl<Input, Output, Error, Context, NodeType>
+    AssertInfallible<Input, Output, Error, Context, NodeType>
+{
+    /// Creates a new [`AssertInfallible`] node by wrapping the given node.
+    ///
+    /// See also [`AssertInfallible`].
+    pub const fn new(node: NodeType) -> Self {
+        Self {
+            _ioec: PhantomData,
+            node,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType> Debug
+    for AssertInfallible<Input, Output, Error, Context, NodeType>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AssertInfallible")
+            .field("node", &self.node)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType> Clone
+    for AssertInfallible<Input, Output, Error, Context, NodeType>
+where
+    NodeType: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            node: self.node.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType> Node<Input, Output, Error, Context>
+    for AssertInfallible<Input, Output, Error, Context, NodeType>
+where
+    NodeType: Node<Input, Output, Infallible, Context> + Send,
+    Input: Send,
+    Context: Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> Result<Output, Error> {
+        match self.node.run(input, context).await {
+            Ok(output) => Ok(output),
+            Err(never) => match never {},
+        }
+    }
+
+    fn describe(&self) -> Description {
+        let mut description = self.node.describe();
+        description.get_base_mut().error = Type::of::<Error>();
+        description
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::Infallible;
+
+    use crate::{
+        flows::{SequentialFlow, tests::Passer},
+        node::{Node, NodeExt, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct Fail;
+
+    impl<C: Send> Node<u8, NodeOutput<u8>, u8, C> for Fail {
+        async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u8>, u8> {
+            Err(input)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unwrap_errors() {
+        let errors = std::sync::Mutex::new(Vec::new());
+        let mut node = Fail.unwrap_errors(|err| errors.lock().unwrap().push(err));
+        let res: Result<_, Infallible> = node.run(3, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+        assert_eq!(*errors.lock().unwrap(), vec![3]);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "error: 3")]
+    async fn test_unwrap_errors_panic() {
+        let mut node = Fail.unwrap_errors(|err| panic!("error: {err}"));
+        let _: Result<_, Infallible> = node.run(3, &mut ()).await;
+    }
+
+    #[tokio::test]
+    async fn test_assert_infallible() {
+        let mut flow = SequentialFlow::<u8, u64, String, _>::builder()
+            .add_node(Passer::<u8, u16, Infallible>::new().assert_infallible::<String>())
+            .add_node(Passer::<u16, u64, String>::new())
+            .build();
+        let res = flow.run(3, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(3)));
+    }
+}
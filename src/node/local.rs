@@ -0,0 +1,97 @@
+use super::{Node, base::strip_node_output};
+use crate::describe::{Description, DescriptionBase};
+
+/// The `LocalNode` trait is a variant of [`Node`] for single-threaded flows.
+///
+/// Unlike [`Node::run`], [`LocalNode::run`] returns a future which doesn't have to be [`Send`],
+/// so nodes can hold and use non-[`Send`] state (for example [`Rc`](std::rc::Rc)).
+///
+/// Regular nodes and flows can be used as a `LocalNode` by wrapping them in [`AsLocal`],
+/// for example when adding them into a [`LocalSequentialFlow`](crate::flows::LocalSequentialFlow).
+///
+/// # Type Parameters
+/// - `Input`: The type of data consumed by the node.
+/// - `Output`: The type of data produced by the node.
+/// - `Error`: The type representing possible errors.
+/// - `Context`: The type of context used during execution (it should always be a generic).
+///
+/// # Examples
+/// ```
+/// use std::{cell::Cell, rc::Rc};
+/// use node_flow::node::{LocalNode, NodeOutput};
+///
+/// struct Counter(Rc<Cell<u32>>);
+///
+/// impl<Context> LocalNode<u32, NodeOutput<u32>, (), Context> for Counter {
+///     async fn run(&mut self, input: u32, _context: &mut Context) -> Result<NodeOutput<u32>, ()> {
+///         self.0.set(self.0.get() + input);
+///         Ok(NodeOutput::Ok(self.0.get()))
+///     }
+/// }
+/// ```
+pub trait LocalNode<Input, Output, Error, Context> {
+    /// Runs the node.
+    ///
+    /// See [`Node::run`].
+    ///
+    /// # Errors
+    /// Returns an error when the node "hard" fails.
+    fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = Result<Output, Error>>;
+
+    /// Describes this node, its type signature and other specifics.
+    ///
+    /// See [`Node::describe`].
+    fn describe(&self) -> Description
+    where
+        Self: Sized,
+    {
+        let mut base = DescriptionBase::from::<Self, Input, Output, Error, Context>();
+
+        strip_node_output(&mut base.output.name);
+
+        Description::Node { base }
+    }
+}
+
+/// Adapter which allows using a [`Node`] as a [`LocalNode`].
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{AsLocal, LocalNode, Node, NodeOutput};
+///
+/// #[derive(Clone)]
+/// struct AddOne;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         Ok(NodeOutput::Ok(input + 1))
+///     }
+/// }
+///
+/// fn assert_local_node(_: &impl LocalNode<u8, NodeOutput<u8>, (), ()>) {}
+/// assert_local_node(&AsLocal(AddOne));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AsLocal<NodeType>(pub NodeType);
+
+impl<NodeType, Input, Output, Error, Context> LocalNode<Input, Output, Error, Context>
+    for AsLocal<NodeType>
+where
+    NodeType: Node<Input, Output, Error, Context>,
+{
+    fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> impl Future<Output = Result<Output, Error>> {
+        self.0.run(input, context)
+    }
+
+    fn describe(&self) -> Description {
+        self.0.describe()
+    }
+}
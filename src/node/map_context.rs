@@ -0,0 +1,155 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{describe::Description, node::Node};
+
+/// Node that runs the wrapped node against a part of the context.
+///
+/// The projection function receives the outer context and returns
+/// a mutable reference to the inner context the wrapped node expects.
+///
+/// This allows reusing nodes written against a narrower context
+/// without wrapping the context in newtypes.
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{MapContextNode, Node, NodeOutput};
+///
+/// struct Outer {
+///     counter: u32,
+/// }
+///
+/// struct Count;
+///
+/// impl Node<(), NodeOutput<u32>, (), u32> for Count {
+///     async fn run(&mut self, _: (), counter: &mut u32) -> Result<NodeOutput<u32>, ()> {
+///         *counter += 1;
+///         Ok(NodeOutput::Ok(*counter))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// let mut node = MapContextNode::new(Count, |ctx: &mut Outer| &mut ctx.counter);
+/// let mut ctx = Outer { counter: 4 };
+/// let result = node.run((), &mut ctx).await;
+/// assert_eq!(result, Ok(NodeOutput::Ok(5)));
+/// assert_eq!(ctx.counter, 5);
+/// # });
+/// ```
+pub struct MapContextNode<Input, Output, Error, OuterCtx, InnerCtx, NodeType, F> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, OuterCtx, InnerCtx)>,
+    node: NodeType,
+    project: F,
+}
+
+impl<Input, Output, Error, OuterCtx, InnerCtx, NodeType, F>
+    MapContextNode<Input, Output, Error, OuterCtx, InnerCtx, NodeType, F>
+{
+    /// Creates a new [`MapContextNode`] by wrapping the given node and context projection.
+    ///
+    /// See also [`MapContextNode`].
+    pub const fn new(node: NodeType, project: F) -> Self
+    where
+        // Trait bounds for better and nicer errors
+        F: for<'a> Fn(&'a mut OuterCtx) -> &'a mut InnerCtx,
+        NodeType: Node<Input, Output, Error, InnerCtx>,
+    {
+        Self {
+            _ioec: PhantomData,
+            node,
+            project,
+        }
+    }
+}
+
+impl<Input, Output, Error, OuterCtx, InnerCtx, NodeType, F> Debug
+    for MapContextNode<Input, Output, Error, OuterCtx, InnerCtx, NodeType, F>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MapContextNode")
+            .field("node", &self.node)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, OuterCtx, InnerCtx, NodeType, F> Clone
+    for MapContextNode<Input, Output, Error, OuterCtx, InnerCtx, NodeType, F>
+where
+    NodeType: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            node: self.node.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, OuterCtx, InnerCtx, NodeType, F> Node<Input, Output, Error, OuterCtx>
+    for MapContextNode<Input, Output, Error, OuterCtx, InnerCtx, NodeType, F>
+where
+    NodeType: Node<Input, Output, Error, InnerCtx> + Send,
+    F: for<'a> Fn(&'a mut OuterCtx) -> &'a mut InnerCtx + Send + Sync,
+    Input: Send,
+    OuterCtx: Send,
+    InnerCtx: Send,
+{
+    async fn run(&mut self, input: Input, context: &mut OuterCtx) -> Result<Output, Error> {
+        self.node.run(input, (self.project)(context)).await
+    }
+
+    fn describe(&self) -> Description {
+        self.node.describe()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MapContextNode;
+    use crate::{
+        flows::{SequentialFlow, tests::Passer},
+        node::{Node, NodeOutput},
+    };
+
+    struct AppContext {
+        requests: u32,
+        name: String,
+    }
+
+    #[derive(Clone)]
+    struct CountRequests;
+
+    impl Node<u8, NodeOutput<u32>, (), u32> for CountRequests {
+        async fn run(&mut self, input: u8, requests: &mut u32) -> Result<NodeOutput<u32>, ()> {
+            *requests += u32::from(input);
+            Ok(NodeOutput::Ok(*requests))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_map_context() {
+        let mut flow = SequentialFlow::<u8, u32, (), AppContext>::builder()
+            .add_node(Passer::<u8, u8, ()>::new())
+            .add_node(MapContextNode::new(
+                CountRequests,
+                |ctx: &mut AppContext| &mut ctx.requests,
+            ))
+            .build();
+        let mut ctx = AppContext {
+            requests: 10,
+            name: "app".to_owned(),
+        };
+        let res = flow.run(5, &mut ctx).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(15)));
+        assert_eq!(ctx.requests, 15);
+        assert_eq!(ctx.name, "app");
+    }
+}
@@ -0,0 +1,145 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{
+    describe::{Description, Type},
+    node::Node,
+};
+
+/// Node that maps the error of the wrapped node using a function.
+///
+/// When the wrapped node returns an error, the function is applied to it.
+/// The output is passed through untouched.
+///
+/// This allows adapting error types inline instead of implementing [`From`]
+/// for the error type of a flow.
+///
+/// It is usually created using [`NodeExt::map_err`](crate::node::NodeExt::map_err).
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeExt, NodeOutput};
+///
+/// struct Fail;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, u8, Ctx> for Fail {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, u8> {
+///         Err(input)
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// let mut node = Fail.map_err(|code| format!("failed with code {code}"));
+/// let result = node.run(5, &mut ()).await;
+/// assert_eq!(result, Err("failed with code 5".to_owned()));
+/// # });
+/// ```
+pub struct MapErr<Input, Output, Error, Context, NodeType, F> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    node: NodeType,
+    func: F,
+}
+
+impl<Input, Output, Error, Context, NodeType, F>
+    MapErr<Input, Output, Error, Context, NodeType, F>
+{
+    /// Creates a new [`MapErr`] node by wrapping the given node and mapping function.
+    ///
+    /// See also [`MapErr`].
+    pub const fn new(node: NodeType, func: F) -> Self {
+        Self {
+            _ioec: PhantomData,
+            node,
+            func,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, F> Debug
+    for MapErr<Input, Output, Error, Context, NodeType, F>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MapErr")
+            .field("node", &self.node)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, F> Clone
+    for MapErr<Input, Output, Error, Context, NodeType, F>
+where
+    NodeType: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            node: self.node.clone(),
+            func: self.func.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, NewError, Context, NodeType, F> Node<Input, Output, NewError, Context>
+    for MapErr<Input, Output, Error, Context, NodeType, F>
+where
+    NodeType: Node<Input, Output, Error, Context> + Send,
+    F: Fn(Error) -> NewError + Send + Sync,
+    Input: Send,
+    Context: Send,
+{
+    async fn run(&mut self, input: Input, context: &mut Context) -> Result<Output, NewError> {
+        self.node.run(input, context).await.map_err(&self.func)
+    }
+
+    fn describe(&self) -> Description {
+        let mut description = self.node.describe();
+        description.get_base_mut().error = Type::of::<NewError>();
+        description
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        flows::{SequentialFlow, tests::Passer},
+        node::{Node, NodeExt, NodeOutput},
+    };
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum FlowError {
+        Code(u8),
+    }
+
+    #[derive(Clone)]
+    struct Fail;
+
+    impl<C: Send> Node<u16, NodeOutput<u64>, u8, C> for Fail {
+        async fn run(&mut self, _input: u16, _context: &mut C) -> Result<NodeOutput<u64>, u8> {
+            Err(7)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_map_err() {
+        let mut flow = SequentialFlow::<u8, u64, FlowError, _>::builder()
+            .add_node(Passer::<u8, u16, ()>::new().map_err(|()| FlowError::Code(0)))
+            .add_node(Fail.map_err(FlowError::Code))
+            .build();
+        let res = flow.run(3, &mut ()).await;
+        assert_eq!(res, Err(FlowError::Code(7)));
+    }
+
+    #[tokio::test]
+    async fn test_map_err_ok() {
+        let mut node = Passer::<u8, u16, ()>::new().map_err(|()| FlowError::Code(0));
+        let res = node.run(3, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(3)));
+    }
+}
@@ -0,0 +1,151 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{
+    describe::{Description, Type},
+    node::{Node, NodeOutput},
+};
+
+/// Node that maps the output of the wrapped node using a function.
+///
+/// When the wrapped node returns [`NodeOutput::Ok`], the function is applied to the value.
+/// [`NodeOutput::SoftFail`] and errors are passed through untouched.
+///
+/// It is usually created using [`NodeExt::map_output`](crate::node::NodeExt::map_output).
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeExt, NodeOutput};
+///
+/// struct Double;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u16>, (), Ctx> for Double {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u16>, ()> {
+///         Ok(NodeOutput::Ok(u16::from(input) * 2))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// let mut node = Double.map_output(|output| format!("result: {output}"));
+/// let result = node.run(5, &mut ()).await;
+/// assert_eq!(result, Ok(NodeOutput::Ok("result: 10".to_owned())));
+/// # });
+/// ```
+pub struct MapOutput<Input, Output, Error, Context, NodeType, F> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    node: NodeType,
+    func: F,
+}
+
+impl<Input, Output, Error, Context, NodeType, F>
+    MapOutput<Input, Output, Error, Context, NodeType, F>
+{
+    /// Creates a new [`MapOutput`] node by wrapping the given node and mapping function.
+    ///
+    /// See also [`MapOutput`].
+    pub const fn new(node: NodeType, func: F) -> Self {
+        Self {
+            _ioec: PhantomData,
+            node,
+            func,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, F> Debug
+    for MapOutput<Input, Output, Error, Context, NodeType, F>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MapOutput")
+            .field("node", &self.node)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, F> Clone
+    for MapOutput<Input, Output, Error, Context, NodeType, F>
+where
+    NodeType: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            node: self.node.clone(),
+            func: self.func.clone(),
+        }
+    }
+}
+
+impl<Input, Output, NewOutput, Error, Context, NodeType, F>
+    Node<Input, NodeOutput<NewOutput>, Error, Context>
+    for MapOutput<Input, NodeOutput<Output>, Error, Context, NodeType, F>
+where
+    NodeType: Node<Input, NodeOutput<Output>, Error, Context> + Send,
+    F: Fn(Output) -> NewOutput + Send + Sync,
+    Input: Send,
+    Context: Send,
+{
+    async fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> Result<NodeOutput<NewOutput>, Error> {
+        Ok(match self.node.run(input, context).await? {
+            NodeOutput::Ok(output) => NodeOutput::Ok((self.func)(output)),
+            NodeOutput::SoftFail => NodeOutput::SoftFail,
+        })
+    }
+
+    fn describe(&self) -> Description {
+        let mut description = self.node.describe();
+        description.get_base_mut().output = Type::of::<NewOutput>();
+        description
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        describe::Type,
+        flows::{
+            SequentialFlow,
+            tests::{Passer, SoftFailNode},
+        },
+        node::{Node, NodeExt, NodeOutput},
+    };
+
+    #[tokio::test]
+    async fn test_map_output() {
+        let mut node = Passer::<u8, u16, ()>::new().map_output(|x| x.to_string());
+        let res = node.run(3, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::Ok("3".to_owned())));
+        assert_eq!(
+            Node::<u8, _, (), ()>::describe(&node)
+                .get_base_ref()
+                .output
+                .name,
+            Type::of::<String>().name
+        );
+
+        let mut node = SoftFailNode::<u8, u16, ()>::new().map_output(|x| x.to_string());
+        let res = node.run(3, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+    }
+
+    #[tokio::test]
+    async fn test_map_output_in_flow() {
+        let mut flow = SequentialFlow::<u8, u64, (), _>::builder()
+            .add_node(Passer::<u8, u16, ()>::new().map_output(|x| x * 2))
+            .add_node(Passer::<u16, u64, ()>::new())
+            .build();
+        let res = flow.run(3, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(6)));
+    }
+}
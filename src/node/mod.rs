@@ -6,8 +6,38 @@ mod base;
 pub use base::*;
 mod output;
 pub use output::*;
+mod identity;
+pub use identity::*;
+mod error;
+pub use error::*;
+mod ext;
+pub use ext::*;
+mod infallible;
+pub use infallible::*;
+mod map_output;
+pub use map_output::*;
+mod map_err;
+pub use map_err::*;
+mod recover;
+pub use recover::*;
+mod map_context;
+pub use map_context::*;
+mod then;
+pub use then::*;
+mod either;
+pub use either::*;
+mod local;
+pub use local::*;
+#[cfg(feature = "shared_storage_impl")]
+mod critical_section;
+#[cfg(feature = "shared_storage_impl")]
+pub use critical_section::*;
 #[cfg(feature = "boxed_node")]
 mod boxed;
 mod macros;
 #[cfg(feature = "boxed_node")]
 pub use boxed::*;
+#[cfg(feature = "stream")]
+mod stream;
+#[cfg(feature = "stream")]
+pub use stream::*;
@@ -10,7 +10,11 @@
 /// assert_eq!(success.ok(), Some(42));
 /// assert_eq!(failure.ok(), None);
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+///
+/// With the `serde` feature enabled, `NodeOutput` implements `Serialize` and `Deserialize`.
+/// [`NodeOutput::SoftFail`] is serialized as a unit variant and [`NodeOutput::Ok`] as a newtype variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NodeOutput<T> {
     /// Indicates that the node failed in a non-critical way and produced no output.
     ///
@@ -98,4 +102,258 @@ impl<T> NodeOutput<T> {
             Self::Ok(val) => Ok(val),
         }
     }
+
+    /// Converts a [`Result<T, E>`] into `NodeOutput<T>`, discarding the error.
+    ///
+    /// - Returns [`NodeOutput::Ok`] if the result is `Ok(T)`.
+    /// - Returns [`NodeOutput::SoftFail`] if the result is `Err(E)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::NodeOutput;
+    ///
+    /// assert_eq!(NodeOutput::from_result("42".parse::<i32>()), NodeOutput::Ok(42));
+    /// assert_eq!(NodeOutput::from_result("nope".parse::<i32>()), NodeOutput::SoftFail);
+    /// ```
+    pub fn from_result<E>(result: Result<T, E>) -> Self {
+        result.map_or(Self::SoftFail, Self::Ok)
+    }
+
+    /// Returns the output if it is [`NodeOutput::Ok`], otherwise returns `other`.
+    ///
+    /// This mirrors [`Option::or`].
+    /// Arguments passed to `or` are eagerly evaluated,
+    /// if you are passing the result of a function call, it is recommended to use [`NodeOutput::or_else`].
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::NodeOutput;
+    ///
+    /// assert_eq!(NodeOutput::SoftFail.or(NodeOutput::Ok(3)), NodeOutput::Ok(3));
+    /// assert_eq!(NodeOutput::Ok(2).or(NodeOutput::Ok(3)), NodeOutput::Ok(2));
+    /// assert_eq!(NodeOutput::<i32>::SoftFail.or(NodeOutput::SoftFail), NodeOutput::SoftFail);
+    /// ```
+    #[must_use]
+    pub fn or(self, other: Self) -> Self {
+        match self {
+            Self::SoftFail => other,
+            Self::Ok(val) => Self::Ok(val),
+        }
+    }
+
+    /// Returns the output if it is [`NodeOutput::Ok`], otherwise calls `f` and returns its result.
+    ///
+    /// This is the lazy variant of [`NodeOutput::or`] and mirrors [`Option::or_else`].
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::NodeOutput;
+    ///
+    /// assert_eq!(NodeOutput::SoftFail.or_else(|| NodeOutput::Ok(3)), NodeOutput::Ok(3));
+    /// assert_eq!(NodeOutput::Ok(2).or_else(|| unreachable!()), NodeOutput::Ok(2));
+    /// ```
+    #[must_use]
+    pub fn or_else(self, f: impl FnOnce() -> Self) -> Self {
+        match self {
+            Self::SoftFail => f(),
+            Self::Ok(val) => Self::Ok(val),
+        }
+    }
+
+    /// Returns [`NodeOutput::SoftFail`] if the output is [`NodeOutput::Ok`] and `predicate` returns `false`,
+    /// otherwise returns the output unchanged.
+    ///
+    /// This mirrors [`Option::filter`] and is useful for validating values inside of nodes.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::NodeOutput;
+    ///
+    /// let is_even = |val: &i32| val % 2 == 0;
+    /// assert_eq!(NodeOutput::Ok(4).filter(is_even), NodeOutput::Ok(4));
+    /// assert_eq!(NodeOutput::Ok(3).filter(is_even), NodeOutput::SoftFail);
+    /// assert_eq!(NodeOutput::SoftFail.filter(is_even), NodeOutput::SoftFail);
+    /// ```
+    #[must_use]
+    pub fn filter(self, predicate: impl FnOnce(&T) -> bool) -> Self {
+        match self {
+            Self::Ok(val) if predicate(&val) => Self::Ok(val),
+            _ => Self::SoftFail,
+        }
+    }
+
+    /// Returns `true` if the output is [`NodeOutput::Ok`].
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::NodeOutput;
+    ///
+    /// assert!(NodeOutput::Ok(5).is_ok());
+    /// assert!(!NodeOutput::<i32>::SoftFail.is_ok());
+    /// ```
+    #[must_use]
+    pub const fn is_ok(&self) -> bool {
+        matches!(self, Self::Ok(_))
+    }
+
+    /// Returns `true` if the output is [`NodeOutput::SoftFail`].
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::NodeOutput;
+    ///
+    /// assert!(NodeOutput::<i32>::SoftFail.is_soft_fail());
+    /// assert!(!NodeOutput::Ok(5).is_soft_fail());
+    /// ```
+    #[must_use]
+    pub const fn is_soft_fail(&self) -> bool {
+        matches!(self, Self::SoftFail)
+    }
+
+    /// Converts from `&NodeOutput<T>` to `NodeOutput<&T>`.
+    ///
+    /// This allows inspecting the inner value without moving it out of the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::NodeOutput;
+    ///
+    /// let output = NodeOutput::Ok("value".to_string());
+    /// assert_eq!(output.as_ref().ok().map(String::len), Some(5));
+    /// assert_eq!(output, NodeOutput::Ok("value".to_string()));
+    /// ```
+    pub const fn as_ref(&self) -> NodeOutput<&T> {
+        match self {
+            Self::SoftFail => NodeOutput::SoftFail,
+            Self::Ok(val) => NodeOutput::Ok(val),
+        }
+    }
+
+    /// Converts from `&mut NodeOutput<T>` to `NodeOutput<&mut T>`.
+    ///
+    /// This allows modifying the inner value in place.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::NodeOutput;
+    ///
+    /// let mut output = NodeOutput::Ok(5);
+    /// if let NodeOutput::Ok(val) = output.as_mut() {
+    ///     *val += 1;
+    /// }
+    /// assert_eq!(output, NodeOutput::Ok(6));
+    /// ```
+    pub const fn as_mut(&mut self) -> NodeOutput<&mut T> {
+        match self {
+            Self::SoftFail => NodeOutput::SoftFail,
+            Self::Ok(val) => NodeOutput::Ok(val),
+        }
+    }
+}
+
+impl<T, E> NodeOutput<Result<T, E>> {
+    /// Transposes a `NodeOutput` of a [`Result`] into a [`Result`] of a `NodeOutput`.
+    ///
+    /// - Returns `Ok(NodeOutput::Ok(T))` if the output is [`NodeOutput::Ok`] containing `Ok(T)`.
+    /// - Returns `Err(E)` if the output is [`NodeOutput::Ok`] containing `Err(E)`.
+    /// - Returns `Ok(NodeOutput::SoftFail)` if the output is [`NodeOutput::SoftFail`].
+    ///
+    /// This mirrors [`Option::transpose`] and allows using `?` on the inner error.
+    ///
+    /// # Errors
+    /// Returns the inner error if the output is [`NodeOutput::Ok`] containing an `Err`.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::NodeOutput;
+    ///
+    /// let ok: NodeOutput<Result<i32, &str>> = NodeOutput::Ok(Ok(5));
+    /// assert_eq!(ok.transpose(), Ok(NodeOutput::Ok(5)));
+    ///
+    /// let err: NodeOutput<Result<i32, &str>> = NodeOutput::Ok(Err("invalid"));
+    /// assert_eq!(err.transpose(), Err("invalid"));
+    ///
+    /// let soft_fail: NodeOutput<Result<i32, &str>> = NodeOutput::SoftFail;
+    /// assert_eq!(soft_fail.transpose(), Ok(NodeOutput::SoftFail));
+    /// ```
+    pub fn transpose(self) -> Result<NodeOutput<T>, E> {
+        match self {
+            Self::SoftFail => Ok(NodeOutput::SoftFail),
+            Self::Ok(Ok(val)) => Ok(NodeOutput::Ok(val)),
+            Self::Ok(Err(err)) => Err(err),
+        }
+    }
+}
+
+impl<T> From<Option<T>> for NodeOutput<T> {
+    /// Converts an [`Option<T>`] into `NodeOutput<T>`.
+    ///
+    /// - Returns [`NodeOutput::Ok`] if the option is `Some(T)`.
+    /// - Returns [`NodeOutput::SoftFail`] if the option is `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::NodeOutput;
+    ///
+    /// let output: NodeOutput<i32> = Some(5).into();
+    /// assert_eq!(output, NodeOutput::Ok(5));
+    ///
+    /// let output: NodeOutput<i32> = None.into();
+    /// assert_eq!(output, NodeOutput::SoftFail);
+    /// ```
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::SoftFail, Self::Ok)
+    }
+}
+
+impl<T> IntoIterator for NodeOutput<T> {
+    type Item = T;
+    type IntoIter = std::option::IntoIter<T>;
+
+    /// Returns an iterator yielding the value if the output is [`NodeOutput::Ok`]
+    /// and nothing if the output is [`NodeOutput::SoftFail`].
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::NodeOutput;
+    ///
+    /// let mut sum = 0;
+    /// for val in NodeOutput::Ok(5) {
+    ///     sum += val;
+    /// }
+    /// for val in NodeOutput::<i32>::SoftFail {
+    ///     sum += val;
+    /// }
+    /// assert_eq!(sum, 5);
+    ///
+    /// let outputs = vec![NodeOutput::Ok(1), NodeOutput::SoftFail, NodeOutput::Ok(3)];
+    /// let values = outputs.into_iter().flatten().collect::<Vec<_>>();
+    /// assert_eq!(values, vec![1, 3]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.ok().into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use super::NodeOutput;
+
+        let ok = serde_json::to_string(&NodeOutput::Ok(5u8)).unwrap();
+        assert_eq!(ok, r#"{"Ok":5}"#);
+        assert_eq!(
+            serde_json::from_str::<NodeOutput<u8>>(&ok).unwrap(),
+            NodeOutput::Ok(5)
+        );
+
+        let soft_fail = serde_json::to_string(&NodeOutput::<u8>::SoftFail).unwrap();
+        assert_eq!(soft_fail, r#""SoftFail""#);
+        assert_eq!(
+            serde_json::from_str::<NodeOutput<u8>>(&soft_fail).unwrap(),
+            NodeOutput::SoftFail
+        );
+    }
 }
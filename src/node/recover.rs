@@ -0,0 +1,152 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{
+    describe::{Description, Type},
+    node::{Node, NodeOutput},
+};
+
+/// Node that recovers from errors of the wrapped node using a function.
+///
+/// When the wrapped node returns an error, the function is applied to it
+/// and its result is returned as the output.
+/// The function can either return a fallback value or [`NodeOutput::SoftFail`].
+/// Outputs of the wrapped node are passed through untouched.
+///
+/// Unlike [`MapErr`](crate::node::MapErr), errors are collapsed into outputs,
+/// so the returned node never returns an error and its error type can be chosen freely.
+///
+/// It is usually created using [`NodeExt::recover`](crate::node::NodeExt::recover).
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeExt, NodeOutput};
+///
+/// struct Fail;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, String, Ctx> for Fail {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, String> {
+///         Err(format!("failed on {input}"))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// let mut node = Fail.recover(|_err| NodeOutput::Ok(0));
+/// let result: Result<_, ()> = node.run(5, &mut ()).await;
+/// assert_eq!(result, Ok(NodeOutput::Ok(0)));
+/// # });
+/// ```
+pub struct RecoverNode<Input, Output, Error, Context, NodeType, F> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context)>,
+    node: NodeType,
+    func: F,
+}
+
+impl<Input, Output, Error, Context, NodeType, F>
+    RecoverNode<Input, Output, Error, Context, NodeType, F>
+{
+    /// Creates a new [`RecoverNode`] by wrapping the given node and recovery function.
+    ///
+    /// See also [`RecoverNode`].
+    pub const fn new(node: NodeType, func: F) -> Self {
+        Self {
+            _ioec: PhantomData,
+            node,
+            func,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, F> Debug
+    for RecoverNode<Input, Output, Error, Context, NodeType, F>
+where
+    NodeType: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecoverNode")
+            .field("node", &self.node)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, NodeType, F> Clone
+    for RecoverNode<Input, Output, Error, Context, NodeType, F>
+where
+    NodeType: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            node: self.node.clone(),
+            func: self.func.clone(),
+        }
+    }
+}
+
+impl<Input, Output, Error, NewError, Context, NodeType, F>
+    Node<Input, NodeOutput<Output>, NewError, Context>
+    for RecoverNode<Input, NodeOutput<Output>, Error, Context, NodeType, F>
+where
+    NodeType: Node<Input, NodeOutput<Output>, Error, Context> + Send,
+    F: Fn(Error) -> NodeOutput<Output> + Send + Sync,
+    Input: Send,
+    Context: Send,
+{
+    async fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> Result<NodeOutput<Output>, NewError> {
+        Ok(self
+            .node
+            .run(input, context)
+            .await
+            .unwrap_or_else(&self.func))
+    }
+
+    fn describe(&self) -> Description {
+        let mut description = self.node.describe();
+        description.get_base_mut().error = Type::of::<NewError>();
+        description
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        flows::tests::Passer,
+        node::{Node, NodeExt, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct Fail;
+
+    impl<C: Send> Node<u16, NodeOutput<u16>, u8, C> for Fail {
+        async fn run(&mut self, _input: u16, _context: &mut C) -> Result<NodeOutput<u16>, u8> {
+            Err(7)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recover() {
+        let mut node = Fail.recover(|code| NodeOutput::Ok(u16::from(code) * 10));
+        let res: Result<_, ()> = node.run(3, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(70)));
+
+        let mut node = Fail.recover(|_| NodeOutput::SoftFail);
+        let res: Result<_, ()> = node.run(3, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+    }
+
+    #[tokio::test]
+    async fn test_recover_ok() {
+        let mut node = Passer::<u8, u16, ()>::new().recover(|()| NodeOutput::Ok(0));
+        let res: Result<_, ()> = node.run(3, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(3)));
+    }
+}
@@ -0,0 +1,114 @@
+use futures_util::{Stream, StreamExt, stream};
+
+use crate::node::{Node, NodeOutput};
+
+/// Runs a node over a [`Stream`] of inputs and yields the result of each run.
+///
+/// The node is run **sequentially**, one input at a time, in the order in which
+/// the inputs are produced by the `inputs` stream.
+/// The next input is pulled only after the result of the previous run is consumed.
+/// Errors don't stop the stream, they are yielded like any other result.
+///
+/// This is a lighter alternative to [`MapFlow`](crate::flows::MapFlow)
+/// for driving a node over a batch of inputs.
+///
+/// # Examples
+/// ```
+/// use futures_util::{StreamExt, stream};
+/// use node_flow::node::{Node, NodeOutput, run_stream};
+///
+/// #[derive(Clone)]
+/// struct AddOne;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         Ok(NodeOutput::Ok(input + 1))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// async fn main() {
+///     let mut node = AddOne;
+///     let results = run_stream(&mut node, stream::iter([1, 2]), &mut ())
+///         .collect::<Vec<_>>()
+///         .await;
+///     assert_eq!(results, [Ok(NodeOutput::Ok(2)), Ok(NodeOutput::Ok(3))]);
+/// }
+/// # main().await;
+/// # });
+/// ```
+pub fn run_stream<'a, NodeType, Inputs, Output, Error, Context>(
+    node: &'a mut NodeType,
+    inputs: Inputs,
+    context: &'a mut Context,
+) -> impl Stream<Item = Result<NodeOutput<Output>, Error>> + Send + 'a
+where
+    NodeType: Node<Inputs::Item, NodeOutput<Output>, Error, Context> + Send,
+    Inputs: Stream + Send + 'a,
+    Context: Send,
+{
+    stream::unfold(
+        (node, Box::pin(inputs), context),
+        |(node, mut inputs, context)| async move {
+            let input = inputs.next().await?;
+            let result = node.run(input, context).await;
+            Some((result, (node, inputs, context)))
+        },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use futures_util::{StreamExt, stream};
+
+    use super::run_stream;
+    use crate::{
+        flows::tests::Passer,
+        node::{Node, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct FailOnZero;
+
+    impl<C: Send> Node<u8, NodeOutput<u8>, (), C> for FailOnZero {
+        async fn run(&mut self, input: u8, _context: &mut C) -> Result<NodeOutput<u8>, ()> {
+            if input == 0 {
+                Err(())
+            } else {
+                Ok(NodeOutput::Ok(input))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_stream() {
+        let mut node = Passer::<u8, u16, ()>::new();
+        let results = run_stream(&mut node, stream::iter([1, 2, 3]), &mut ())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            results,
+            [
+                Ok(NodeOutput::Ok(1)),
+                Ok(NodeOutput::Ok(2)),
+                Ok(NodeOutput::Ok(3))
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_stream_error() {
+        let mut node = FailOnZero;
+        let results = run_stream(&mut node, stream::iter([1, 0, 3]), &mut ())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            results,
+            [Ok(NodeOutput::Ok(1)), Err(()), Ok(NodeOutput::Ok(3))]
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{
+    describe::{Description, Edge, remove_generics_from_name},
+    node::{Node, NodeOutput},
+};
+
+/// Node that runs two nodes one after another.
+///
+/// The output of the first node is converted into the input of the second node.
+/// When the first node soft-fails or returns an error, the second node is not executed.
+/// Both nodes must have the same error type, use [`NodeExt::map_err`](crate::node::NodeExt::map_err) to adapt it.
+///
+/// This is an operator-style alternative to [`SequentialFlow`](crate::flows::SequentialFlow) for two nodes.
+///
+/// It is usually created using [`NodeExt::then`](crate::node::NodeExt::then).
+///
+/// # Examples
+/// ```
+/// use node_flow::node::{Node, NodeExt, NodeOutput};
+///
+/// struct AddOne;
+/// struct Double;
+///
+/// impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+///     async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+///         Ok(NodeOutput::Ok(input + 1))
+///     }
+/// }
+///
+/// impl<Ctx: Send> Node<u16, NodeOutput<u16>, (), Ctx> for Double {
+///     async fn run(&mut self, input: u16, _: &mut Ctx) -> Result<NodeOutput<u16>, ()> {
+///         Ok(NodeOutput::Ok(input * 2))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .enable_all()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// let mut node = AddOne.then(Double);
+/// let result = node.run(5, &mut ()).await;
+/// assert_eq!(result, Ok(NodeOutput::Ok(12)));
+/// # });
+/// ```
+pub struct ThenNode<Input, Output, Error, Context, First, Second, SecondInput> {
+    #[expect(clippy::type_complexity)]
+    _ioec: PhantomData<fn() -> (Input, Output, Error, Context, SecondInput)>,
+    first: First,
+    second: Second,
+}
+
+impl<Input, Output, Error, Context, First, Second, SecondInput>
+    ThenNode<Input, Output, Error, Context, First, Second, SecondInput>
+{
+    /// Creates a new [`ThenNode`] running `first` and then `second`.
+    ///
+    /// See also [`ThenNode`].
+    pub const fn new(first: First, second: Second) -> Self {
+        Self {
+            _ioec: PhantomData,
+            first,
+            second,
+        }
+    }
+}
+
+impl<Input, Output, Error, Context, First, Second, SecondInput> Debug
+    for ThenNode<Input, Output, Error, Context, First, Second, SecondInput>
+where
+    First: Debug,
+    Second: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ThenNode")
+            .field("first", &self.first)
+            .field("second", &self.second)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output, Error, Context, First, Second, SecondInput> Clone
+    for ThenNode<Input, Output, Error, Context, First, Second, SecondInput>
+where
+    First: Clone,
+    Second: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ioec: PhantomData,
+            first: self.first.clone(),
+            second: self.second.clone(),
+        }
+    }
+}
+
+impl<Input, FirstOutput, Output, Error, Context, First, Second, SecondInput>
+    Node<Input, NodeOutput<Output>, Error, Context>
+    for ThenNode<Input, NodeOutput<FirstOutput>, Error, Context, First, Second, SecondInput>
+where
+    First: Node<Input, NodeOutput<FirstOutput>, Error, Context> + Send,
+    Second: Node<SecondInput, NodeOutput<Output>, Error, Context> + Send,
+    FirstOutput: Into<SecondInput>,
+    Input: Send,
+    Context: Send,
+{
+    async fn run(
+        &mut self,
+        input: Input,
+        context: &mut Context,
+    ) -> Result<NodeOutput<Output>, Error> {
+        let NodeOutput::Ok(output) = self.first.run(input, context).await? else {
+            return Ok(NodeOutput::SoftFail);
+        };
+        self.second.run(output.into(), context).await
+    }
+
+    fn describe(&self) -> Description {
+        Description::new_flow::<Self, Input, Output, Error, Context>(
+            self,
+            vec![self.first.describe(), self.second.describe()],
+            vec![
+                Edge::flow_to_node(0),
+                Edge::node_to_node(0, 1),
+                Edge::node_to_flow(1),
+            ],
+        )
+        .modify_name(remove_generics_from_name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        flows::tests::{Passer, SoftFailNode},
+        node::{Node, NodeExt, NodeOutput},
+    };
+
+    #[derive(Clone)]
+    struct Double;
+
+    impl<C: Send> Node<u32, NodeOutput<u32>, (), C> for Double {
+        async fn run(&mut self, input: u32, _context: &mut C) -> Result<NodeOutput<u32>, ()> {
+            Ok(NodeOutput::Ok(input * 2))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_then() {
+        let mut node = Passer::<u8, u16, ()>::new().then(Double).then(Double);
+        let res = node.run(3, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::Ok(12)));
+
+        let desc = Node::<u8, NodeOutput<u32>, (), ()>::describe(&node);
+        assert_eq!(desc.node_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_then_soft_fail() {
+        let mut node = SoftFailNode::<u8, u16, ()>::new().then(Double);
+        let res = node.run(3, &mut ()).await;
+        assert_eq!(res, Ok(NodeOutput::SoftFail));
+    }
+}
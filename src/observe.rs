@@ -0,0 +1,83 @@
+//! This module contains the [`Observer`] trait used for observing runs of nodes.
+//!
+//! An [`Observer`] is notified before and after a run of a node wrapped in [`ObservedFlow`],
+//! which makes it a central place for recording metrics like latency or success rates.
+//!
+//! For details, see the documentation of [`Observer`] and [`ObservedFlow`].
+
+use std::time::Duration;
+
+#[cfg(doc)]
+use crate::flows::ObservedFlow;
+use crate::node::NodeOutput;
+
+/// The outcome of a single run of a node.
+///
+/// See also [`Observer::on_finish`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    /// The node returned [`NodeOutput::Ok`].
+    Ok,
+    /// The node returned [`NodeOutput::SoftFail`].
+    SoftFail,
+    /// The node returned an error.
+    Err,
+}
+
+impl Outcome {
+    /// Returns the outcome of a node run from its result.
+    ///
+    /// # Examples
+    /// ```
+    /// use node_flow::node::NodeOutput;
+    /// use node_flow::observe::Outcome;
+    ///
+    /// assert_eq!(Outcome::of(&Ok::<_, ()>(NodeOutput::Ok(5))), Outcome::Ok);
+    /// assert_eq!(Outcome::of(&Ok::<NodeOutput<u8>, ()>(NodeOutput::SoftFail)), Outcome::SoftFail);
+    /// assert_eq!(Outcome::of(&Err::<NodeOutput<u8>, _>(())), Outcome::Err);
+    /// ```
+    pub const fn of<T, E>(result: &Result<NodeOutput<T>, E>) -> Self {
+        match result {
+            Ok(NodeOutput::Ok(_)) => Self::Ok,
+            Ok(NodeOutput::SoftFail) => Self::SoftFail,
+            Err(_) => Self::Err,
+        }
+    }
+}
+
+/// The `Observer` trait is notified around runs of nodes wrapped in [`ObservedFlow`].
+///
+/// It can be used for recording metrics (for example with Prometheus)
+/// without instrumenting every node by hand.
+///
+/// `node_type` is the type name of the observed node taken from its [`Description`](crate::describe::Description).
+///
+/// # Examples
+/// ```
+/// use node_flow::observe::{Observer, Outcome};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::time::Duration;
+///
+/// #[derive(Default)]
+/// struct ErrorCounter(AtomicUsize);
+///
+/// impl Observer for ErrorCounter {
+///     fn on_start(&self, _node_type: &str) {}
+///
+///     fn on_finish(&self, _node_type: &str, outcome: Outcome, _elapsed: Duration) {
+///         if outcome == Outcome::Err {
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+/// ```
+pub trait Observer: Send + Sync {
+    /// Called right before the node starts running.
+    fn on_start(&self, node_type: &str);
+
+    /// Called after the node finished running.
+    ///
+    /// `elapsed` is the time the run took.
+    /// It's not called when the run is canceled.
+    fn on_finish(&self, node_type: &str, outcome: Outcome, elapsed: Duration);
+}
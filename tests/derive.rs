@@ -0,0 +1,157 @@
+#![cfg(feature = "derive")]
+
+use node_flow::{
+    context::{Fork, Join},
+    flows::{ParallelFlow, parallel_flow::FromParallelOutputs},
+    node::{Node, NodeOutput},
+};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Depth(u8);
+
+impl Fork for Depth {
+    fn fork(&self) -> Self {
+        Self(self.0 + 1)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Fork)]
+struct Context {
+    depth: Depth,
+    #[fork(clone)]
+    name: String,
+    #[fork(clone)]
+    pub values: Vec<u32>,
+}
+
+#[derive(Debug, PartialEq, Eq, Fork)]
+#[fork(clone)]
+struct Cloned(String, pub(crate) u8);
+
+#[derive(Fork)]
+struct Generic<'a, T, const N: usize = 2>
+where
+    T: Clone,
+{
+    depth: Depth,
+    #[fork(clone)]
+    items: [&'a T; N],
+    #[fork(clone)]
+    callback: fn(u8) -> Option<T>,
+}
+
+#[derive(Debug, PartialEq, Eq, Fork)]
+struct Unit;
+
+#[derive(Debug, PartialEq, Eq, Fork)]
+struct Empty {}
+
+#[test]
+fn test_derive_fork() {
+    let ctx = Context {
+        depth: Depth(0),
+        name: "ctx".to_owned(),
+        values: vec![1, 2],
+    };
+    assert_eq!(
+        ctx.fork(),
+        Context {
+            depth: Depth(1),
+            name: "ctx".to_owned(),
+            values: vec![1, 2],
+        }
+    );
+
+    let cloned = Cloned("cloned".to_owned(), 3);
+    assert_eq!(cloned.fork(), cloned);
+
+    assert_eq!(Unit.fork(), Unit);
+    assert_eq!(Empty {}.fork(), Empty {});
+}
+
+#[test]
+fn test_derive_fork_generic() {
+    let (a, b) = (1u8, 2u8);
+    let generic = Generic {
+        depth: Depth(5),
+        items: [&a, &b],
+        callback: Some,
+    };
+    let forked = generic.fork();
+    assert_eq!(forked.depth, Depth(6));
+    assert_eq!(forked.items, [&1, &2]);
+    assert_eq!((forked.callback)(4), Some(4));
+}
+
+#[derive(Debug, PartialEq, Eq, FromParallelOutputs)]
+struct Outputs {
+    name: NodeOutput<String>,
+    count: NodeOutput<u32>,
+}
+
+#[derive(Debug, PartialEq, Eq, FromParallelOutputs)]
+struct TupleOutputs<T>(NodeOutput<T>, NodeOutput<T>, NodeOutput<T>);
+
+#[derive(Clone)]
+struct Name;
+
+impl<C: Send> Node<u32, NodeOutput<String>, (), C> for Name {
+    async fn run(&mut self, input: u32, _: &mut C) -> Result<NodeOutput<String>, ()> {
+        Ok(NodeOutput::Ok(format!("node{input}")))
+    }
+}
+
+#[derive(Clone)]
+struct Double;
+
+impl<C: Send> Node<u32, NodeOutput<u32>, (), C> for Double {
+    async fn run(&mut self, input: u32, _: &mut C) -> Result<NodeOutput<u32>, ()> {
+        Ok(NodeOutput::Ok(input * 2))
+    }
+}
+
+#[derive(Fork)]
+struct Ctx;
+
+impl Join for Ctx {
+    fn join(&mut self, _others: Box<[Self]>) {}
+}
+
+#[test]
+fn test_derive_from_parallel_outputs() {
+    let outputs = Outputs::from(((NodeOutput::Ok("a".to_owned()),), NodeOutput::SoftFail));
+    assert_eq!(
+        outputs,
+        Outputs {
+            name: NodeOutput::Ok("a".to_owned()),
+            count: NodeOutput::SoftFail,
+        }
+    );
+
+    let outputs = TupleOutputs::from((
+        ((NodeOutput::Ok(1u8),), NodeOutput::Ok(2)),
+        NodeOutput::Ok(3),
+    ));
+    assert_eq!(
+        outputs,
+        TupleOutputs(NodeOutput::Ok(1), NodeOutput::Ok(2), NodeOutput::Ok(3))
+    );
+}
+
+#[tokio::test]
+async fn test_derive_from_parallel_outputs_joiner() {
+    let mut flow = ParallelFlow::<u32, String, (), Ctx>::builder()
+        .add_node(Name)
+        .add_node(Double)
+        .build(async |outputs, _: &mut Ctx| {
+            let Outputs { name, count } = Outputs::from(outputs);
+            Ok(NodeOutput::Ok(format!(
+                "{}: {}",
+                name.ok().unwrap(),
+                count.ok().unwrap()
+            )))
+        });
+
+    let res = flow.run(4, &mut Ctx).await;
+    assert_eq!(res, Ok(NodeOutput::Ok("node4: 8".to_owned())));
+}
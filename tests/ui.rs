@@ -0,0 +1,13 @@
+// Tests of compile errors emitted for invalid usage.
+// Snapshots of the errors can be regenerated by running the tests with `TRYBUILD=overwrite`.
+
+#[cfg(feature = "derive")]
+#[test]
+fn derive() {
+    trybuild::TestCases::new().compile_fail("tests/ui/derive/*.rs");
+}
+
+#[test]
+fn flows() {
+    trybuild::TestCases::new().compile_fail("tests/ui/flows/*.rs");
+}
@@ -0,0 +1,9 @@
+use node_flow::context::Fork;
+
+#[derive(Fork)]
+enum Context {
+    A(u8),
+    B(u16),
+}
+
+fn main() {}
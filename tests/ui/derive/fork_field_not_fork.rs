@@ -0,0 +1,10 @@
+use node_flow::context::Fork;
+
+#[derive(Fork)]
+struct Context {
+    #[fork(clone)]
+    name: String,
+    depth: u8,
+}
+
+fn main() {}
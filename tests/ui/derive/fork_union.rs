@@ -0,0 +1,9 @@
+use node_flow::context::Fork;
+
+#[derive(Fork)]
+union Context {
+    a: u8,
+    b: u16,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use node_flow::context::Fork;
+
+#[derive(Fork)]
+struct Context {
+    #[fork(copy)]
+    depth: u8,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use node_flow::{flows::parallel_flow::FromParallelOutputs, node::NodeOutput};
+
+#[derive(FromParallelOutputs)]
+enum Outputs {
+    User(NodeOutput<String>),
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use node_flow::flows::parallel_flow::FromParallelOutputs;
+
+#[derive(FromParallelOutputs)]
+struct Outputs;
+
+fn main() {}
@@ -0,0 +1,19 @@
+use node_flow::{
+    flows::SequentialFlow,
+    node::{Node, NodeOutput},
+};
+
+// missing `#[derive(Clone)]`
+struct AddOne;
+
+impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+    async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+        Ok(NodeOutput::Ok(input + 1))
+    }
+}
+
+fn main() {
+    let _flow = SequentialFlow::<u8, u8, (), ()>::builder()
+        .add_node(AddOne)
+        .build();
+}
@@ -0,0 +1,33 @@
+use std::cell::Cell;
+
+use node_flow::{
+    context::{Fork, Join},
+    flows::ParallelFlow,
+    node::{Node, NodeOutput},
+};
+
+// `Cell` is not `Sync`
+#[derive(Clone)]
+struct AddOne(Cell<u8>);
+
+impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+    async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+        Ok(NodeOutput::Ok(input + 1))
+    }
+}
+
+struct Ctx;
+
+impl Fork for Ctx {
+    fn fork(&self) -> Self {
+        Self
+    }
+}
+
+impl Join for Ctx {
+    fn join(&mut self, _others: Box<[Self]>) {}
+}
+
+fn main() {
+    let _builder = ParallelFlow::<u8, u8, (), Ctx>::builder().add_node(AddOne(Cell::new(1)));
+}
@@ -0,0 +1,29 @@
+use node_flow::{
+    flows::SequentialFlow,
+    node::{Node, NodeOutput},
+};
+
+#[derive(Clone)]
+struct ToText;
+
+impl<Ctx: Send> Node<u8, NodeOutput<String>, (), Ctx> for ToText {
+    async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<String>, ()> {
+        Ok(NodeOutput::Ok(input.to_string()))
+    }
+}
+
+#[derive(Clone)]
+struct AddOne;
+
+impl<Ctx: Send> Node<u8, NodeOutput<u8>, (), Ctx> for AddOne {
+    async fn run(&mut self, input: u8, _: &mut Ctx) -> Result<NodeOutput<u8>, ()> {
+        Ok(NodeOutput::Ok(input + 1))
+    }
+}
+
+fn main() {
+    let _flow = SequentialFlow::<u8, u8, (), ()>::builder()
+        .add_node(ToText)
+        .add_node(AddOne)
+        .build();
+}